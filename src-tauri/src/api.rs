@@ -1,5 +1,7 @@
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
 const MAPLE_API_BASE: &str = "https://open.api.nexon.com/maplestory/v1";
@@ -14,6 +16,15 @@ pub enum ApiError {
     CharacterNotFound,
 }
 
+// API가 키 대신 null을 주는 필드는 기본값으로 (serde(default)는 키가 없을 때만 적용됨)
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OcidResponse {
     pub ocid: String,
@@ -64,6 +75,479 @@ pub struct CharacterListItem {
     pub character_level: i32,
 }
 
+// 장비 스탯 옵션 (item_total_option, item_base_option, item_add_option,
+// item_etc_option, item_starforce_option, item_exceptional_option 공통)
+// 옵션 종류마다 제공되는 필드가 달라 공통 필드 외에는 Option으로 둔다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ItemStatOption {
+    #[serde(deserialize_with = "null_as_default")]
+    pub str: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub dex: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub int: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub luk: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub max_hp: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub max_mp: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub attack_power: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub magic_power: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub armor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jump: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boss_damage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_monster_armor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_stat: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub damage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_hp_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_mp_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equipment_level_decrease: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_equipment_level: Option<i32>,
+    // 익셉셔널 강화 횟수 (item_exceptional_option 전용)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exceptional_upgrade: Option<i32>,
+    // 알 수 없는 필드는 그대로 보존
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// 착용 장비 1개 (item_equipment, item_equipment_preset_N 공통)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ItemEquipment {
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_equipment_part: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_equipment_slot: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_name: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_icon: String,
+    pub item_description: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_shape_name: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_shape_icon: String,
+    pub item_gender: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_total_option: ItemStatOption,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_base_option: ItemStatOption,
+    // 잠재능력
+    pub potential_option_flag: Option<String>,
+    pub potential_option_grade: Option<String>,
+    pub potential_option_1: Option<String>,
+    pub potential_option_2: Option<String>,
+    pub potential_option_3: Option<String>,
+    // 에디셔널 잠재능력
+    pub additional_potential_option_flag: Option<String>,
+    pub additional_potential_option_grade: Option<String>,
+    pub additional_potential_option_1: Option<String>,
+    pub additional_potential_option_2: Option<String>,
+    pub additional_potential_option_3: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub equipment_level_increase: i32,
+    // 익셉셔널 / 추가옵션 / 주문서 / 스타포스 옵션
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_exceptional_option: ItemStatOption,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_add_option: ItemStatOption,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_etc_option: ItemStatOption,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_starforce_option: ItemStatOption,
+    #[serde(deserialize_with = "null_as_default")]
+    pub growth_exp: i32,
+    #[serde(deserialize_with = "null_as_default")]
+    pub growth_level: i32,
+    #[serde(deserialize_with = "null_as_default")]
+    pub scroll_upgrade: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub cuttable_count: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub golden_hammer_flag: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub scroll_resilience_count: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub scroll_upgradeable_count: String,
+    // 소울
+    pub soul_name: Option<String>,
+    pub soul_option: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub starforce: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub starforce_scroll_flag: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub special_ring_level: i32,
+    pub date_expire: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct EquipmentTitle {
+    #[serde(deserialize_with = "null_as_default")]
+    pub title_name: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub title_icon: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub title_description: String,
+    pub date_expire: Option<String>,
+    pub date_option_expire: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// character/item-equipment 응답
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CharacterEquipment {
+    pub date: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub character_gender: String,
+    #[serde(deserialize_with = "null_as_default")]
+    pub character_class: String,
+    pub preset_no: Option<i32>,
+    #[serde(deserialize_with = "null_as_default")]
+    pub item_equipment: Vec<ItemEquipment>,
+    pub item_equipment_preset_1: Option<Vec<ItemEquipment>>,
+    pub item_equipment_preset_2: Option<Vec<ItemEquipment>>,
+    pub item_equipment_preset_3: Option<Vec<ItemEquipment>>,
+    pub title: Option<EquipmentTitle>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// HEXA 코어 (character/hexamatrix)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HexaCore {
+    pub hexa_core_name: String,
+    pub hexa_core_level: i32,
+    pub hexa_core_type: String, // "스킬 코어", "마스터리 코어", "강화 코어", "공용 코어"
+    pub linked_skill: Vec<HexaLinkedSkill>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HexaLinkedSkill {
+    pub hexa_skill_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HexaMatrix {
+    pub date: Option<String>,
+    pub character_hexa_core_equipment: Vec<HexaCore>,
+}
+
+// HEXA 스탯 코어 (character/hexamatrix-stat)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HexaStatCore {
+    pub slot_id: String,
+    pub main_stat_name: String,
+    pub sub_stat_name_1: String,
+    pub sub_stat_name_2: String,
+    pub main_stat_level: i32,
+    pub sub_stat_level_1: i32,
+    pub sub_stat_level_2: i32,
+    pub stat_grade: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct HexaMatrixStat {
+    pub date: Option<String>,
+    pub character_class: String,
+    pub character_hexa_stat_core: Vec<HexaStatCore>,
+    pub character_hexa_stat_core_2: Vec<HexaStatCore>,
+    pub character_hexa_stat_core_3: Vec<HexaStatCore>,
+    pub preset_hexa_stat_core: Vec<HexaStatCore>,
+    pub preset_hexa_stat_core_2: Vec<HexaStatCore>,
+    pub preset_hexa_stat_core_3: Vec<HexaStatCore>,
+}
+
+// 심볼 (character/symbol-equipment)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Symbol {
+    pub symbol_name: String, // "아케인심볼 : 소멸의 여로", "어센틱심볼 : 세르니움" 등
+    pub symbol_icon: String,
+    pub symbol_description: String,
+    pub symbol_force: String,
+    pub symbol_level: i32,
+    pub symbol_str: String,
+    pub symbol_dex: String,
+    pub symbol_int: String,
+    pub symbol_luk: String,
+    pub symbol_hp: String,
+    pub symbol_drop_rate: String,
+    pub symbol_meso_rate: String,
+    pub symbol_exp_rate: String,
+    pub symbol_growth_count: i32,
+    pub symbol_require_growth_count: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SymbolEquipment {
+    pub date: Option<String>,
+    pub character_class: String,
+    pub symbol: Vec<Symbol>,
+}
+
+// 유니온 (user/union)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionInfo {
+    pub date: Option<String>,
+    pub union_level: i32,
+    pub union_grade: String,
+    pub union_artifact_level: i32,
+    pub union_artifact_exp: i64,
+    pub union_artifact_point: i64,
+}
+
+// 유니온 공격대 (user/union-raider)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionBlockPosition {
+    pub x: String,
+    pub y: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionBlock {
+    pub block_type: String,
+    pub block_class: String,
+    pub block_level: String,
+    pub block_control_point: Option<UnionBlockPosition>,
+    pub block_position: Option<Vec<UnionBlockPosition>>,
+}
+
+impl UnionBlock {
+    pub fn level(&self) -> i32 {
+        self.block_level.trim().parse().unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionInnerStat {
+    pub stat_field_id: String,
+    pub stat_field_effect: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionRaider {
+    pub date: Option<String>,
+    pub union_raider_stat: Vec<String>,
+    pub union_occupied_stat: Vec<String>,
+    pub union_inner_stat: Vec<UnionInnerStat>,
+    pub union_block: Vec<UnionBlock>,
+    pub use_preset_no: Option<i32>,
+}
+
+// 유니온 챔피언 (user/union-champion)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionChampionBadge {
+    pub stat: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionChampion {
+    pub champion_name: String,
+    pub champion_slot: i32,
+    pub champion_grade: String,
+    pub champion_class: String,
+    pub champion_badge_info: Vec<UnionChampionBadge>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct UnionChampionInfo {
+    pub date: Option<String>,
+    pub union_champion: Vec<UnionChampion>,
+    pub champion_badge_total_info: Vec<UnionChampionBadge>,
+}
+
+// 길드 (guild/id, guild/basic)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuildIdResponse {
+    pub oguild_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct GuildBasic {
+    pub date: Option<String>,
+    pub world_name: String,
+    pub guild_name: String,
+    pub guild_level: i32,
+    pub guild_fame: i64,
+    pub guild_point: i64,
+    pub guild_master_name: String,
+    pub guild_member_count: i32,
+    pub guild_member: Vec<String>,
+    pub guild_mark: Option<String>,
+    pub guild_mark_custom: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// 랭킹 (ranking/overall, ranking/union, ranking/dojang)
+// 세 랭킹의 공통 필드 + 종류별 필드를 하나의 구조체로 받는다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RankingEntry {
+    pub date: Option<String>,
+    pub ranking: i64,
+    pub character_name: String,
+    pub world_name: String,
+    pub class_name: String,
+    pub sub_class_name: String,
+    pub character_level: Option<i32>,
+    pub character_exp: Option<i64>,
+    pub character_popularity: Option<i32>,
+    pub character_guildname: Option<String>,
+    pub union_level: Option<i32>,
+    pub union_power: Option<i64>,
+    pub dojang_floor: Option<i32>,
+    pub dojang_time_record: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RankingResponse {
+    pub ranking: Vec<RankingEntry>,
+}
+
+// 강화 기록 (history/starforce, history/cube, history/potential)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct StarforceEvent {
+    pub success_rate: Option<String>,
+    pub cost_discount_rate: Option<String>,
+    pub plus_value: Option<String>,
+    pub starforce_event_range: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct StarforceHistory {
+    pub id: String,
+    pub item_upgrade_result: String, // "성공", "실패(유지)", "실패(하락)", "파괴"
+    pub before_starforce_count: i32,
+    pub after_starforce_count: i32,
+    pub starcatch_result: String,
+    pub superior_item_flag: String,
+    pub destroy_defence: String,
+    pub chance_time: String,
+    pub event_field_flag: String,
+    pub upgrade_item: String,
+    pub protect_shield: String,
+    pub bonus_stat_upgrade: String,
+    pub character_name: String,
+    pub world_name: String,
+    pub target_item: String,
+    pub date_create: String,
+    pub starforce_event_list: Option<Vec<StarforceEvent>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PotentialOptionValue {
+    pub value: String,
+    pub grade: String,
+}
+
+// 큐브 사용 기록과 잠재능력 재설정 기록은 필드 구성이 같고
+// 사용한 아이템 이름 필드만 다르다 (cube_type / potential_type)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PotentialHistory {
+    pub id: String,
+    pub character_name: String,
+    pub date_create: String,
+    #[serde(alias = "potential_type")]
+    pub cube_type: String,
+    pub item_upgrade_result: String,
+    pub miracle_time_flag: String,
+    pub item_equipment_part: String,
+    pub item_level: i32,
+    pub target_item: String,
+    pub potential_option_grade: Option<String>,
+    pub additional_potential_option_grade: Option<String>,
+    pub upgrade_guarantee: bool,
+    pub upgrade_guarantee_count: i32,
+    pub before_potential_option: Vec<PotentialOptionValue>,
+    pub before_additional_potential_option: Vec<PotentialOptionValue>,
+    pub after_potential_option: Vec<PotentialOptionValue>,
+    pub after_additional_potential_option: Vec<PotentialOptionValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct StarforceHistoryPage {
+    pub count: i32,
+    pub next_cursor: Option<String>,
+    pub starforce_history: Vec<StarforceHistory>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PotentialHistoryPage {
+    pub count: i32,
+    pub next_cursor: Option<String>,
+    #[serde(alias = "cube_history")]
+    pub potential_history: Vec<PotentialHistory>,
+}
+
+// 커서 기반 페이지 응답 공통 처리
+trait HistoryPage: DeserializeOwned {
+    type Item;
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>);
+}
+
+impl HistoryPage for StarforceHistoryPage {
+    type Item = StarforceHistory;
+    fn into_parts(self) -> (Vec<StarforceHistory>, Option<String>) {
+        (self.starforce_history, self.next_cursor)
+    }
+}
+
+impl HistoryPage for PotentialHistoryPage {
+    type Item = PotentialHistory;
+    fn into_parts(self) -> (Vec<PotentialHistory>, Option<String>) {
+        (self.potential_history, self.next_cursor)
+    }
+}
+
+const HISTORY_PAGE_SIZE: &str = "1000";
+const RATE_LIMIT_RETRIES: u32 = 3;
+
 pub struct MapleApi {
     client: Client,
     api_key: String,
@@ -85,6 +569,34 @@ impl MapleApi {
         }
     }
 
+    // 공통 GET 요청 (성공 시 JSON 역직렬화, 실패 시 API 오류 메시지 반환)
+    // 호출 한도 초과(429)면 잠시 기다렸다가 재시도
+    async fn get_json<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T, ApiError> {
+        let url = format!("{}/{}", MAPLE_API_BASE, path);
+
+        let mut attempt = 0;
+        let response = loop {
+            let response = self
+                .client
+                .get(&url)
+                .header("x-nxopen-api-key", &self.api_key)
+                .query(query)
+                .send()
+                .await?;
+            if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS || attempt >= RATE_LIMIT_RETRIES {
+                break response;
+            }
+            attempt += 1;
+            tokio::time::sleep(Duration::from_secs(1 << attempt)).await;
+        };
+
+        if response.status().is_success() {
+            Ok(response.json().await?)
+        } else {
+            Err(Self::parse_error_response(response).await)
+        }
+    }
+
     pub async fn get_ocid(&self, character_name: &str) -> Result<String, ApiError> {
         let url = format!("{}/id", MAPLE_API_BASE);
 
@@ -151,7 +663,7 @@ impl MapleApi {
         }
     }
 
-    pub async fn get_character_equipment(&self, ocid: &str) -> Result<serde_json::Value, ApiError> {
+    pub async fn get_character_equipment(&self, ocid: &str) -> Result<CharacterEquipment, ApiError> {
         let url = format!("{}/character/item-equipment", MAPLE_API_BASE);
 
         let response = self
@@ -163,8 +675,8 @@ impl MapleApi {
             .await?;
 
         if response.status().is_success() {
-            let data: serde_json::Value = response.json().await?;
-            Ok(data)
+            let equipment: CharacterEquipment = response.json().await?;
+            Ok(equipment)
         } else {
             Err(Self::parse_error_response(response).await)
         }
@@ -190,4 +702,121 @@ impl MapleApi {
         }
     }
 
+    // HEXA 코어 정보 조회
+    pub async fn get_character_hexamatrix(&self, ocid: &str) -> Result<HexaMatrix, ApiError> {
+        self.get_json("character/hexamatrix", &[("ocid", ocid)]).await
+    }
+
+    // HEXA 스탯 정보 조회
+    pub async fn get_character_hexamatrix_stat(&self, ocid: &str) -> Result<HexaMatrixStat, ApiError> {
+        self.get_json("character/hexamatrix-stat", &[("ocid", ocid)]).await
+    }
+
+    // 심볼 정보 조회
+    pub async fn get_character_symbol_equipment(&self, ocid: &str) -> Result<SymbolEquipment, ApiError> {
+        self.get_json("character/symbol-equipment", &[("ocid", ocid)]).await
+    }
+
+    // 유니온 정보 조회
+    pub async fn get_user_union(&self, ocid: &str) -> Result<UnionInfo, ApiError> {
+        self.get_json("user/union", &[("ocid", ocid)]).await
+    }
+
+    // 유니온 공격대 정보 조회
+    pub async fn get_user_union_raider(&self, ocid: &str) -> Result<UnionRaider, ApiError> {
+        self.get_json("user/union-raider", &[("ocid", ocid)]).await
+    }
+
+    // 유니온 챔피언 정보 조회
+    pub async fn get_user_union_champion(&self, ocid: &str) -> Result<UnionChampionInfo, ApiError> {
+        self.get_json("user/union-champion", &[("ocid", ocid)]).await
+    }
+
+    // 길드 식별자 조회
+    pub async fn get_guild_id(&self, guild_name: &str, world_name: &str) -> Result<String, ApiError> {
+        let response: GuildIdResponse = self
+            .get_json("guild/id", &[("guild_name", guild_name), ("world_name", world_name)])
+            .await?;
+        Ok(response.oguild_id)
+    }
+
+    // 길드 기본 정보 조회
+    pub async fn get_guild_basic(&self, oguild_id: &str) -> Result<GuildBasic, ApiError> {
+        self.get_json("guild/basic", &[("oguild_id", oguild_id)]).await
+    }
+
+    // 종합 랭킹 조회 (class 지정 시 직업 랭킹, 예: "전사-히어로")
+    pub async fn get_ranking_overall(&self, date: &str, world_name: &str, class: Option<&str>, ocid: &str) -> Result<Vec<RankingEntry>, ApiError> {
+        let mut query = vec![("date", date), ("world_name", world_name), ("ocid", ocid)];
+        if let Some(class) = class {
+            query.push(("class", class));
+        }
+        let response: RankingResponse = self.get_json("ranking/overall", &query).await?;
+        Ok(response.ranking)
+    }
+
+    // 유니온 랭킹 조회
+    pub async fn get_ranking_union(&self, date: &str, world_name: &str, ocid: &str) -> Result<Vec<RankingEntry>, ApiError> {
+        let response: RankingResponse = self
+            .get_json("ranking/union", &[("date", date), ("world_name", world_name), ("ocid", ocid)])
+            .await?;
+        Ok(response.ranking)
+    }
+
+    // 무릉도장 랭킹 조회 (difficulty: 0 일반, 1 통달)
+    pub async fn get_ranking_dojang(&self, date: &str, world_name: &str, difficulty: i32, ocid: &str) -> Result<Vec<RankingEntry>, ApiError> {
+        let difficulty = difficulty.to_string();
+        let response: RankingResponse = self
+            .get_json("ranking/dojang", &[("date", date), ("world_name", world_name), ("difficulty", &difficulty), ("ocid", ocid)])
+            .await?;
+        Ok(response.ranking)
+    }
+
+    // 강화 기록 한 페이지 조회 (첫 페이지는 date, 다음 페이지부터는 cursor로 조회)
+    async fn get_history_page<P: HistoryPage>(&self, path: &str, date: Option<&str>, cursor: Option<&str>) -> Result<P, ApiError> {
+        let mut query = vec![("count", HISTORY_PAGE_SIZE)];
+        match cursor {
+            Some(cursor) => query.push(("cursor", cursor)),
+            None => {
+                if let Some(date) = date {
+                    query.push(("date", date));
+                }
+            }
+        }
+        self.get_json(path, &query).await
+    }
+
+    // 특정 날짜의 강화 기록 전체 조회 (next_cursor가 없을 때까지 반복)
+    async fn get_history_by_date<P: HistoryPage>(&self, path: &str, date: &str) -> Result<Vec<P::Item>, ApiError> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let page: P = self.get_history_page(path, Some(date), cursor.as_deref()).await?;
+            let (page_items, next_cursor) = page.into_parts();
+            items.extend(page_items);
+
+            match next_cursor {
+                Some(next) if !next.is_empty() => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    // 스타포스 강화 기록 조회
+    pub async fn get_starforce_history_by_date(&self, date: &str) -> Result<Vec<StarforceHistory>, ApiError> {
+        self.get_history_by_date::<StarforceHistoryPage>("history/starforce", date).await
+    }
+
+    // 큐브 사용 기록 조회
+    pub async fn get_cube_history_by_date(&self, date: &str) -> Result<Vec<PotentialHistory>, ApiError> {
+        self.get_history_by_date::<PotentialHistoryPage>("history/cube", date).await
+    }
+
+    // 잠재능력 재설정 기록 조회
+    pub async fn get_potential_history_by_date(&self, date: &str) -> Result<Vec<PotentialHistory>, ApiError> {
+        self.get_history_by_date::<PotentialHistoryPage>("history/potential", date).await
+    }
 }
@@ -0,0 +1,164 @@
+use crate::db::Database;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// 백업 사유 (파일 이름에 포함, 사유별로 따로 보관 개수 유지)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupReason {
+    Startup,
+    Daily,
+    PreReset,
+    PreImport,
+    PreRestore,
+    Manual,
+}
+
+impl BackupReason {
+    const ALL: [BackupReason; 6] = [
+        Self::Startup,
+        Self::Daily,
+        Self::PreReset,
+        Self::PreImport,
+        Self::PreRestore,
+        Self::Manual,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Startup => "startup",
+            Self::Daily => "daily",
+            Self::PreReset => "pre_reset",
+            Self::PreImport => "pre_import",
+            Self::PreRestore => "pre_restore",
+            Self::Manual => "manual",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackupInfo {
+    pub file_name: String,
+    pub reason: BackupReason,
+    pub created_at: String, // YYYY-MM-DD HH:MM:SS (로컬 시간)
+    pub size_bytes: u64,
+}
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S%3f";
+
+// DB 파일 옆 backups 폴더 (프로필별 DB 위치를 따라감)
+pub fn backup_dir(db: &Database) -> PathBuf {
+    db.path()
+        .parent()
+        .map(|dir| dir.join("backups"))
+        .unwrap_or_else(|| Database::get_data_dir().join("backups"))
+}
+
+// 같은 폴더의 다른 DB 백업과 구분하기 위해 DB 파일 이름을 접두사로 사용
+fn file_prefix(db: &Database) -> String {
+    let stem = db
+        .path()
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "maple_diary".to_string());
+    format!("{}_", stem)
+}
+
+// maple_diary_20250101_120000123_startup.db
+fn parse_file_name(prefix: &str, file_name: &str) -> Option<(chrono::NaiveDateTime, BackupReason)> {
+    let stem = file_name.strip_prefix(prefix)?.strip_suffix(".db")?;
+    let timestamp = stem.get(..18)?;
+    let reason = stem.get(19..)?;
+    let created_at = chrono::NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+    let reason = BackupReason::ALL.into_iter().find(|r| r.as_str() == reason)?;
+    Some((created_at, reason))
+}
+
+// 최신 백업이 앞에 오도록 정렬
+pub fn list_backups(db: &Database) -> Result<Vec<BackupInfo>, String> {
+    let dir = backup_dir(db);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let prefix = file_prefix(db);
+
+    let mut backups = Vec::new();
+    for entry in std::fs::read_dir(&dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if let Some((created_at, reason)) = parse_file_name(&prefix, &file_name) {
+            backups.push(BackupInfo {
+                file_name,
+                reason,
+                created_at: created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                size_bytes: entry.metadata().map(|m| m.len()).unwrap_or(0),
+            });
+        }
+    }
+    backups.sort_by(|a, b| b.file_name.cmp(&a.file_name));
+    Ok(backups)
+}
+
+fn snapshot(db: &Database, reason: BackupReason) -> Result<BackupInfo, String> {
+    let dir = backup_dir(db);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let now = chrono::Local::now().naive_local();
+    let file_name = format!("{}{}_{}.db", file_prefix(db), now.format(TIMESTAMP_FORMAT), reason.as_str());
+    let path = dir.join(&file_name);
+    db.backup_to(&path).map_err(|e| e.to_string())?;
+
+    Ok(BackupInfo {
+        file_name,
+        reason,
+        created_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        size_bytes: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+    })
+}
+
+// DB를 열 때 스키마 마이그레이션 전 상태를 백업
+pub fn snapshot_before_migration(db: &Database) -> Result<BackupInfo, String> {
+    snapshot(db, BackupReason::Startup)
+}
+
+// 사유별로 최신 keep_count개만 남기고 삭제
+pub fn rotate(db: &Database) -> Result<(), String> {
+    let keep_count = db.get_app_settings().map_err(|e| e.to_string())?.backup_keep_count.max(1) as usize;
+    let backups = list_backups(db)?;
+    for reason in BackupReason::ALL {
+        for old in backups.iter().filter(|b| b.reason == reason).skip(keep_count) {
+            std::fs::remove_file(backup_dir(db).join(&old.file_name)).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+pub fn create_backup(db: &Database, reason: BackupReason) -> Result<BackupInfo, String> {
+    let info = snapshot(db, reason)?;
+    rotate(db)?;
+    Ok(info)
+}
+
+// 오늘 만든 백업이 없으면 일일 백업 생성
+pub fn ensure_daily_backup(db: &Database) -> Result<Option<BackupInfo>, String> {
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    if list_backups(db)?.iter().any(|b| b.created_at.starts_with(&today)) {
+        return Ok(None);
+    }
+    create_backup(db, BackupReason::Daily).map(Some)
+}
+
+// 복원 전 현재 상태를 백업해 두고 복원 (보관 개수 정리는 복원 후)
+pub fn restore_backup(db: &mut Database, file_name: &str) -> Result<(), String> {
+    if parse_file_name(&file_prefix(db), file_name).is_none() || file_name.contains(['/', '\\']) {
+        return Err(format!("백업 파일이 아닙니다: {}", file_name));
+    }
+    let path = backup_dir(db).join(file_name);
+    if !path.exists() {
+        return Err(format!("백업 파일을 찾을 수 없습니다: {}", file_name));
+    }
+
+    snapshot(db, BackupReason::PreRestore)?;
+    db.restore_from(&path).map_err(|e| e.to_string())?;
+    rotate(db)
+}
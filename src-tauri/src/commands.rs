@@ -1,7 +1,24 @@
-use crate::api::{CharacterListItem, MapleApi};
-use crate::db::{AppSettings, BossClear, BossSetting, Character, DailyTotal, Database, ExpHistory, HuntingSession, ItemDrop, Settings, WeeklyBossSummary};
+use crate::backup::{self, BackupInfo, BackupReason};
+use crate::api::{CharacterEquipment, CharacterListItem, GuildBasic, HexaMatrixStat, MapleApi, PotentialHistory, RankingEntry};
+use crate::db::{AppSettings, SaveHuntingSessionInput, ApiKeyCharacter, DEFAULT_API_KEY_NAME, SessionConsumable, HuntingMap, SolErdaPiecePrice, CatalogItem, HeldItemValue, ItemPrice, PotentialRecord, StarforceRecord, BossClear, BossSetting, Character, DailyTotal, Database, ExpHistory, GameEvent, HexaSkillHistory, HuntingSession, ItemDrop, ItemDropSummary, MesoExpense, RankingProgress, RankingSnapshot, Settings, SymbolDailyQuest, SymbolProgress, UnionHistory, WeeklyBossSummary};
+use crate::enhancement::{self, EnhancementLedgerItem};
+use crate::expense::{self, FlowPeriod, MesoFlow};
+use crate::game_event::{self, EventCalendar, EventPreset};
+use crate::hexa::{self, HexaCorePlan, HexaLevelCost};
+use crate::search::{SearchHit, SearchSource};
+use crate::secret::ApiKeyExport;
+use crate::session_import::{self, SessionImportPreview, SpreadsheetPreview};
+use crate::session_revision::HuntingSessionRevision;
+use crate::symbol::{self, SymbolProjection};
+use crate::table_export::{self, ExportTable};
+use crate::xlsx_report;
+use crate::item_catalog;
+use crate::journal::{JournalOperation, JournalState, OperationKind, TrashItem};
+use crate::profile::{self, Profile, ProfileConfig};
+use crate::metrics::{self, BuffCombinationMetrics, DailyMetrics, EventExpComparison, MapMetrics, SessionMetrics};
 use crate::AppState;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tauri::State;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -15,30 +32,58 @@ pub struct SearchCharacterResult {
     pub character_exp_rate: String,
 }
 
+// API Key 저장 결과 (키로 조회되는 캐릭터)
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SaveHuntingSessionInput {
-    pub character_id: i64,
-    pub date: String,
-    pub start_level: i32,
-    pub end_level: i32,
-    pub start_exp_percent: f64,
-    pub end_exp_percent: f64,
-    pub start_meso: i64,
-    pub end_meso: i64,
-    pub duration_minutes: i32,
-    // 솔 에르다 (개수 0-20, 게이지 0-1000)
-    pub start_sol_erda: i32,
-    pub end_sol_erda: i32,
-    pub start_sol_erda_gauge: i32,
-    pub end_sol_erda_gauge: i32,
-    // 솔 에르다 조각
-    pub start_sol_erda_piece: i64,
-    pub end_sol_erda_piece: i64,
-    pub sol_erda_piece_price: i64, // 해당 사냥 시점의 조각 가격
-    pub start_screenshot: Option<String>,
-    pub end_screenshot: Option<String>,
-    pub items: String,
-    pub memo: Option<String>,
+pub struct ApiKeyValidation {
+    pub api_key_id: i64,
+    pub characters: Vec<CharacterListItem>,
+}
+
+// 잠겨 있으면 오류
+fn usable_api_key(settings: Option<Settings>) -> Result<String, String> {
+    let settings = settings.ok_or("API Key가 설정되지 않았습니다")?;
+    if settings.locked {
+        return Err("API Key가 잠겨 있습니다. 설정에서 암호를 입력해 주세요".to_string());
+    }
+    Ok(settings.api_key)
+}
+
+// 기본 API Key
+fn stored_api_key(db: &Database) -> Result<String, String> {
+    usable_api_key(db.get_settings().map_err(|e| e.to_string())?)
+}
+
+// 캐릭터를 조회할 수 있는 API Key (연결된 키가 없으면 기본 키)
+fn character_api_key(db: &Database, ocid: &str) -> Result<String, String> {
+    usable_api_key(db.get_api_key_for_ocid(ocid).map_err(|e| e.to_string())?)
+}
+
+// 키로 조회되는 캐릭터 목록 (키 검증 겸용)
+async fn fetch_api_key_characters(api_key: &str) -> Result<Vec<CharacterListItem>, String> {
+    MapleApi::new(api_key)
+        .get_character_list()
+        .await
+        .map_err(|e| format!("API Key 확인 실패: {}", e))
+}
+
+fn to_api_key_characters(api_key_id: i64, characters: &[CharacterListItem]) -> Vec<ApiKeyCharacter> {
+    characters
+        .iter()
+        .map(|c| ApiKeyCharacter {
+            api_key_id,
+            ocid: c.ocid.clone(),
+            character_name: c.character_name.clone(),
+            world_name: c.world_name.clone(),
+            character_class: c.character_class.clone(),
+            character_level: c.character_level,
+        })
+        .collect()
+}
+
+// 활성 프로필 대신 기본 프로필로 시작했으면 그 이유
+#[tauri::command]
+pub fn get_startup_error(state: State<AppState>) -> Option<String> {
+    state.startup_error.clone()
 }
 
 // Settings Commands
@@ -49,9 +94,84 @@ pub fn get_settings(state: State<AppState>) -> Result<Option<Settings>, String>
 }
 
 #[tauri::command]
-pub fn save_api_key(state: State<AppState>, api_key: String) -> Result<(), String> {
+pub fn get_api_keys(state: State<AppState>) -> Result<Vec<Settings>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_api_keys().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_api_key_characters(state: State<AppState>, api_key_id: i64) -> Result<Vec<ApiKeyCharacter>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_api_key_characters(api_key_id).map_err(|e| e.to_string())
+}
+
+// 캐릭터 목록 조회로 키를 확인한 뒤 저장 (같은 이름이면 교체)
+#[tauri::command]
+pub async fn save_api_key(
+    state: State<'_, AppState>,
+    api_key: String,
+    name: Option<String>,
+) -> Result<ApiKeyValidation, String> {
+    let api_key = api_key.trim().to_string();
+    let name = name.map(|n| n.trim().to_string()).unwrap_or_else(|| DEFAULT_API_KEY_NAME.to_string());
+    if api_key.is_empty() {
+        return Err("API Key를 입력해 주세요".to_string());
+    }
+    if name.is_empty() || name.chars().count() > 30 {
+        return Err("API Key 이름은 1 ~ 30자여야 합니다".to_string());
+    }
+
+    let characters = fetch_api_key_characters(&api_key).await?;
+
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let api_key_id = db.save_api_key(&name, &api_key).map_err(|e| e.to_string())?;
+    db.set_api_key_characters(api_key_id, &to_api_key_characters(api_key_id, &characters))
+        .map_err(|e| e.to_string())?;
+    Ok(ApiKeyValidation { api_key_id, characters })
+}
+
+// 새로 만든 캐릭터 반영
+#[tauri::command]
+pub async fn refresh_api_key_characters(
+    state: State<'_, AppState>,
+    api_key_id: i64,
+) -> Result<ApiKeyValidation, String> {
+    let api_key = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let settings = db.get_api_keys().map_err(|e| e.to_string())?
+            .into_iter()
+            .find(|k| k.id == api_key_id);
+        usable_api_key(settings)?
+    };
+
+    let characters = fetch_api_key_characters(&api_key).await?;
+
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.set_api_key_characters(api_key_id, &to_api_key_characters(api_key_id, &characters))
+        .map_err(|e| e.to_string())?;
+    Ok(ApiKeyValidation { api_key_id, characters })
+}
+
+#[tauri::command]
+pub fn delete_api_key(state: State<AppState>, api_key_id: i64) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.save_api_key(&api_key).map_err(|e| e.to_string())
+    db.delete_api_key(api_key_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn unlock_api_key(state: State<AppState>, passphrase: String) -> Result<(), String> {
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    db.unlock_api_key(&passphrase).map_err(|e| e.to_string())
+}
+
+// 암호 보호 설정 (None이면 해제하고 로컬 키로 암호화)
+#[tauri::command]
+pub fn set_api_key_passphrase(state: State<AppState>, passphrase: Option<String>) -> Result<(), String> {
+    if passphrase.as_ref().is_some_and(|p| p.chars().count() < 4) {
+        return Err("암호는 4자 이상이어야 합니다".to_string());
+    }
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    db.set_api_key_passphrase(passphrase.as_deref()).map_err(|e| e.to_string())
 }
 
 // Character Commands
@@ -69,8 +189,7 @@ pub async fn search_character(
     // API Key 조회
     let api_key = {
         let db = state.db.lock().map_err(|e| e.to_string())?;
-        let settings = db.get_settings().map_err(|e| e.to_string())?;
-        settings.ok_or("API Key가 설정되지 않았습니다")?.api_key
+        stored_api_key(&db)?
     };
 
     let api = MapleApi::new(&api_key);
@@ -101,8 +220,7 @@ pub async fn get_character_list(
         Some(k) => k,
         None => {
             let db = state.db.lock().map_err(|e| e.to_string())?;
-            let settings = db.get_settings().map_err(|e| e.to_string())?;
-            settings.ok_or("API Key가 설정되지 않았습니다")?.api_key
+            stored_api_key(&db)?
         }
     };
 
@@ -160,9 +278,8 @@ pub async fn refresh_character(
         let db = state.db.lock().map_err(|e| e.to_string())?;
         let char = db.get_active_character().map_err(|e| e.to_string())?
             .ok_or("활성 캐릭터가 없습니다")?;
-        let settings = db.get_settings().map_err(|e| e.to_string())?
-            .ok_or("API Key가 설정되지 않았습니다")?;
-        (char, settings.api_key)
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
     };
 
     // API에서 최신 정보 가져오기
@@ -199,14 +316,13 @@ pub async fn refresh_character(
 #[tauri::command]
 pub async fn get_character_equipment(
     state: State<'_, AppState>,
-) -> Result<serde_json::Value, String> {
+) -> Result<CharacterEquipment, String> {
     let (character, api_key) = {
         let db = state.db.lock().map_err(|e| e.to_string())?;
         let char = db.get_active_character().map_err(|e| e.to_string())?
             .ok_or("활성 캐릭터가 없습니다")?;
-        let settings = db.get_settings().map_err(|e| e.to_string())?
-            .ok_or("API Key가 설정되지 않았습니다")?;
-        (char, settings.api_key)
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
     };
 
     let api = MapleApi::new(&api_key);
@@ -224,59 +340,34 @@ pub fn get_hunting_sessions(
     db.get_hunting_sessions(character_id, &date).map_err(|e| e.to_string())
 }
 
+fn validate_consumables(consumables: &[SessionConsumable]) -> Result<(), String> {
+    for consumable in consumables {
+        if consumable.quantity <= 0 {
+            return Err("버프/쿠폰 수량은 0보다 커야 합니다".to_string());
+        }
+        if consumable.meso_cost < 0 {
+            return Err("버프/쿠폰 비용은 0 이상이어야 합니다".to_string());
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub fn save_hunting_session(
     state: State<AppState>,
     input: SaveHuntingSessionInput,
 ) -> Result<i64, String> {
+    validate_consumables(&input.consumables)?;
     let db = state.db.lock().map_err(|e| e.to_string())?;
 
-    let exp_gained = input.end_exp_percent - input.start_exp_percent
-        + ((input.end_level - input.start_level) as f64 * 100.0);
-    let meso_gained = input.end_meso - input.start_meso;
-    let sojaebi = input.duration_minutes as f64 / 30.0;
-
-    // 솔 에르다 획득량 계산 (개수 + 게이지/1000)
-    let start_sol_erda_total = input.start_sol_erda as f64 + (input.start_sol_erda_gauge as f64 / 1000.0);
-    let end_sol_erda_total = input.end_sol_erda as f64 + (input.end_sol_erda_gauge as f64 / 1000.0);
-    let sol_erda_gained = end_sol_erda_total - start_sol_erda_total;
-
-    // 솔 에르다 조각 획득량
-    let sol_erda_piece_gained = input.end_sol_erda_piece - input.start_sol_erda_piece;
-
-    let session = HuntingSession {
-        id: 0,
-        character_id: input.character_id,
-        date: input.date,
-        session_order: 0,
-        start_level: input.start_level,
-        end_level: input.end_level,
-        start_exp_percent: input.start_exp_percent,
-        end_exp_percent: input.end_exp_percent,
-        exp_gained,
-        start_meso: input.start_meso,
-        end_meso: input.end_meso,
-        meso_gained,
-        duration_minutes: input.duration_minutes,
-        sojaebi,
-        start_sol_erda: input.start_sol_erda,
-        end_sol_erda: input.end_sol_erda,
-        start_sol_erda_gauge: input.start_sol_erda_gauge,
-        end_sol_erda_gauge: input.end_sol_erda_gauge,
-        sol_erda_gained,
-        start_sol_erda_piece: input.start_sol_erda_piece,
-        end_sol_erda_piece: input.end_sol_erda_piece,
-        sol_erda_piece_gained,
-        sol_erda_piece_price: input.sol_erda_piece_price,
-        start_screenshot: input.start_screenshot,
-        end_screenshot: input.end_screenshot,
-        items: input.items,
-        memo: input.memo,
-        created_at: String::new(),
-        updated_at: String::new(),
+    let sol_erda_piece_price = match input.sol_erda_piece_price {
+        Some(price) => price,
+        None => db.get_sol_erda_piece_price_on(&input.date).map_err(|e| e.to_string())?,
     };
+    let session = input.into_session(sol_erda_piece_price);
 
-    db.save_hunting_session(&session).map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Create, "hunting_sessions", "사냥 기록 추가", |db| db.save_hunting_session(&session))
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -284,14 +375,41 @@ pub fn update_hunting_session(
     state: State<AppState>,
     session: HuntingSession,
 ) -> Result<(), String> {
+    validate_consumables(&session.consumables)?;
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.update_hunting_session(&session).map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Update, "hunting_sessions", "사냥 기록 수정", |db| db.update_hunting_session(&session))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn set_hunting_session_map(state: State<AppState>, id: i64, map_id: Option<i64>) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Update, "hunting_sessions", "사냥터 변경", |db| db.set_hunting_session_map(id, map_id))
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub fn delete_hunting_session(state: State<AppState>, id: i64) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.delete_hunting_session(id).map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Delete, "hunting_sessions", "사냥 기록 삭제", |db| db.delete_hunting_session(id))
+        .map_err(|e| e.to_string())
+}
+
+// 사냥 기록 수정 이력 (최근 수정 순)
+#[tauri::command]
+pub fn get_hunting_session_revisions(state: State<AppState>, session_id: i64) -> Result<Vec<HuntingSessionRevision>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_hunting_session_revisions(session_id).map_err(|e| e.to_string())
+}
+
+// 수정 하나에서 바뀐 필드를 변경 전 값으로 (OCR/API로 채운 값을 고친 뒤 원래 값으로 돌릴 때 등)
+#[tauri::command]
+pub fn revert_hunting_session_revision(state: State<AppState>, revision_id: i64) -> Result<HuntingSession, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Update, "hunting_sessions", "사냥 기록 수정 되돌리기", |db| {
+        db.revert_hunting_session_revision(revision_id)
+    })
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -300,9 +418,13 @@ pub fn get_daily_totals(
     character_id: i64,
     year: i32,
     month: i32,
+    normalize: Option<bool>,
 ) -> Result<Vec<DailyTotal>, String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.get_daily_totals(character_id, year, month).map_err(|e| e.to_string())
+    let mut totals = db.get_daily_totals(character_id, year, month).map_err(|e| e.to_string())?;
+    let calendar = EventCalendar::new(db.get_game_events(character_id).map_err(|e| e.to_string())?);
+    game_event::annotate_daily_totals(&mut totals, &calendar, normalize.unwrap_or(false));
+    Ok(totals)
 }
 
 // Exp History Commands
@@ -313,7 +435,10 @@ pub fn get_exp_history(
     days: i32,
 ) -> Result<Vec<ExpHistory>, String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.get_exp_history(character_id, days).map_err(|e| e.to_string())
+    let mut history = db.get_exp_history(character_id, days).map_err(|e| e.to_string())?;
+    let calendar = EventCalendar::new(db.get_game_events(character_id).map_err(|e| e.to_string())?);
+    game_event::annotate_exp_history(&mut history, &calendar);
+    Ok(history)
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -333,9 +458,8 @@ pub async fn get_weekly_exp_from_api(
         let db = state.db.lock().map_err(|e| e.to_string())?;
         let char = db.get_active_character().map_err(|e| e.to_string())?
             .ok_or("활성 캐릭터가 없습니다")?;
-        let settings = db.get_settings().map_err(|e| e.to_string())?
-            .ok_or("API Key가 설정되지 않았습니다")?;
-        (char, settings.api_key)
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
     };
 
     let api = MapleApi::new(&api_key);
@@ -378,24 +502,124 @@ pub async fn get_weekly_exp_from_api(
 }
 
 // Data Management Commands
+// API Key는 기본적으로 제외 (encrypted + passphrase면 다른 PC에서도 복원 가능)
 #[tauri::command]
-pub fn export_data(state: State<AppState>) -> Result<String, String> {
+pub fn export_data(
+    state: State<AppState>,
+    api_key_export: Option<ApiKeyExport>,
+    passphrase: Option<String>,
+) -> Result<String, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.export_data(api_key_export.unwrap_or_default(), passphrase.as_deref()).map_err(|e| e.to_string())
+}
+
+// 테이블별 CSV (캐릭터/기간 필터, None이면 전체)
+#[tauri::command]
+pub fn export_csv(
+    state: State<AppState>,
+    table: ExportTable,
+    character_id: Option<i64>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+) -> Result<String, String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.export_data().map_err(|e| e.to_string())
+    let sheet = table_export::build_sheet(&db, table, character_id, start_date.as_deref(), end_date.as_deref())
+        .map_err(|e| e.to_string())?;
+    table_export::to_csv(&sheet).map_err(|e| e.to_string())
+}
+
+// 연간 XLSX 리포트 (요약, 월별 일간 기록, 주간 보스 시트)
+#[tauri::command]
+pub fn export_xlsx_report(state: State<AppState>, character_id: i64, year: i32, path: String) -> Result<(), String> {
+    let report = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        xlsx_report::build_report(&db, character_id, year).map_err(|e| e.to_string())?
+    };
+    xlsx_report::save_report(&report, &path).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub fn import_data(state: State<AppState>, data: String) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
+    backup::create_backup(&db, BackupReason::PreImport)?;
     db.import_data(&data).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub fn reset_data(state: State<AppState>) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
+    backup::create_backup(&db, BackupReason::PreReset)?;
     db.reset_data().map_err(|e| e.to_string())
 }
 
+// Backup Commands
+#[tauri::command]
+pub fn list_backups(state: State<AppState>) -> Result<Vec<BackupInfo>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    backup::list_backups(&db)
+}
+
+#[tauri::command]
+pub fn create_backup(state: State<AppState>) -> Result<BackupInfo, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    backup::create_backup(&db, BackupReason::Manual)
+}
+
+#[tauri::command]
+pub fn restore_backup(state: State<AppState>, file_name: String) -> Result<(), String> {
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    backup::restore_backup(&mut db, &file_name)
+}
+
+#[tauri::command]
+pub fn save_backup_keep_count(state: State<AppState>, count: i32) -> Result<(), String> {
+    if !(1..=100).contains(&count) {
+        return Err("백업 보관 개수는 1 ~ 100 사이여야 합니다".to_string());
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.save_backup_keep_count(count).map_err(|e| e.to_string())
+}
+
+// Profile Commands
+#[tauri::command]
+pub fn get_profiles() -> Result<ProfileConfig, String> {
+    Ok(profile::load_config())
+}
+
+#[tauri::command]
+pub fn create_profile(name: String, db_path: Option<String>) -> Result<Profile, String> {
+    profile::create_profile(&name, db_path.as_deref())
+}
+
+// 프로필 DB로 연결 교체
+#[tauri::command]
+pub fn switch_profile(state: State<AppState>, name: String) -> Result<(), String> {
+    let new_db = profile::open_profile(&name)?;
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    *db = new_db;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_profile(name: String) -> Result<(), String> {
+    profile::delete_profile(&name)
+}
+
+#[tauri::command]
+pub fn get_database_path(state: State<AppState>) -> Result<String, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    Ok(db.path().to_string_lossy().to_string())
+}
+
+// 현재 프로필의 DB 파일 위치 변경 (copy_current가 아니면 새 위치의 기존 파일을 그대로 사용)
+#[tauri::command]
+pub fn set_database_path(state: State<AppState>, path: String, copy_current: bool) -> Result<(), String> {
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    let new_db = profile::move_active_database(&db, &path, copy_current)?;
+    *db = new_db;
+    Ok(())
+}
+
 // Boss Settings Commands
 #[tauri::command]
 pub fn get_boss_settings(state: State<AppState>, character_id: i64) -> Result<Vec<BossSetting>, String> {
@@ -427,7 +651,8 @@ pub fn save_boss_setting(state: State<AppState>, input: SaveBossSettingInput) ->
         updated_at: String::new(),
     };
 
-    db.save_boss_setting(&setting).map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Create, "boss_settings", "보스 설정 저장", |db| db.save_boss_setting(&setting))
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -438,8 +663,10 @@ pub fn delete_boss_setting(
     difficulty: String,
 ) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.delete_boss_setting(character_id, &boss_id, &difficulty)
-        .map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Delete, "boss_settings", "보스 설정 삭제", |db| {
+        db.delete_boss_setting(character_id, &boss_id, &difficulty)
+    })
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -451,8 +678,10 @@ pub fn update_boss_party_size(
     party_size: i32,
 ) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.update_boss_setting_party_size(character_id, &boss_id, &difficulty, party_size)
-        .map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Update, "boss_settings", "보스 파티 인원 변경", |db| {
+        db.update_boss_setting_party_size(character_id, &boss_id, &difficulty, party_size)
+    })
+    .map_err(|e| e.to_string())
 }
 
 // Boss Clear Commands
@@ -465,6 +694,7 @@ pub struct SaveBossClearInput {
     pub crystal_price: i64,
     pub party_size: i32,
     pub is_monthly: bool,
+    pub memo: Option<String>,
 }
 
 #[tauri::command]
@@ -486,10 +716,14 @@ pub fn save_boss_clear(state: State<AppState>, input: SaveBossClearInput) -> Res
         week_start_date: period_start,
         crystal_price: input.crystal_price,
         party_size: input.party_size,
+        memo: input.memo.filter(|m| !m.trim().is_empty()),
         created_at: String::new(),
     };
 
-    db.save_boss_clear(&clear, input.is_monthly).map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Create, "boss_clears", "보스 클리어 저장", |db| {
+        db.save_boss_clear(&clear, input.is_monthly)
+    })
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -500,8 +734,10 @@ pub fn delete_boss_clear(
     week_start_date: String,
 ) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.delete_boss_clear(character_id, &boss_id, &week_start_date)
-        .map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Delete, "boss_clears", "보스 클리어 삭제", |db| {
+        db.delete_boss_clear(character_id, &boss_id, &week_start_date)
+    })
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -569,7 +805,48 @@ pub fn get_app_settings(state: State<AppState>) -> Result<AppSettings, String> {
 #[tauri::command]
 pub fn save_app_settings(state: State<AppState>, sol_erda_piece_price: i64) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.save_app_settings(sol_erda_piece_price).map_err(|e| e.to_string())
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    db.save_app_settings(sol_erda_piece_price).map_err(|e| e.to_string())?;
+    db.save_sol_erda_piece_price(&today, sol_erda_piece_price).map_err(|e| e.to_string())
+}
+
+// Sol Erda Piece Price Commands
+#[tauri::command]
+pub fn save_sol_erda_piece_price(state: State<AppState>, date: String, price: i64) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Update, "sol_erda_piece_prices", "조각 시세 저장", |db| {
+        db.save_sol_erda_piece_price(&date, price)
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_sol_erda_piece_price(state: State<AppState>, id: i64) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Delete, "sol_erda_piece_prices", "조각 시세 삭제", |db| db.delete_sol_erda_piece_price(id))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_sol_erda_piece_price_history(state: State<AppState>) -> Result<Vec<SolErdaPiecePrice>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_sol_erda_piece_price_history().map_err(|e| e.to_string())
+}
+
+// 해당 날짜에 유효한 조각 가격 (사냥 기록 기본값용)
+#[tauri::command]
+pub fn get_sol_erda_piece_price(state: State<AppState>, date: String) -> Result<i64, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_sol_erda_piece_price_on(&date).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_auction_fee_rate(state: State<AppState>, rate: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&rate) {
+        return Err("수수료율은 0 ~ 1 사이여야 합니다".to_string());
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.save_auction_fee_rate(rate).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -587,7 +864,17 @@ pub struct DailyTotalWithPieces {
     pub total_sojaebi: f64,
     pub session_count: i32,
     pub total_pieces: i64,
-    pub avg_piece_price: i64,
+    pub avg_piece_price: i64, // 조각 수 가중 평균
+    pub total_piece_value: i64,
+}
+
+// 조각 평가 기준 (획득일 시세 / 현재 시세)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PieceValuation {
+    #[default]
+    Earned,
+    Current,
 }
 
 #[tauri::command]
@@ -596,18 +883,28 @@ pub fn get_daily_totals_with_pieces(
     character_id: i64,
     year: i32,
     month: i32,
+    valuation: Option<PieceValuation>,
 ) -> Result<Vec<DailyTotalWithPieces>, String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    let totals = db.get_daily_totals_with_pieces(character_id, year, month).map_err(|e| e.to_string())?;
+    let mut totals = db.get_daily_totals_with_pieces(character_id, year, month).map_err(|e| e.to_string())?;
 
-    Ok(totals.into_iter().map(|(daily, pieces, price)| DailyTotalWithPieces {
+    if let PieceValuation::Current = valuation.unwrap_or_default() {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let current_price = db.get_sol_erda_piece_price_on(&today).map_err(|e| e.to_string())?;
+        for (_, pieces, value) in totals.iter_mut() {
+            *value = *pieces * current_price;
+        }
+    }
+
+    Ok(totals.into_iter().map(|(daily, pieces, value)| DailyTotalWithPieces {
         date: daily.date,
         total_exp_gained: daily.total_exp_gained,
         total_meso_gained: daily.total_meso_gained,
         total_sojaebi: daily.total_sojaebi,
         session_count: daily.session_count,
         total_pieces: pieces,
-        avg_piece_price: price,
+        avg_piece_price: if pieces != 0 { value / pieces } else { 0 },
+        total_piece_value: value,
     }).collect())
 }
 
@@ -622,8 +919,10 @@ pub fn save_item_drop(
     screenshot: Option<String>,
 ) -> Result<i64, String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.save_item_drop(character_id, &date, &item_name, price, screenshot.as_deref())
-        .map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Create, "item_drops", "득템 기록 추가", |db| {
+        db.save_item_drop(character_id, &date, &item_name, price, screenshot.as_deref())
+    })
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -645,14 +944,48 @@ pub fn update_item_drop(
     screenshot: Option<String>,
 ) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.update_item_drop(id, &item_name, price, screenshot.as_deref())
-        .map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Update, "item_drops", "득템 기록 수정", |db| {
+        db.update_item_drop(id, &item_name, price, screenshot.as_deref())
+    })
+    .map_err(|e| e.to_string())
+}
+
+// 판매 상태 변경 (sold면 판매가와 판매일 필수, 수수료는 현재 설정된 수수료율로 계산)
+#[tauri::command]
+pub fn update_item_drop_sale(
+    state: State<AppState>,
+    id: i64,
+    sale_status: String,
+    sale_price: Option<i64>,
+    sold_date: Option<String>,
+) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+
+    match sale_status.as_str() {
+        "sold" => {
+            let (price, date) = sale_price.zip(sold_date)
+                .ok_or("판매 완료 시 판매가와 판매일이 필요합니다")?;
+            let fee_rate = db.get_app_settings().map_err(|e| e.to_string())?.auction_fee_rate;
+            let fee = expense::auction_fee(price, fee_rate);
+            db.with_journal(OperationKind::Update, "item_drops", "판매 상태 변경", |db| {
+                db.update_item_drop_sale(id, "sold", Some(price), Some(fee), Some(&date))
+            })
+            .map_err(|e| e.to_string())
+        }
+        "listed" | "kept" => db
+            .with_journal(OperationKind::Update, "item_drops", "판매 상태 변경", |db| {
+                db.update_item_drop_sale(id, &sale_status, None, None, None)
+            })
+            .map_err(|e| e.to_string()),
+        _ => Err(format!("알 수 없는 판매 상태입니다: {}", sale_status)),
+    }
 }
 
 #[tauri::command]
 pub fn delete_item_drop(state: State<AppState>, id: i64) -> Result<(), String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.delete_item_drop(id).map_err(|e| e.to_string())
+    db.with_journal(OperationKind::Delete, "item_drops", "득템 기록 삭제", |db| db.delete_item_drop(id))
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -667,11 +1000,1198 @@ pub fn get_monthly_item_drops(
 }
 
 #[tauri::command]
-pub fn get_months_with_data(
+pub fn get_monthly_drop_summary(
     state: State<AppState>,
     character_id: i64,
     year: i32,
-) -> Result<Vec<i32>, String> {
+    month: i32,
+) -> Result<ItemDropSummary, String> {
     let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.get_months_with_data(character_id, year).map_err(|e| e.to_string())
+    db.get_monthly_drop_summary(character_id, year, month).map_err(|e| e.to_string())
+}
+
+// Item Catalog Commands
+fn validate_item_category(category: &str) -> Result<(), String> {
+    if item_catalog::is_valid_category(category) {
+        Ok(())
+    } else {
+        Err(format!("알 수 없는 아이템 카테고리입니다: {}", category))
+    }
+}
+
+// 득템 이름 자동완성
+#[tauri::command]
+pub fn search_items(
+    state: State<AppState>,
+    query: String,
+    limit: Option<i32>,
+) -> Result<Vec<CatalogItem>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.search_item_catalog(&query, limit.unwrap_or(10)).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_catalog_item(
+    state: State<AppState>,
+    canonical_name: String,
+    category: String,
+) -> Result<i64, String> {
+    validate_item_category(&category)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    if db.find_catalog_item(&canonical_name).map_err(|e| e.to_string())?.is_some() {
+        return Err(format!("이미 등록된 아이템입니다: {}", canonical_name));
+    }
+    db.with_journal(OperationKind::Create, "item_catalog", "아이템 등록", |db| {
+        db.save_catalog_item(canonical_name.trim(), &category)
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_catalog_item_category(
+    state: State<AppState>,
+    id: i64,
+    category: String,
+) -> Result<(), String> {
+    validate_item_category(&category)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Update, "item_catalog", "아이템 분류 변경", |db| {
+        db.update_catalog_item_category(id, &category)
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn add_item_alias(state: State<AppState>, item_id: i64, alias: String) -> Result<(), String> {
+    if item_catalog::normalize_item_name(&alias).is_empty() {
+        return Err("별칭이 비어 있습니다".to_string());
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.add_item_alias(item_id, alias.trim()).map_err(|e| e.to_string())
+}
+
+// 오타 등으로 생긴 항목을 정식 항목에 병합
+// 별칭(item_aliases)이 함께 바뀌어 실행 취소 기록에는 남기지 않음
+#[tauri::command]
+pub fn merge_catalog_items(state: State<AppState>, source_id: i64, target_id: i64) -> Result<(), String> {
+    if source_id == target_id {
+        return Err("같은 아이템은 병합할 수 없습니다".to_string());
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.merge_catalog_item(source_id, target_id).map_err(|e| e.to_string())
+}
+
+// Item Price Commands
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ItemPriceInput {
+    pub item_name: String,
+    pub date: String,
+    pub price: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HeldItemValuation {
+    pub items: Vec<HeldItemValue>,
+    pub total_entry_value: i64,
+    pub total_current_value: i64,
+}
+
+// 시세는 0 이상, 날짜는 YYYY-MM-DD (날짜순 정렬이 문자열 비교로 이루어짐)
+fn validate_item_price(date: &str, price: i64) -> Result<(), String> {
+    if date.len() != 10 || chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+        return Err(format!("날짜 형식이 올바르지 않습니다 (YYYY-MM-DD): {}", date));
+    }
+    if price < 0 {
+        return Err("시세는 0 이상이어야 합니다".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn save_item_price(
+    state: State<AppState>,
+    item_name: String,
+    date: String,
+    price: i64,
+) -> Result<i64, String> {
+    validate_item_price(&date, price)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Create, "item_price_history", "시세 저장", |db| {
+        let (item_id, _) = db.resolve_catalog_item(&item_name)?;
+        db.save_item_price(item_id, &date, price, "manual")?;
+        Ok(item_id)
+    })
+    .map_err(|e| e.to_string())
+}
+
+// 외부 시세 목록 일괄 저장 (source = imported)
+#[tauri::command]
+pub fn import_item_prices(state: State<AppState>, prices: Vec<ItemPriceInput>) -> Result<usize, String> {
+    for input in &prices {
+        validate_item_price(&input.date, input.price).map_err(|e| format!("{}: {}", input.item_name, e))?;
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Create, "item_price_history", "시세 가져오기", |db| {
+        for input in &prices {
+            let (item_id, _) = db.resolve_catalog_item(&input.item_name)?;
+            db.save_item_price(item_id, &input.date, input.price, "imported")?;
+        }
+        Ok(prices.len())
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_item_price(state: State<AppState>, id: i64) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Delete, "item_price_history", "시세 삭제", |db| db.delete_item_price(id))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_item_price_trend(
+    state: State<AppState>,
+    item_id: i64,
+    days: Option<i32>,
+) -> Result<Vec<ItemPrice>, String> {
+    let since = (chrono::Local::now() - chrono::Duration::days(days.unwrap_or(90) as i64))
+        .format("%Y-%m-%d")
+        .to_string();
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_item_price_trend(item_id, &since).map_err(|e| e.to_string())
+}
+
+// 보유 중인 득템을 최신 시세로 평가
+#[tauri::command]
+pub fn get_held_item_valuation(state: State<AppState>, character_id: i64) -> Result<HeldItemValuation, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let items = db.get_held_item_values(character_id).map_err(|e| e.to_string())?;
+
+    Ok(HeldItemValuation {
+        total_entry_value: items.iter().map(|item| item.entry_price).sum(),
+        total_current_value: items.iter().map(|item| item.current_value).sum(),
+        items,
+    })
+}
+
+#[tauri::command]
+pub fn get_months_with_data(
+    state: State<AppState>,
+    character_id: i64,
+    year: i32,
+) -> Result<Vec<i32>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_months_with_data(character_id, year).map_err(|e| e.to_string())
+}
+
+// HEXA Matrix Commands
+// API에서 HEXA 코어 레벨을 가져와 오늘 날짜로 기록
+#[tauri::command]
+pub async fn sync_hexa_matrix(
+    state: State<'_, AppState>,
+) -> Result<Vec<HexaSkillHistory>, String> {
+    let (character, api_key) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let char = db.get_active_character().map_err(|e| e.to_string())?
+            .ok_or("활성 캐릭터가 없습니다")?;
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
+    };
+
+    let api = MapleApi::new(&api_key);
+    let matrix = api.get_character_hexamatrix(&character.ocid).await.map_err(|e| e.to_string())?;
+
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    for core in &matrix.character_hexa_core_equipment {
+        db.save_hexa_skill_level(character.id, &today, &core.hexa_core_name, &core.hexa_core_type, core.hexa_core_level)
+            .map_err(|e| e.to_string())?;
+    }
+
+    db.get_latest_hexa_skill_levels(character.id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_hexa_matrix_stat(
+    state: State<'_, AppState>,
+) -> Result<HexaMatrixStat, String> {
+    let (character, api_key) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let char = db.get_active_character().map_err(|e| e.to_string())?
+            .ok_or("활성 캐릭터가 없습니다")?;
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
+    };
+
+    let api = MapleApi::new(&api_key);
+    api.get_character_hexamatrix_stat(&character.ocid).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_hexa_skill_history(
+    state: State<AppState>,
+    character_id: i64,
+    hexa_core_name: Option<String>,
+) -> Result<Vec<HexaSkillHistory>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_hexa_skill_history(character_id, hexa_core_name.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_hexa_cost_table(hexa_core_type: String) -> Result<Vec<HexaLevelCost>, String> {
+    Ok(hexa::get_cost_table(&hexa_core_type))
+}
+
+// 최근 사냥 기록의 시간당 솔 에르다/조각 획득량으로 각 코어의 다음 레벨까지 필요한 사냥 시간 계산
+#[tauri::command]
+pub fn plan_hexa_cores(
+    state: State<AppState>,
+    character_id: i64,
+    days: Option<i32>,
+) -> Result<Vec<HexaCorePlan>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let rate = db.get_sol_erda_rate(character_id, days.unwrap_or(14)).map_err(|e| e.to_string())?;
+    let levels = db.get_latest_hexa_skill_levels(character_id).map_err(|e| e.to_string())?;
+
+    Ok(levels
+        .iter()
+        .filter(|core| hexa::has_cost_table(&core.hexa_core_type))
+        .map(|core| hexa::plan_core(&core.hexa_core_name, &core.hexa_core_type, core.hexa_core_level, &rate))
+        .collect())
+}
+
+// Symbol Commands
+// API에서 심볼 레벨/성장치를 가져와 오늘 날짜로 기록
+#[tauri::command]
+pub async fn sync_symbols(
+    state: State<'_, AppState>,
+) -> Result<Vec<SymbolProgress>, String> {
+    let (character, api_key) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let char = db.get_active_character().map_err(|e| e.to_string())?
+            .ok_or("활성 캐릭터가 없습니다")?;
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
+    };
+
+    let api = MapleApi::new(&api_key);
+    let equipment = api.get_character_symbol_equipment(&character.ocid).await.map_err(|e| e.to_string())?;
+
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    for s in &equipment.symbol {
+        db.save_symbol_progress(
+            character.id,
+            &today,
+            &s.symbol_name,
+            s.symbol_level,
+            s.symbol_growth_count as i64,
+            s.symbol_require_growth_count as i64,
+        ).map_err(|e| e.to_string())?;
+    }
+
+    db.get_latest_symbol_progress(character.id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_symbol_progress_history(
+    state: State<AppState>,
+    character_id: i64,
+    symbol_name: Option<String>,
+) -> Result<Vec<SymbolProgress>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_symbol_progress_history(character_id, symbol_name.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn set_symbol_daily_quest(
+    state: State<AppState>,
+    character_id: i64,
+    date: String,
+    symbol_name: String,
+    completed: bool,
+) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.set_symbol_daily_quest(character_id, &date, &symbol_name, completed)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_symbol_daily_quests(
+    state: State<AppState>,
+    character_id: i64,
+    date: String,
+) -> Result<Vec<SymbolDailyQuest>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_symbol_daily_quests(character_id, &date).map_err(|e| e.to_string())
+}
+
+// 심볼별 만렙 예상일 및 남은 강화 비용
+// 최근 days일 동안의 일일 퀘스트 완료율을 반영 (기록이 없으면 매일 완료로 가정)
+#[tauri::command]
+pub fn project_symbols(
+    state: State<AppState>,
+    character_id: i64,
+    days: Option<i32>,
+) -> Result<Vec<SymbolProjection>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let progress = db.get_latest_symbol_progress(character_id).map_err(|e| e.to_string())?;
+
+    let today = chrono::Local::now().date_naive();
+    let since = (today - chrono::Duration::days(days.unwrap_or(28).max(1) as i64 - 1))
+        .format("%Y-%m-%d").to_string();
+
+    let mut projections = Vec::new();
+    for p in &progress {
+        let stats = db.get_symbol_quest_stats(character_id, &p.symbol_name, &since)
+            .map_err(|e| e.to_string())?;
+        let completion_rate = match stats {
+            Some((first_date, completed)) => {
+                let first = chrono::NaiveDate::parse_from_str(&first_date, "%Y-%m-%d").unwrap_or(today);
+                let tracked_days = ((today - first).num_days() + 1).max(1);
+                (completed as f64 / tracked_days as f64).min(1.0)
+            }
+            None => 1.0,
+        };
+
+        if let Some(projection) = symbol::project_symbol(
+            &p.symbol_name,
+            p.symbol_level,
+            p.symbol_growth_count,
+            completion_rate,
+            today,
+        ) {
+            projections.push(projection);
+        }
+    }
+
+    Ok(projections)
+}
+
+// Union Commands
+#[tauri::command]
+pub async fn sync_union(
+    state: State<'_, AppState>,
+) -> Result<UnionHistory, String> {
+    let (character, api_key) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let char = db.get_active_character().map_err(|e| e.to_string())?
+            .ok_or("활성 캐릭터가 없습니다")?;
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
+    };
+
+    let api = MapleApi::new(&api_key);
+    let union = api.get_user_union(&character.ocid).await.map_err(|e| e.to_string())?;
+
+    let history = UnionHistory {
+        id: 0,
+        character_id: character.id,
+        date: String::new(),
+        union_level: union.union_level,
+        union_grade: union.union_grade,
+        union_artifact_level: union.union_artifact_level,
+        union_artifact_exp: union.union_artifact_exp,
+        union_artifact_point: union.union_artifact_point,
+        created_at: String::new(),
+    };
+
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.save_union_history(&history).map_err(|e| e.to_string())?;
+    db.get_union_history(character.id)
+        .map_err(|e| e.to_string())?
+        .pop()
+        .ok_or_else(|| "유니온 정보 저장에 실패했습니다".to_string())
+}
+
+#[tauri::command]
+pub fn get_union_history(
+    state: State<AppState>,
+    character_id: i64,
+) -> Result<Vec<UnionHistory>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_union_history(character_id).map_err(|e| e.to_string())
+}
+
+// 유니온 공격대원과 등록된 캐릭터 매칭 결과
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnionMemberStatus {
+    pub block_type: String,
+    pub block_class: String,
+    pub block_level: i32,
+    pub is_champion: bool,
+    pub character_id: Option<i64>,
+    pub character_name: Option<String>,
+    pub boss_setting_count: i32,
+}
+
+// 유니온 공격대 목록을 등록된 캐릭터와 대조
+// 공격대 블록에는 닉네임이 없으므로 직업+레벨로 매칭하고, 챔피언은 닉네임으로 매칭
+#[tauri::command]
+pub async fn get_union_member_status(
+    state: State<'_, AppState>,
+) -> Result<Vec<UnionMemberStatus>, String> {
+    let (character, api_key) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let char = db.get_active_character().map_err(|e| e.to_string())?
+            .ok_or("활성 캐릭터가 없습니다")?;
+        let api_key = character_api_key(&db, &char.ocid)?;
+        (char, api_key)
+    };
+
+    let api = MapleApi::new(&api_key);
+    let (raider, champion) = tokio::join!(
+        api.get_user_union_raider(&character.ocid),
+        api.get_user_union_champion(&character.ocid),
+    );
+    let raider = raider.map_err(|e| e.to_string())?;
+    let champions = champion.map_err(|e| e.to_string())?.union_champion;
+
+    let (characters, boss_counts) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        (
+            db.get_characters().map_err(|e| e.to_string())?,
+            db.get_enabled_boss_setting_counts().map_err(|e| e.to_string())?,
+        )
+    };
+    let boss_count_of = |id: i64| {
+        boss_counts.iter().find(|(cid, _)| *cid == id).map(|(_, n)| *n).unwrap_or(0)
+    };
+
+    let mut matched_ids: Vec<i64> = Vec::new();
+    let mut members: Vec<UnionMemberStatus> = Vec::new();
+
+    // 챔피언: 닉네임으로 정확히 매칭
+    for champ in &champions {
+        let found = characters.iter().find(|c| c.character_name == champ.champion_name);
+        if let Some(c) = found {
+            matched_ids.push(c.id);
+        }
+        members.push(UnionMemberStatus {
+            block_type: "챔피언".to_string(),
+            block_class: champ.champion_class.clone(),
+            block_level: 0,
+            is_champion: true,
+            character_id: found.map(|c| c.id),
+            character_name: Some(champ.champion_name.clone()),
+            boss_setting_count: found.map(|c| boss_count_of(c.id)).unwrap_or(0),
+        });
+    }
+
+    // 공격대원: 같은 직업 + 같은 레벨인 미매칭 캐릭터
+    for block in &raider.union_block {
+        let level = block.level();
+        let found = characters.iter().find(|c| {
+            !matched_ids.contains(&c.id)
+                && c.character_class == block.block_class
+                && c.character_level == level
+        });
+        if let Some(c) = found {
+            matched_ids.push(c.id);
+        }
+        members.push(UnionMemberStatus {
+            block_type: block.block_type.clone(),
+            block_class: block.block_class.clone(),
+            block_level: level,
+            is_champion: false,
+            character_id: found.map(|c| c.id),
+            character_name: found.map(|c| c.character_name.clone()),
+            boss_setting_count: found.map(|c| boss_count_of(c.id)).unwrap_or(0),
+        });
+    }
+
+    Ok(members)
+}
+
+// Guild / Ranking Commands
+#[tauri::command]
+pub async fn search_guild(
+    state: State<'_, AppState>,
+    guild_name: String,
+    world_name: String,
+) -> Result<GuildBasic, String> {
+    let api_key = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        stored_api_key(&db)?
+    };
+
+    let api = MapleApi::new(&api_key);
+    let oguild_id = api.get_guild_id(&guild_name, &world_name).await.map_err(|e| e.to_string())?;
+    api.get_guild_basic(&oguild_id).await.map_err(|e| e.to_string())
+}
+
+// ocid로 조회한 랭킹 목록에서 해당 캐릭터 항목 찾기
+fn find_ranking<'a>(entries: &'a [RankingEntry], character_name: &str) -> Option<&'a RankingEntry> {
+    entries
+        .iter()
+        .find(|e| e.character_name == character_name)
+}
+
+// 랭킹 동기화 결과 (조회에 실패한 캐릭터는 이유와 함께 따로 전달, 순위 없음과 구분)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RankingSyncResult {
+    pub snapshots: Vec<RankingSnapshot>,
+    pub failures: Vec<RankingSyncFailure>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RankingSyncFailure {
+    pub character_id: i64,
+    pub character_name: String,
+    pub reason: String,
+}
+
+// 캐릭터 하나의 랭킹 조회 (하나라도 실패하면 빈 순위로 덮어쓰지 않도록 오류)
+async fn fetch_ranking_snapshot(api: &MapleApi, character: &Character, date: &str) -> Result<RankingSnapshot, String> {
+    let ocid = &character.ocid;
+    let world = &character.world_name;
+    let name = &character.character_name;
+
+    let (overall, union, dojang) = tokio::join!(
+        api.get_ranking_overall(date, world, None, ocid),
+        api.get_ranking_union(date, world, ocid),
+        api.get_ranking_dojang(date, world, 1, ocid),
+    );
+    let overall = overall.map_err(|e| format!("종합 랭킹 조회 실패: {}", e))?;
+    let union = union.map_err(|e| format!("유니온 랭킹 조회 실패: {}", e))?;
+    let dojang = dojang.map_err(|e| format!("무릉도장 랭킹 조회 실패: {}", e))?;
+    let world_entry = find_ranking(&overall, name);
+
+    // 직업 랭킹은 종합 랭킹 응답의 직업명으로 다시 조회 ("전사-히어로")
+    let class_rank = match world_entry {
+        Some(entry) if !entry.class_name.is_empty() => {
+            let sub_class = if entry.sub_class_name.is_empty() { "전체 전직" } else { &entry.sub_class_name };
+            let class = format!("{}-{}", entry.class_name, sub_class);
+            let entries = api.get_ranking_overall(date, world, Some(&class), ocid).await
+                .map_err(|e| format!("직업 랭킹 조회 실패: {}", e))?;
+            find_ranking(&entries, name).map(|e| e.ranking)
+        }
+        _ => None,
+    };
+
+    let dojang_entry = find_ranking(&dojang, name);
+
+    Ok(RankingSnapshot {
+        id: 0,
+        character_id: character.id,
+        date: date.to_string(),
+        world_rank: world_entry.map(|e| e.ranking),
+        class_rank,
+        union_rank: find_ranking(&union, name).map(|e| e.ranking),
+        dojang_rank: dojang_entry.map(|e| e.ranking),
+        dojang_floor: dojang_entry.and_then(|e| e.dojang_floor),
+        created_at: String::new(),
+    })
+}
+
+// 등록된 모든 캐릭터의 월드/직업/유니온/무릉 랭킹 스냅샷 저장
+// 랭킹은 전날 기준으로 집계되므로 날짜 미지정 시 어제 날짜를 사용
+#[tauri::command]
+pub async fn sync_rankings(
+    state: State<'_, AppState>,
+    date: Option<String>,
+) -> Result<RankingSyncResult, String> {
+    let mut failures = Vec::new();
+
+    // 캐릭터마다 조회 가능한 키 사용 (키가 잠긴 캐릭터는 실패로 기록하고 나머지는 계속)
+    let characters = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let mut characters = Vec::new();
+        for character in db.get_characters().map_err(|e| e.to_string())? {
+            match character_api_key(&db, &character.ocid) {
+                Ok(api_key) => characters.push((character, api_key)),
+                Err(reason) => failures.push(RankingSyncFailure {
+                    character_id: character.id,
+                    character_name: character.character_name,
+                    reason,
+                }),
+            }
+        }
+        characters
+    };
+
+    let date = date.unwrap_or_else(|| {
+        (chrono::Local::now() - chrono::Duration::days(1)).format("%Y-%m-%d").to_string()
+    });
+    let mut snapshots = Vec::new();
+
+    for (character, api_key) in &characters {
+        match fetch_ranking_snapshot(&MapleApi::new(api_key), character, &date).await {
+            Ok(snapshot) => snapshots.push(snapshot),
+            Err(reason) => failures.push(RankingSyncFailure {
+                character_id: character.id,
+                character_name: character.character_name.clone(),
+                reason,
+            }),
+        }
+    }
+
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    for snapshot in &snapshots {
+        db.save_ranking_snapshot(snapshot).map_err(|e| e.to_string())?;
+    }
+
+    Ok(RankingSyncResult { snapshots, failures })
+}
+
+#[tauri::command]
+pub fn get_ranking_progress(
+    state: State<AppState>,
+    character_id: i64,
+    days: i32,
+) -> Result<Vec<RankingProgress>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_ranking_progress(character_id, days).map_err(|e| e.to_string())
+}
+
+// Enhancement History Commands
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EnhancementImportResult {
+    pub starforce_count: usize,
+    pub cube_count: usize,
+    pub potential_count: usize,
+    // 모든 키의 기록을 저장한 마지막 날짜 (다음 날부터 이어서 가져오기)
+    pub completed_date: Option<String>,
+    pub skipped_locked_keys: usize,
+    // 중간에 실패하면 그때까지 저장한 기록은 유지하고 오류만 전달
+    pub error: Option<String>,
+}
+
+fn to_potential_record(h: PotentialHistory) -> PotentialRecord {
+    PotentialRecord {
+        id: h.id,
+        character_name: h.character_name,
+        date_create: h.date_create,
+        cube_type: h.cube_type,
+        item_upgrade_result: h.item_upgrade_result,
+        item_equipment_part: h.item_equipment_part,
+        item_level: h.item_level,
+        target_item: h.target_item,
+        potential_option_grade: h.potential_option_grade,
+        additional_potential_option_grade: h.additional_potential_option_grade,
+        after_potential_option: serde_json::to_string(&h.after_potential_option).unwrap_or_else(|_| "[]".to_string()),
+        after_additional_potential_option: serde_json::to_string(&h.after_additional_potential_option).unwrap_or_else(|_| "[]".to_string()),
+    }
+}
+
+// API Key 기준 스타포스/큐브/잠재능력 재설정 기록을 날짜별로 가져와 저장
+// 기록은 키의 넥슨 계정 단위이므로 등록된 모든 키로 조회
+// 이미 저장된 기록(같은 ID)은 건너뛰고 새로 저장된 건수를 반환
+// Open API 호출 한도를 넘지 않도록 기록 요청 사이 대기
+const HISTORY_REQUEST_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+// 하루치 기록을 모든 키로 가져와 저장
+async fn fetch_enhancement_day(
+    state: &State<'_, AppState>,
+    api_keys: &[String],
+    date: chrono::NaiveDate,
+    result: &mut EnhancementImportResult,
+) -> Result<(), String> {
+    let date_str = date.format("%Y-%m-%d").to_string();
+    let covers = |start: &str| date_str.as_str() >= start;
+
+    for api_key in api_keys {
+        let api = MapleApi::new(api_key);
+
+        let mut starforce_records: Vec<StarforceRecord> = Vec::new();
+        if covers(enhancement::STARFORCE_HISTORY_START) {
+            let starforce = api.get_starforce_history_by_date(&date_str).await.map_err(|e| e.to_string())?;
+            starforce_records = starforce.into_iter().map(|h| {
+                // 여러 할인 이벤트가 겹치면 가장 큰 할인율 적용
+                let cost_discount_rate = h.starforce_event_list.iter().flatten()
+                    .filter_map(|e| e.cost_discount_rate.as_deref())
+                    .map(enhancement::parse_discount_rate)
+                    .fold(0.0, f64::max);
+                StarforceRecord {
+                    id: h.id,
+                    character_name: h.character_name,
+                    world_name: h.world_name,
+                    target_item: h.target_item,
+                    date_create: h.date_create,
+                    before_starforce_count: h.before_starforce_count,
+                    after_starforce_count: h.after_starforce_count,
+                    item_upgrade_result: h.item_upgrade_result,
+                    destroy_defence: h.destroy_defence == "파괴 방지 적용",
+                    chance_time: h.chance_time == "찬스타임 적용",
+                    cost_discount_rate,
+                }
+            }).collect();
+            tokio::time::sleep(HISTORY_REQUEST_INTERVAL).await;
+        }
+
+        let mut cube_records: Vec<PotentialRecord> = Vec::new();
+        if covers(enhancement::CUBE_HISTORY_START) {
+            let cube = api.get_cube_history_by_date(&date_str).await.map_err(|e| e.to_string())?;
+            cube_records = cube.into_iter().map(to_potential_record).collect();
+            tokio::time::sleep(HISTORY_REQUEST_INTERVAL).await;
+        }
+
+        let mut potential_records: Vec<PotentialRecord> = Vec::new();
+        if covers(enhancement::POTENTIAL_HISTORY_START) {
+            let potential = api.get_potential_history_by_date(&date_str).await.map_err(|e| e.to_string())?;
+            potential_records = potential.into_iter().map(to_potential_record).collect();
+            tokio::time::sleep(HISTORY_REQUEST_INTERVAL).await;
+        }
+
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        result.starforce_count += db.save_starforce_records(&starforce_records).map_err(|e| e.to_string())?;
+        result.cube_count += db.save_cube_records(&cube_records).map_err(|e| e.to_string())?;
+        result.potential_count += db.save_potential_records(&potential_records).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// 날짜별로 강화 기록 가져오기 (잠긴 키는 건너뜀, 실패하면 마지막 완료 날짜와 오류를 반환)
+#[tauri::command]
+pub async fn import_enhancement_history(
+    state: State<'_, AppState>,
+    start_date: String,
+    end_date: Option<String>,
+) -> Result<EnhancementImportResult, String> {
+    let mut result = EnhancementImportResult {
+        starforce_count: 0,
+        cube_count: 0,
+        potential_count: 0,
+        completed_date: None,
+        skipped_locked_keys: 0,
+        error: None,
+    };
+
+    let api_keys = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let mut api_keys = Vec::new();
+        for settings in db.get_api_keys().map_err(|e| e.to_string())? {
+            if settings.locked {
+                result.skipped_locked_keys += 1;
+                continue;
+            }
+            api_keys.push(settings.api_key);
+        }
+        if api_keys.is_empty() {
+            return Err(if result.skipped_locked_keys > 0 {
+                "API Key가 잠겨 있습니다. 설정에서 암호를 입력해 주세요".to_string()
+            } else {
+                "API Key가 설정되지 않았습니다".to_string()
+            });
+        }
+        api_keys
+    };
+
+    let history_start = chrono::NaiveDate::parse_from_str(enhancement::CUBE_HISTORY_START, "%Y-%m-%d")
+        .map_err(|e| e.to_string())?;
+    let start = chrono::NaiveDate::parse_from_str(&start_date, "%Y-%m-%d").map_err(|e| e.to_string())?
+        .max(history_start);
+    let today = chrono::Local::now().date_naive();
+    let end = match end_date {
+        Some(d) => chrono::NaiveDate::parse_from_str(&d, "%Y-%m-%d").map_err(|e| e.to_string())?.min(today),
+        None => today,
+    };
+
+    let mut date = start;
+    while date <= end {
+        if let Err(e) = fetch_enhancement_day(&state, &api_keys, date, &mut result).await {
+            result.error = Some(format!("{} 기록을 가져오지 못했습니다: {}", date.format("%Y-%m-%d"), e));
+            break;
+        }
+        result.completed_date = Some(date.format("%Y-%m-%d").to_string());
+        date += chrono::Duration::days(1);
+    }
+
+    Ok(result)
+}
+
+// 기간 내 아이템별 스타포스 메소 지출 및 큐브 사용량
+#[tauri::command]
+pub async fn get_enhancement_ledger(
+    state: State<'_, AppState>,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<EnhancementLedgerItem>, String> {
+    let (starforce, cubes, mut item_levels, characters) = {
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let starforce = db.get_starforce_attempt_groups(&start_date, &end_date).map_err(|e| e.to_string())?;
+        let cubes = db.get_cube_usage_groups(&start_date, &end_date).map_err(|e| e.to_string())?;
+        let item_levels: HashMap<String, i32> = db.get_known_item_levels().map_err(|e| e.to_string())?
+            .into_iter()
+            .collect();
+
+        // 큐브 기록으로 레벨을 알 수 없는 아이템을 스타포스한 캐릭터 (키가 없으면 제외)
+        let mut characters = Vec::new();
+        for character in db.get_characters().map_err(|e| e.to_string())? {
+            let needs_level = starforce.iter().any(|g| {
+                g.character_name == character.character_name && !item_levels.contains_key(&g.target_item)
+            });
+            if !needs_level {
+                continue;
+            }
+            if let Ok(api_key) = character_api_key(&db, &character.ocid) {
+                characters.push((character, api_key));
+            }
+        }
+        (starforce, cubes, item_levels, characters)
+    };
+
+    // 나머지는 현재 착용 장비의 기본 레벨로 채우고, 그래도 모르면 비용 미상으로 표시
+    for (character, api_key) in &characters {
+        let Ok(equipment) = MapleApi::new(api_key).get_character_equipment(&character.ocid).await else {
+            continue;
+        };
+        for item in &equipment.item_equipment {
+            if let Some(level) = item.item_base_option.base_equipment_level.filter(|level| *level > 0) {
+                item_levels.entry(item.item_name.clone()).or_insert(level);
+            }
+        }
+    }
+
+    Ok(enhancement::build_ledger(&starforce, &cubes, &item_levels))
+}
+
+// Meso Expense Commands
+fn validate_expense(category: &str, amount: i64) -> Result<(), String> {
+    if !expense::is_valid_category(category) {
+        return Err(format!("알 수 없는 지출 카테고리입니다: {}", category));
+    }
+    if amount <= 0 {
+        return Err("지출 금액은 0보다 커야 합니다".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn save_meso_expense(
+    state: State<AppState>,
+    character_id: i64,
+    date: String,
+    category: String,
+    amount: i64,
+    memo: Option<String>,
+) -> Result<i64, String> {
+    validate_expense(&category, amount)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Create, "meso_expenses", "메소 지출 추가", |db| {
+        db.save_meso_expense(character_id, &date, &category, amount, memo.as_deref())
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_meso_expense(
+    state: State<AppState>,
+    id: i64,
+    date: String,
+    category: String,
+    amount: i64,
+    memo: Option<String>,
+) -> Result<(), String> {
+    validate_expense(&category, amount)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Update, "meso_expenses", "메소 지출 수정", |db| {
+        db.update_meso_expense(id, &date, &category, amount, memo.as_deref())
+    })
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_meso_expense(state: State<AppState>, id: i64) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Delete, "meso_expenses", "메소 지출 삭제", |db| db.delete_meso_expense(id))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_meso_expenses(
+    state: State<AppState>,
+    character_id: i64,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<MesoExpense>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_meso_expenses(character_id, &start_date, &end_date).map_err(|e| e.to_string())
+}
+
+// 기간별(일/주/월) 수입, 지출, 순수익
+#[tauri::command]
+pub fn get_meso_flow(
+    state: State<AppState>,
+    character_id: i64,
+    start_date: String,
+    end_date: String,
+    period: FlowPeriod,
+) -> Result<Vec<MesoFlow>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let daily = db.get_daily_meso_flow(character_id, &start_date, &end_date).map_err(|e| e.to_string())?;
+
+    Ok(expense::aggregate_flow(&daily, period))
+}
+
+// Hunting Metrics Commands
+// 기간 내 세션별 시간당 효율 (백분위는 캐릭터 전체 기록 기준)
+#[tauri::command]
+pub fn get_session_metrics(
+    state: State<AppState>,
+    character_id: i64,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<SessionMetrics>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let sessions = db.get_all_hunting_sessions(character_id).map_err(|e| e.to_string())?;
+    let drop_values = db.get_daily_drop_values(character_id).map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+
+    Ok(metrics::session_metrics(&sessions, &drop_values)
+        .into_iter()
+        .filter(|m| m.date >= start_date && m.date <= end_date)
+        .collect())
+}
+
+// 기간 내 일별 시간당 효율 (백분위는 캐릭터 전체 기록 기준)
+#[tauri::command]
+pub fn get_daily_metrics(
+    state: State<AppState>,
+    character_id: i64,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<DailyMetrics>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let sessions = db.get_all_hunting_sessions(character_id).map_err(|e| e.to_string())?;
+    let drop_values = db.get_daily_drop_values(character_id).map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+
+    let calendar = EventCalendar::new(db.get_game_events(character_id).map_err(|e| e.to_string())?);
+
+    Ok(metrics::daily_metrics(&sessions, &drop_values, &calendar)
+        .into_iter()
+        .filter(|m| m.date >= start_date && m.date <= end_date)
+        .collect())
+}
+
+// Hunting Map Commands
+#[tauri::command]
+pub fn get_hunting_maps(state: State<AppState>) -> Result<Vec<HuntingMap>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_hunting_maps().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_hunting_map(state: State<AppState>, region: String, map_name: String) -> Result<i64, String> {
+    let (region, map_name) = (region.trim(), map_name.trim());
+    if region.is_empty() || map_name.is_empty() {
+        return Err("지역과 맵 이름을 입력해주세요".to_string());
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.save_hunting_map(region, map_name).map_err(|e| e.to_string())
+}
+
+// 사냥터별 평균 시간당 효율
+#[tauri::command]
+pub fn get_map_metrics(state: State<AppState>, character_id: i64) -> Result<Vec<MapMetrics>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let sessions = db.get_all_hunting_sessions(character_id).map_err(|e| e.to_string())?;
+    let drop_values = db.get_daily_drop_values(character_id).map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+    let maps = db.get_hunting_maps().map_err(|e| e.to_string())?;
+
+    Ok(metrics::map_metrics(&sessions, &drop_values, &maps))
+}
+
+// 버프/쿠폰 조합별 평균 시간당 효율
+#[tauri::command]
+pub fn get_buff_combination_metrics(state: State<AppState>, character_id: i64) -> Result<Vec<BuffCombinationMetrics>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let sessions = db.get_all_hunting_sessions(character_id).map_err(|e| e.to_string())?;
+    let drop_values = db.get_daily_drop_values(character_id).map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+
+    Ok(metrics::buff_combination_metrics(&sessions, &drop_values))
+}
+
+// Game Event Commands
+fn validate_game_event(event: &GameEvent) -> Result<(), String> {
+    if !game_event::is_valid_event_type(&event.event_type) {
+        return Err(format!("알 수 없는 이벤트 종류입니다: {}", event.event_type));
+    }
+    if event.start_date > event.end_date {
+        return Err("이벤트 종료일은 시작일 이후여야 합니다".to_string());
+    }
+    if event.exp_multiplier <= 0.0 {
+        return Err("경험치 배율은 0보다 커야 합니다".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_event_presets() -> Vec<EventPreset> {
+    game_event::presets()
+}
+
+#[tauri::command]
+pub fn get_game_events(state: State<AppState>, character_id: i64) -> Result<Vec<GameEvent>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_game_events(character_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_game_event(state: State<AppState>, event: GameEvent) -> Result<i64, String> {
+    validate_game_event(&event)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Create, "game_events", "이벤트 추가", |db| db.save_game_event(&event))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_game_event(state: State<AppState>, event: GameEvent) -> Result<(), String> {
+    validate_game_event(&event)?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Update, "game_events", "이벤트 수정", |db| db.update_game_event(&event))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_game_event(state: State<AppState>, id: i64) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.with_journal(OperationKind::Delete, "game_events", "이벤트 삭제", |db| db.delete_game_event(id))
+        .map_err(|e| e.to_string())
+}
+
+// 기간 내 이벤트 안/밖 시간당 경험치 비교
+#[tauri::command]
+pub fn get_event_exp_comparison(
+    state: State<AppState>,
+    character_id: i64,
+    start_date: String,
+    end_date: String,
+) -> Result<EventExpComparison, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let sessions: Vec<HuntingSession> = db.get_all_hunting_sessions(character_id).map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|s| s.date >= start_date && s.date <= end_date)
+        .collect();
+    let drop_values = db.get_daily_drop_values(character_id).map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+    let calendar = EventCalendar::new(db.get_game_events(character_id).map_err(|e| e.to_string())?);
+
+    Ok(metrics::event_exp_comparison(&sessions, &drop_values, &calendar))
+}
+
+// Spreadsheet Import Commands
+// 열 매핑 단계용: 열 이름, 앞부분 행, 자동 매핑 제안
+#[tauri::command]
+pub fn read_import_spreadsheet(path: String) -> Result<SpreadsheetPreview, String> {
+    let table = session_import::read_spreadsheet(&path)?;
+    Ok(session_import::preview_table(&table, 5))
+}
+
+// 매핑대로 모든 행을 변환한 결과 (저장하지 않음)
+#[tauri::command]
+pub fn preview_hunting_session_import(
+    state: State<AppState>,
+    character_id: i64,
+    path: String,
+    mapping: HashMap<String, usize>,
+) -> Result<SessionImportPreview, String> {
+    let table = session_import::read_spreadsheet(&path)?;
+    session_import::validate_mapping(&mapping, table.headers.len())?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    session_import::preview_import(&db, character_id, &table, &mapping).map_err(|e| e.to_string())
+}
+
+// skip_invalid가 아니면 오류 행이 하나라도 있을 때 저장하지 않음
+// 기존 기록과 중복인 행은 include_duplicates로 확인받은 경우에만 저장
+#[tauri::command]
+pub fn import_hunting_sessions(
+    state: State<AppState>,
+    character_id: i64,
+    path: String,
+    mapping: HashMap<String, usize>,
+    skip_invalid: bool,
+    include_duplicates: Option<bool>,
+) -> Result<usize, String> {
+    let table = session_import::read_spreadsheet(&path)?;
+    session_import::validate_mapping(&mapping, table.headers.len())?;
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let preview = session_import::preview_import(&db, character_id, &table, &mapping).map_err(|e| e.to_string())?;
+
+    if preview.error_count > 0 && !skip_invalid {
+        return Err(format!("오류가 있는 행이 {}개 있습니다", preview.error_count));
+    }
+    let include_duplicates = include_duplicates.unwrap_or(false);
+    let sessions: Vec<HuntingSession> = preview.rows
+        .into_iter()
+        .filter(|r| include_duplicates || !r.duplicate)
+        .filter_map(|r| r.session)
+        .collect();
+    db.with_journal(OperationKind::Create, "hunting_sessions", "사냥 기록 가져오기", |db| db.save_hunting_sessions(&sessions))
+        .map_err(|e| e.to_string())
+}
+
+// Search Commands
+// "몽환의 벨트" 처럼 여러 단어면 모두 포함된 기록만 (단어별 접두사 일치)
+#[tauri::command]
+pub fn search_diary(
+    state: State<AppState>,
+    query: String,
+    character_id: Option<i64>,
+    source: Option<SearchSource>,
+    limit: Option<i64>,
+) -> Result<Vec<SearchHit>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.search(&query, character_id, source, limit.unwrap_or(50).clamp(1, 200))
+        .map_err(|e| e.to_string())
+}
+
+// Undo / Trash Commands
+#[tauri::command]
+pub fn get_journal_state(state: State<AppState>) -> Result<JournalState, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_journal_state().map_err(|e| e.to_string())
+}
+
+// 되돌린 작업 반환 (되돌릴 작업이 없으면 None)
+#[tauri::command]
+pub fn undo(state: State<AppState>) -> Result<Option<JournalOperation>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.undo().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn redo(state: State<AppState>) -> Result<Option<JournalOperation>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.redo().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_trash(state: State<AppState>) -> Result<Vec<TrashItem>, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.get_trash().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn restore_trash(state: State<AppState>, operation_id: i64) -> Result<(), String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.restore_trash(operation_id).map_err(|e| e.to_string())
+}
+
+// operation_id가 없으면 휴지통 비우기
+#[tauri::command]
+pub fn purge_trash(state: State<AppState>, operation_id: Option<i64>) -> Result<usize, String> {
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.purge_trash(operation_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_trash_retention_days(state: State<AppState>, days: i32) -> Result<(), String> {
+    if !(1..=365).contains(&days) {
+        return Err("휴지통 보관 기간은 1 ~ 365일 사이여야 합니다".to_string());
+    }
+    let db = state.db.lock().map_err(|e| e.to_string())?;
+    db.save_trash_retention_days(days).map_err(|e| e.to_string())?;
+    db.purge_journal().map_err(|e| e.to_string())?;
+    Ok(())
 }
@@ -0,0 +1,99 @@
+use crate::db::SessionConsumable;
+use crate::item_catalog::normalize_item_name;
+use serde::{Deserialize, Serialize};
+
+// 사냥 세션에 사용한 버프/쿠폰 종류
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsumableType {
+    ExpCoupon,    // 경험치 쿠폰
+    VipBuff,      // VIP 버프
+    MvpBuff,      // MVP 버프
+    WealthPotion, // 재물 획득의 비약
+    LegionWealth, // 유니온의 부
+    FamiliarBuff, // 패밀리어 버프
+}
+
+impl ConsumableType {
+    pub const ALL: [ConsumableType; 6] = [
+        Self::ExpCoupon,
+        Self::VipBuff,
+        Self::MvpBuff,
+        Self::WealthPotion,
+        Self::LegionWealth,
+        Self::FamiliarBuff,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ExpCoupon => "exp_coupon",
+            Self::VipBuff => "vip_buff",
+            Self::MvpBuff => "mvp_buff",
+            Self::WealthPotion => "wealth_potion",
+            Self::LegionWealth => "legion_wealth",
+            Self::FamiliarBuff => "familiar_buff",
+        }
+    }
+
+    fn korean_name(self) -> &'static str {
+        match self {
+            Self::ExpCoupon => "경험치 쿠폰",
+            Self::VipBuff => "VIP 버프",
+            Self::MvpBuff => "MVP 버프",
+            Self::WealthPotion => "재물 획득의 비약",
+            Self::LegionWealth => "유니온의 부",
+            Self::FamiliarBuff => "패밀리어 버프",
+        }
+    }
+
+    // 저장 키("exp_coupon") 또는 한글 이름("경험치 쿠폰")으로 변환
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = normalize_item_name(value);
+        Self::ALL.into_iter().find(|t| {
+            normalize_item_name(t.as_str()) == normalized || normalize_item_name(t.korean_name()) == normalized
+        })
+    }
+}
+
+fn first_field<'a>(value: &'a serde_json::Value, keys: &[&str]) -> Option<&'a serde_json::Value> {
+    keys.iter().find_map(|key| value.get(key))
+}
+
+// 기존 items JSON 문자열을 소모품 목록으로 변환
+// 알 수 없는 항목은 메모에 남길 수 있도록 원문으로 반환
+pub fn parse_legacy_items(items: &str) -> (Vec<SessionConsumable>, Vec<String>) {
+    let trimmed = items.trim();
+    if trimmed.is_empty() || trimmed == "[]" {
+        return (Vec::new(), Vec::new());
+    }
+    let Ok(entries) = serde_json::from_str::<Vec<serde_json::Value>>(trimmed) else {
+        return (Vec::new(), vec![trimmed.to_string()]);
+    };
+
+    let mut consumables = Vec::new();
+    let mut unknown = Vec::new();
+    for entry in entries {
+        let name = match &entry {
+            serde_json::Value::String(name) => Some(name.as_str()),
+            _ => first_field(&entry, &["consumable_type", "type", "name", "item_name"]).and_then(|v| v.as_str()),
+        };
+        match name.and_then(ConsumableType::parse) {
+            Some(consumable_type) => consumables.push(SessionConsumable {
+                id: 0,
+                consumable_type,
+                quantity: first_field(&entry, &["quantity", "count"]).and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                meso_cost: first_field(&entry, &["meso_cost", "price", "cost"]).and_then(|v| v.as_i64()).unwrap_or(0),
+            }),
+            None => unknown.push(name.map(str::to_string).unwrap_or_else(|| entry.to_string())),
+        }
+    }
+    (consumables, unknown)
+}
+
+// 버프 조합 키 (종류 정렬, 중복 제거)
+pub fn combination(consumables: &[SessionConsumable]) -> Vec<ConsumableType> {
+    let mut types: Vec<ConsumableType> = consumables.iter().map(|c| c.consumable_type).collect();
+    types.sort();
+    types.dedup();
+    types
+}
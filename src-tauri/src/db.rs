@@ -1,7 +1,24 @@
+use crate::backup;
+use crate::consumable::{self, ConsumableType};
+use crate::profile;
+use crate::search::{self, SearchHit, SearchSource};
+use crate::secret::{self, ApiKeyExport, KeyProtection};
+use crate::session_revision::{self, HuntingSessionRevision, RevisionSource};
+use crate::hunting_map::SEED_MAPS;
+use crate::item_catalog::{self, SEED_ITEMS};
+use crate::journal::{self, JournalEntry, JournalOperation, JournalState, OperationKind, TrashItem, JOURNAL_TABLES};
 use chrono::{NaiveDate, Datelike};
-use rusqlite::{Connection, OptionalExtension, Result, params};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::backup::{Backup, Progress};
+use rusqlite::{Connection, DatabaseName, OptionalExtension, Result, params};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// 화면에 보여줄 오류 메시지를 rusqlite 오류로 감싸서 전달 (메시지는 그대로 표시됨)
+fn message_error(message: String) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(message.into())
+}
 
 fn last_day_of_month(year: i32, month: u32) -> u32 {
     NaiveDate::from_ymd_opt(
@@ -15,6 +32,29 @@ fn last_day_of_month(year: i32, month: u32) -> u32 {
     .day()
 }
 
+// 소모품으로 변환하지 못한 기존 items 항목은 메모 뒤에 보존
+fn append_legacy_items_memo(memo: &mut Option<String>, unknown: &[String]) {
+    if unknown.is_empty() {
+        return;
+    }
+    let note = format!("[기존 아이템] {}", unknown.join(", "));
+    *memo = Some(match memo.take() {
+        Some(existing) if !existing.is_empty() => format!("{}\n{}", existing, note),
+        _ => note,
+    });
+}
+
+fn row_to_api_key_character(row: &rusqlite::Row) -> rusqlite::Result<ApiKeyCharacter> {
+    Ok(ApiKeyCharacter {
+        api_key_id: row.get(0)?,
+        ocid: row.get(1)?,
+        character_name: row.get(2)?,
+        world_name: row.get(3)?,
+        character_class: row.get(4)?,
+        character_level: row.get(5)?,
+    })
+}
+
 fn row_to_hunting_session(row: &rusqlite::Row) -> rusqlite::Result<HuntingSession> {
     Ok(HuntingSession {
         id: row.get(0)?,
@@ -42,13 +82,53 @@ fn row_to_hunting_session(row: &rusqlite::Row) -> rusqlite::Result<HuntingSessio
         sol_erda_piece_price: row.get(22)?,
         start_screenshot: row.get(23)?,
         end_screenshot: row.get(24)?,
-        items: row.get(25)?,
-        memo: row.get(26)?,
-        created_at: row.get(27)?,
-        updated_at: row.get(28)?,
+        consumables: Vec::new(),
+        memo: row.get(25)?,
+        created_at: row.get(26)?,
+        updated_at: row.get(27)?,
+        map_id: row.get(28)?,
+    })
+}
+
+fn row_to_hunting_session_revision(row: &rusqlite::Row) -> rusqlite::Result<HuntingSessionRevision> {
+    let source: String = row.get(2)?;
+    let changes: String = row.get(3)?;
+    Ok(HuntingSessionRevision {
+        id: row.get(0)?,
+        session_id: row.get(1)?,
+        source: RevisionSource::parse(&source).ok_or(rusqlite::Error::InvalidColumnType(
+            2,
+            "source".to_string(),
+            rusqlite::types::Type::Text,
+        ))?,
+        changes: serde_json::from_str(&changes).unwrap_or_default(),
+        reverted_revision_id: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
+
+fn row_to_session_consumable(row: &rusqlite::Row) -> rusqlite::Result<SessionConsumable> {
+    Ok(SessionConsumable {
+        id: row.get(0)?,
+        consumable_type: row.get(1)?,
+        quantity: row.get(2)?,
+        meso_cost: row.get(3)?,
     })
 }
 
+impl ToSql for ConsumableType {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+impl FromSql for ConsumableType {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let text = value.as_str()?;
+        ConsumableType::parse(text).ok_or_else(|| FromSqlError::Other(format!("unknown consumable type: {}", text).into()))
+    }
+}
+
 fn row_to_boss_clear(row: &rusqlite::Row) -> rusqlite::Result<BossClear> {
     Ok(BossClear {
         id: row.get(0)?,
@@ -59,18 +139,126 @@ fn row_to_boss_clear(row: &rusqlite::Row) -> rusqlite::Result<BossClear> {
         week_start_date: row.get(5)?,
         crystal_price: row.get(6)?,
         party_size: row.get(7)?,
-        created_at: row.get(8)?,
+        memo: row.get(8)?,
+        created_at: row.get(9)?,
+    })
+}
+
+fn row_to_journal_operation(row: &rusqlite::Row) -> rusqlite::Result<JournalOperation> {
+    let kind: String = row.get(1)?;
+    Ok(JournalOperation {
+        id: row.get(0)?,
+        kind: OperationKind::parse(&kind).ok_or(rusqlite::Error::InvalidColumnType(
+            1,
+            "kind".to_string(),
+            rusqlite::types::Type::Text,
+        ))?,
+        table_name: row.get(2)?,
+        label: row.get(3)?,
+        undone: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
+
+fn row_to_symbol_progress(row: &rusqlite::Row) -> rusqlite::Result<SymbolProgress> {
+    Ok(SymbolProgress {
+        id: row.get(0)?,
+        character_id: row.get(1)?,
+        date: row.get(2)?,
+        symbol_name: row.get(3)?,
+        symbol_level: row.get(4)?,
+        symbol_growth_count: row.get(5)?,
+        symbol_require_growth_count: row.get(6)?,
+        created_at: row.get(7)?,
+    })
+}
+
+fn row_to_hexa_skill_history(row: &rusqlite::Row) -> rusqlite::Result<HexaSkillHistory> {
+    Ok(HexaSkillHistory {
+        id: row.get(0)?,
+        character_id: row.get(1)?,
+        date: row.get(2)?,
+        hexa_core_name: row.get(3)?,
+        hexa_core_type: row.get(4)?,
+        hexa_core_level: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+fn row_to_item_drop(row: &rusqlite::Row) -> rusqlite::Result<ItemDrop> {
+    Ok(ItemDrop {
+        id: row.get(0)?,
+        character_id: row.get(1)?,
+        date: row.get(2)?,
+        item_name: row.get(3)?,
+        price: row.get(4)?,
+        screenshot: row.get(5)?,
+        sale_status: row.get(6)?,
+        sale_price: row.get(7)?,
+        sale_fee: row.get(8)?,
+        sold_date: row.get(9)?,
+        item_id: row.get(10)?,
+        created_at: row.get(11)?,
+    })
+}
+
+fn row_to_sol_erda_piece_price(row: &rusqlite::Row) -> rusqlite::Result<SolErdaPiecePrice> {
+    Ok(SolErdaPiecePrice {
+        id: row.get(0)?,
+        date: row.get(1)?,
+        price: row.get(2)?,
+        created_at: row.get(3)?,
+    })
+}
+
+fn row_to_item_price(row: &rusqlite::Row) -> rusqlite::Result<ItemPrice> {
+    Ok(ItemPrice {
+        id: row.get(0)?,
+        item_id: row.get(1)?,
+        item_name: row.get(2)?,
+        date: row.get(3)?,
+        price: row.get(4)?,
+        source: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+fn row_to_meso_expense(row: &rusqlite::Row) -> rusqlite::Result<MesoExpense> {
+    Ok(MesoExpense {
+        id: row.get(0)?,
+        character_id: row.get(1)?,
+        date: row.get(2)?,
+        category: row.get(3)?,
+        amount: row.get(4)?,
+        memo: row.get(5)?,
+        created_at: row.get(6)?,
     })
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Settings {
     pub id: i64,
-    pub api_key: String,
+    pub name: String,
+    pub api_key: String, // 복호화된 값 (잠겨 있으면 빈 문자열)
+    pub key_protection: KeyProtection,
+    pub locked: bool, // 암호 입력 전이거나 다른 PC에서 암호화되어 복호화할 수 없음
     pub created_at: String,
     pub updated_at: String,
 }
 
+pub const DEFAULT_API_KEY_NAME: &str = "기본";
+
+// API Key로 조회되는 캐릭터 (character/list 결과)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiKeyCharacter {
+    pub api_key_id: i64,
+    pub ocid: String,
+    pub character_name: String,
+    pub world_name: String,
+    pub character_class: String,
+    pub character_level: i32,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Character {
     pub id: i64,
@@ -86,6 +274,20 @@ pub struct Character {
     pub updated_at: String,
 }
 
+fn row_to_game_event(row: &rusqlite::Row) -> rusqlite::Result<GameEvent> {
+    Ok(GameEvent {
+        id: row.get(0)?,
+        character_id: row.get(1)?,
+        name: row.get(2)?,
+        event_type: row.get(3)?,
+        start_date: row.get(4)?,
+        end_date: row.get(5)?,
+        exp_multiplier: row.get(6)?,
+        memo: row.get(7)?,
+        created_at: row.get(8)?,
+    })
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct HuntingSession {
     pub id: i64,
@@ -115,10 +317,118 @@ pub struct HuntingSession {
     pub sol_erda_piece_price: i64, // 해당 사냥 시점의 조각 가격
     pub start_screenshot: Option<String>,
     pub end_screenshot: Option<String>,
-    pub items: String, // JSON string
+    #[serde(default)]
+    pub consumables: Vec<SessionConsumable>, // 버프/쿠폰 사용 내역
     pub memo: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub map_id: Option<i64>, // hunting_maps 참조
+}
+
+impl HuntingSession {
+    // 입력값으로 세션 파생값(경험치/메소 획득량, 소재비, 솔 에르다 획득량) 계산
+    pub fn derive_gains(&mut self) {
+        self.exp_gained = self.end_exp_percent - self.start_exp_percent
+            + ((self.end_level - self.start_level) as f64 * 100.0);
+        self.meso_gained = self.end_meso - self.start_meso;
+        self.sojaebi = self.duration_minutes as f64 / 30.0;
+
+        // 솔 에르다 획득량 계산 (개수 + 게이지/1000)
+        let start_sol_erda_total = self.start_sol_erda as f64 + (self.start_sol_erda_gauge as f64 / 1000.0);
+        let end_sol_erda_total = self.end_sol_erda as f64 + (self.end_sol_erda_gauge as f64 / 1000.0);
+        self.sol_erda_gained = end_sol_erda_total - start_sol_erda_total;
+
+        // 솔 에르다 조각 획득량
+        self.sol_erda_piece_gained = self.end_sol_erda_piece - self.start_sol_erda_piece;
+    }
+}
+
+// 사냥 기록 입력값 (파생값은 저장 시 계산)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveHuntingSessionInput {
+    pub character_id: i64,
+    pub date: String,
+    pub start_level: i32,
+    pub end_level: i32,
+    pub start_exp_percent: f64,
+    pub end_exp_percent: f64,
+    pub start_meso: i64,
+    pub end_meso: i64,
+    pub duration_minutes: i32,
+    // 솔 에르다 (개수 0-20, 게이지 0-1000)
+    pub start_sol_erda: i32,
+    pub end_sol_erda: i32,
+    pub start_sol_erda_gauge: i32,
+    pub end_sol_erda_gauge: i32,
+    // 솔 에르다 조각
+    pub start_sol_erda_piece: i64,
+    pub end_sol_erda_piece: i64,
+    // 해당 사냥 시점의 조각 가격 (없으면 사냥 날짜의 시세)
+    #[serde(default)]
+    pub sol_erda_piece_price: Option<i64>,
+    pub start_screenshot: Option<String>,
+    pub end_screenshot: Option<String>,
+    #[serde(default)]
+    pub consumables: Vec<SessionConsumable>,
+    pub memo: Option<String>,
+    #[serde(default)]
+    pub map_id: Option<i64>,
+}
+
+impl SaveHuntingSessionInput {
+    pub fn into_session(self, sol_erda_piece_price: i64) -> HuntingSession {
+        let mut session = HuntingSession {
+            id: 0,
+            character_id: self.character_id,
+            date: self.date,
+            session_order: 0,
+            start_level: self.start_level,
+            end_level: self.end_level,
+            start_exp_percent: self.start_exp_percent,
+            end_exp_percent: self.end_exp_percent,
+            exp_gained: 0.0,
+            start_meso: self.start_meso,
+            end_meso: self.end_meso,
+            meso_gained: 0,
+            duration_minutes: self.duration_minutes,
+            sojaebi: 0.0,
+            start_sol_erda: self.start_sol_erda,
+            end_sol_erda: self.end_sol_erda,
+            start_sol_erda_gauge: self.start_sol_erda_gauge,
+            end_sol_erda_gauge: self.end_sol_erda_gauge,
+            sol_erda_gained: 0.0,
+            start_sol_erda_piece: self.start_sol_erda_piece,
+            end_sol_erda_piece: self.end_sol_erda_piece,
+            sol_erda_piece_gained: 0,
+            sol_erda_piece_price,
+            start_screenshot: self.start_screenshot,
+            end_screenshot: self.end_screenshot,
+            consumables: self.consumables,
+            memo: self.memo,
+            created_at: String::new(),
+            updated_at: String::new(),
+            map_id: self.map_id,
+        };
+        session.derive_gains();
+        session
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionConsumable {
+    #[serde(default)]
+    pub id: i64,
+    pub consumable_type: ConsumableType,
+    pub quantity: i32,
+    pub meso_cost: i64, // 해당 항목의 총 구매 비용
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HuntingMap {
+    pub id: i64,
+    pub region: String,
+    pub map_name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -128,6 +438,9 @@ pub struct DailyTotal {
     pub total_meso_gained: i64,
     pub total_sojaebi: f64,
     pub session_count: i32,
+    // 이벤트 기간 표시 (이벤트 없으면 1.0)
+    pub exp_multiplier: f64,
+    pub events: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -139,6 +452,8 @@ pub struct ExpHistory {
     pub exp_rate: f64,
     pub total_exp: f64, // level * 100 + exp_rate
     pub created_at: String,
+    pub exp_multiplier: f64,
+    pub events: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -163,6 +478,7 @@ pub struct BossClear {
     pub week_start_date: String,
     pub crystal_price: i64,
     pub party_size: i32,
+    pub memo: Option<String>,
     pub created_at: String,
 }
 
@@ -179,8 +495,65 @@ pub struct ItemDrop {
     pub character_id: i64,
     pub date: String,
     pub item_name: String,
-    pub price: i64,
+    pub price: i64, // 예상 가치
     pub screenshot: Option<String>,
+    // 판매 상태 (listed: 경매장 등록, sold: 판매 완료, kept: 보관/사용)
+    pub sale_status: String,
+    pub sale_price: Option<i64>,
+    pub sale_fee: Option<i64>, // 판매 시점 수수료율로 계산한 경매장 수수료
+    pub sold_date: Option<String>,
+    pub item_id: Option<i64>, // item_catalog 참조
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ItemPrice {
+    pub id: i64,
+    pub item_id: i64,
+    pub item_name: String,
+    pub date: String,
+    pub price: i64,
+    pub source: String, // manual, imported
+    pub created_at: String,
+}
+
+// 미판매 득템의 현재 시세 평가
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeldItemValue {
+    pub drop_id: i64,
+    pub date: String,
+    pub item_name: String,
+    pub item_id: Option<i64>,
+    pub sale_status: String,
+    pub entry_price: i64, // 득템 기록 시 입력한 가격
+    pub latest_price: Option<i64>,
+    pub latest_price_date: Option<String>,
+    pub current_value: i64, // 시세 기록이 없으면 entry_price
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CatalogItem {
+    pub id: i64,
+    pub canonical_name: String,
+    pub category: String,
+    pub drop_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ItemDropSummary {
+    pub gross_sales: i64,
+    pub auction_fee: i64,
+    pub realized_meso: i64,
+    pub listed_value: i64,
+    pub kept_value: i64,
+    pub unrealized_value: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SolErdaPiecePrice {
+    pub id: i64,
+    pub date: String, // 이 날짜부터 적용
+    pub price: i64,
     pub created_at: String,
 }
 
@@ -189,40 +562,268 @@ pub struct AppSettings {
     pub id: i64,
     pub sol_erda_piece_price: i64, // 기본값: 6,500,000 (650만 메소)
     pub screenshot_folder_path: Option<String>, // 스크린샷 폴더 경로
+    pub auction_fee_rate: f64, // 경매장 수수료율 (기본값: 0.05)
+    pub backup_keep_count: i32, // 종류별 자동 백업 보관 개수 (기본값: 10)
+    pub trash_retention_days: i32, // 휴지통 보관 기간 (기본값: 30일)
     pub created_at: String,
     pub updated_at: String,
 }
 
+// 최근 사냥 기록 기반 솔 에르다 획득 속도 및 현재 보유량
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SolErdaRate {
+    pub total_minutes: i64,
+    pub sol_erda_gained: f64,
+    pub sol_erda_piece_gained: i64,
+    pub current_sol_erda: f64, // 마지막 세션 종료 시점 (개수 + 게이지/1000)
+    pub current_sol_erda_piece: i64,
+}
+
+impl SolErdaRate {
+    pub fn sol_erda_per_hour(&self) -> f64 {
+        if self.total_minutes <= 0 {
+            return 0.0;
+        }
+        self.sol_erda_gained / (self.total_minutes as f64 / 60.0)
+    }
+
+    pub fn pieces_per_hour(&self) -> f64 {
+        if self.total_minutes <= 0 {
+            return 0.0;
+        }
+        self.sol_erda_piece_gained as f64 / (self.total_minutes as f64 / 60.0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HexaSkillHistory {
+    pub id: i64,
+    pub character_id: i64,
+    pub date: String,
+    pub hexa_core_name: String,
+    pub hexa_core_type: String,
+    pub hexa_core_level: i32,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SymbolProgress {
+    pub id: i64,
+    pub character_id: i64,
+    pub date: String,
+    pub symbol_name: String,
+    pub symbol_level: i32,
+    pub symbol_growth_count: i64,
+    pub symbol_require_growth_count: i64,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SymbolDailyQuest {
+    pub id: i64,
+    pub character_id: i64,
+    pub date: String,
+    pub symbol_name: String,
+    pub completed: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnionHistory {
+    pub id: i64,
+    pub character_id: i64,
+    pub date: String,
+    pub union_level: i32,
+    pub union_grade: String,
+    pub union_artifact_level: i32,
+    pub union_artifact_exp: i64,
+    pub union_artifact_point: i64,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RankingSnapshot {
+    pub id: i64,
+    pub character_id: i64,
+    pub date: String,
+    pub world_rank: Option<i64>,
+    pub class_rank: Option<i64>,
+    pub union_rank: Option<i64>,
+    pub dojang_rank: Option<i64>,
+    pub dojang_floor: Option<i32>,
+    pub created_at: String,
+}
+
+// 랭킹 스냅샷 + 같은 날짜의 경험치 히스토리
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RankingProgress {
+    pub date: String,
+    pub world_rank: Option<i64>,
+    pub class_rank: Option<i64>,
+    pub union_rank: Option<i64>,
+    pub dojang_rank: Option<i64>,
+    pub dojang_floor: Option<i32>,
+    pub level: Option<i32>,
+    pub exp_rate: Option<f64>,
+    pub total_exp: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StarforceRecord {
+    pub id: String, // Nexon 기록 ID
+    pub character_name: String,
+    pub world_name: String,
+    pub target_item: String,
+    pub date_create: String,
+    pub before_starforce_count: i32,
+    pub after_starforce_count: i32,
+    pub item_upgrade_result: String,
+    pub destroy_defence: bool,
+    pub chance_time: bool,
+    pub cost_discount_rate: f64, // 0.0 ~ 1.0
+}
+
+// 큐브 사용 / 잠재능력 재설정 기록
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PotentialRecord {
+    pub id: String, // Nexon 기록 ID
+    pub character_name: String,
+    pub date_create: String,
+    pub cube_type: String, // 큐브 이름 또는 재설정 종류
+    pub item_upgrade_result: String,
+    pub item_equipment_part: String,
+    pub item_level: i32,
+    pub target_item: String,
+    pub potential_option_grade: Option<String>,
+    pub additional_potential_option_grade: Option<String>,
+    pub after_potential_option: String, // JSON string
+    pub after_additional_potential_option: String, // JSON string
+}
+
+// 아이템/시작 성/파괴방지/할인율 별 스타포스 시도 횟수
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StarforceAttemptGroup {
+    pub character_name: String,
+    pub target_item: String,
+    pub before_starforce_count: i32,
+    pub destroy_defence: bool,
+    pub cost_discount_rate: f64,
+    pub attempts: i64,
+    pub successes: i64,
+    pub destroys: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CubeUsageGroup {
+    pub character_name: String,
+    pub target_item: String,
+    pub cube_type: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MesoExpense {
+    pub id: i64,
+    pub character_id: i64,
+    pub date: String,
+    pub category: String, // starforce, cube, flame, symbol, auction, fee, etc
+    pub amount: i64,
+    pub memo: Option<String>,
+    pub created_at: String,
+}
+
+// 일별 메소 수입/지출 합계 (순수익 계산용)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyMesoFlow {
+    pub date: String,
+    pub hunting_meso: i64,
+    pub crystal_income: i64,
+    pub item_drop_income: i64,
+    pub expense: i64,
+}
+
+// 버닝/경험치 이벤트 기간
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GameEvent {
+    #[serde(default)]
+    pub id: i64,
+    pub character_id: Option<i64>, // None = 모든 캐릭터
+    pub name: String,
+    pub event_type: String, // hyper_burning, tera_burning, exp_event, sunday_maple, etc
+    pub start_date: String,
+    pub end_date: String,
+    pub exp_multiplier: f64,
+    pub memo: Option<String>,
+    #[serde(default)]
+    pub created_at: String,
+}
+
 pub struct Database {
     conn: Connection,
+    path: PathBuf,
+    api_key_passphrase: Option<String>, // 암호 보호된 API Key 잠금 해제용 (메모리에만 보관)
 }
 
 impl Database {
+    // 현재 프로필의 DB 파일 열기
     pub fn new() -> Result<Self> {
-        let db_path = Self::get_db_path();
+        Self::open(&profile::active_db_path())
+    }
 
+    pub fn open(db_path: &Path) -> Result<Self> {
         // 디렉토리 생성
         if let Some(parent) = db_path.parent() {
             std::fs::create_dir_all(parent).ok();
         }
 
-        let conn = Connection::open(&db_path)?;
-        let db = Self { conn };
+        let existed = std::fs::metadata(db_path).map(|m| m.len() > 0).unwrap_or(false);
+        let conn = Connection::open(db_path)?;
+        let db = Self { conn, path: db_path.to_path_buf(), api_key_passphrase: None };
+        // 마이그레이션이 데이터를 바꾸기 전에 시작 백업 (실패해도 계속, 보관 개수 정리는 설정 테이블이 준비된 뒤)
+        if existed {
+            backup::snapshot_before_migration(&db).ok();
+        }
         db.init_tables()?;
+        if existed {
+            backup::rotate(&db).ok();
+        }
         Ok(db)
     }
 
-    fn get_db_path() -> PathBuf {
+    pub fn get_default_db_path() -> PathBuf {
+        let mut path = Self::get_data_dir();
+        path.push("maple_diary.db");
+        path
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_data_dir() -> PathBuf {
         let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
         path.push("MapleDiary");
-        path.push("maple_diary.db");
         path
     }
 
+    // SQLite 온라인 백업 API로 사용 중인 DB를 파일로 복사
+    pub fn backup_to(&self, path: &Path) -> Result<()> {
+        let mut dst = Connection::open(path)?;
+        let backup = Backup::new(&self.conn, &mut dst)?;
+        backup.run_to_completion(256, Duration::from_millis(10), None)
+    }
+
+    // 백업 파일로 현재 DB를 덮어쓴 뒤 스키마 마이그레이션 재실행 (이전 버전 백업 대비)
+    pub fn restore_from(&mut self, path: &Path) -> Result<()> {
+        self.conn.restore(DatabaseName::Main, path, None::<fn(Progress)>)?;
+        self.init_tables()
+    }
+
     fn init_tables(&self) -> Result<()> {
         self.conn.execute(
             "CREATE TABLE IF NOT EXISTS settings (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL DEFAULT '기본',
                 api_key TEXT NOT NULL,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 updated_at TEXT NOT NULL DEFAULT (datetime('now'))
@@ -230,6 +831,29 @@ impl Database {
             [],
         )?;
 
+        // API Key 이름 (여러 넥슨 계정 키 구분)
+        let _ = self.conn.execute("ALTER TABLE settings ADD COLUMN name TEXT NOT NULL DEFAULT '기본'", []);
+
+        // API Key로 조회되는 캐릭터 (캐릭터별로 사용할 키 결정)
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS api_key_characters (
+                api_key_id INTEGER NOT NULL,
+                ocid TEXT NOT NULL,
+                character_name TEXT NOT NULL,
+                world_name TEXT NOT NULL,
+                character_class TEXT NOT NULL,
+                character_level INTEGER NOT NULL,
+                PRIMARY KEY (api_key_id, ocid),
+                FOREIGN KEY (api_key_id) REFERENCES settings(id)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_api_key_characters_ocid ON api_key_characters(ocid)",
+            [],
+        )?;
+
         self.conn.execute(
             "CREATE TABLE IF NOT EXISTS characters (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -271,7 +895,6 @@ impl Database {
                 sojaebi REAL NOT NULL,
                 start_screenshot TEXT,
                 end_screenshot TEXT,
-                items TEXT NOT NULL DEFAULT '[]',
                 memo TEXT,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 updated_at TEXT NOT NULL DEFAULT (datetime('now')),
@@ -395,6 +1018,9 @@ impl Database {
             [],
         )?;
 
+        // 보스 클리어 메모 (드랍/파티 기록)
+        let _ = self.conn.execute("ALTER TABLE boss_clears ADD COLUMN memo TEXT", []);
+
         // 앱 설정 테이블 (조각 가격 등)
         self.conn.execute(
             "CREATE TABLE IF NOT EXISTS app_settings (
@@ -425,12 +1051,63 @@ impl Database {
             [],
         );
 
+        // app_settings에 auction_fee_rate 컬럼 추가 (마이그레이션)
+        let _ = self.conn.execute(
+            "ALTER TABLE app_settings ADD COLUMN auction_fee_rate REAL NOT NULL DEFAULT 0.05",
+            [],
+        );
+
+        // app_settings에 backup_keep_count 컬럼 추가 (마이그레이션)
+        let _ = self.conn.execute(
+            "ALTER TABLE app_settings ADD COLUMN backup_keep_count INTEGER NOT NULL DEFAULT 10",
+            [],
+        );
+
+        // app_settings에 trash_retention_days 컬럼 추가 (마이그레이션)
+        let _ = self.conn.execute(
+            "ALTER TABLE app_settings ADD COLUMN trash_retention_days INTEGER NOT NULL DEFAULT 30",
+            [],
+        );
+
         // hunting_sessions에 sol_erda_piece_price 컬럼 추가 (마이그레이션)
         let _ = self.conn.execute(
             "ALTER TABLE hunting_sessions ADD COLUMN sol_erda_piece_price INTEGER NOT NULL DEFAULT 6500000",
             [],
         );
 
+        // 솔 에르다 조각 시세 기록 테이블
+        let piece_price_table_exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'sol_erda_piece_prices')",
+            [],
+            |row| row.get(0),
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS sol_erda_piece_prices (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                date TEXT NOT NULL UNIQUE,
+                price INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )?;
+
+        // 처음 생성 시 기존 사냥 기록의 조각 가격과 현재 설정값으로 초기화
+        if !piece_price_table_exists {
+            self.conn.execute(
+                "INSERT INTO sol_erda_piece_prices (date, price)
+                 SELECT date, CAST(AVG(sol_erda_piece_price) AS INTEGER)
+                 FROM hunting_sessions
+                 GROUP BY date",
+                [],
+            )?;
+            self.conn.execute(
+                "INSERT OR IGNORE INTO sol_erda_piece_prices (date, price)
+                 SELECT date(updated_at), sol_erda_piece_price FROM app_settings WHERE id = 1",
+                [],
+            )?;
+        }
+
         // 아이템 드랍 테이블
         self.conn.execute(
             "CREATE TABLE IF NOT EXISTS item_drops (
@@ -455,206 +1132,2675 @@ impl Database {
             [],
         )?;
 
-        Ok(())
-    }
+        // item_drops에 판매 정보 컬럼 추가 (마이그레이션)
+        let _ = self.conn.execute(
+            "ALTER TABLE item_drops ADD COLUMN sale_status TEXT NOT NULL DEFAULT 'kept'",
+            [],
+        );
+        let _ = self.conn.execute("ALTER TABLE item_drops ADD COLUMN sale_price INTEGER", []);
+        let _ = self.conn.execute("ALTER TABLE item_drops ADD COLUMN sale_fee INTEGER", []);
+        let _ = self.conn.execute("ALTER TABLE item_drops ADD COLUMN sold_date TEXT", []);
 
-    // Settings
-    pub fn get_settings(&self) -> Result<Option<Settings>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, api_key, created_at, updated_at FROM settings ORDER BY id DESC LIMIT 1"
+        // 아이템 카탈로그 (정식 명칭) 및 별칭 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS item_catalog (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                canonical_name TEXT NOT NULL UNIQUE,
+                normalized_name TEXT NOT NULL UNIQUE,
+                category TEXT NOT NULL DEFAULT 'etc',
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
         )?;
 
-        let mut rows = stmt.query([])?;
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS item_aliases (
+                normalized_alias TEXT PRIMARY KEY,
+                alias TEXT NOT NULL,
+                item_id INTEGER NOT NULL,
+                FOREIGN KEY (item_id) REFERENCES item_catalog(id)
+            )",
+            [],
+        )?;
 
-        if let Some(row) = rows.next()? {
-            Ok(Some(Settings {
-                id: row.get(0)?,
-                api_key: row.get(1)?,
-                created_at: row.get(2)?,
-                updated_at: row.get(3)?,
-            }))
-        } else {
-            Ok(None)
+        // item_drops에 item_id 컬럼 추가 (마이그레이션)
+        let _ = self.conn.execute("ALTER TABLE item_drops ADD COLUMN item_id INTEGER REFERENCES item_catalog(id)", []);
+
+        self.seed_item_catalog()?;
+        self.migrate_item_drop_names()?;
+
+        // 사냥터 목록 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS hunting_maps (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                region TEXT NOT NULL,
+                map_name TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                UNIQUE(region, map_name)
+            )",
+            [],
+        )?;
+
+        for (region, maps) in SEED_MAPS {
+            for map_name in *maps {
+                self.conn.execute(
+                    "INSERT OR IGNORE INTO hunting_maps (region, map_name) VALUES (?1, ?2)",
+                    params![region, map_name],
+                )?;
+            }
         }
-    }
 
-    pub fn save_api_key(&self, api_key: &str) -> Result<()> {
-        // 기존 설정 삭제 후 새로 저장
-        self.conn.execute("DELETE FROM settings", [])?;
+        // hunting_sessions에 map_id 컬럼 추가 (마이그레이션)
+        let _ = self.conn.execute(
+            "ALTER TABLE hunting_sessions ADD COLUMN map_id INTEGER REFERENCES hunting_maps(id)",
+            [],
+        );
+
+        // 사냥 세션 소모품(버프/쿠폰) 테이블
         self.conn.execute(
-            "INSERT INTO settings (api_key) VALUES (?1)",
-            params![api_key],
+            "CREATE TABLE IF NOT EXISTS session_consumables (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id INTEGER NOT NULL,
+                consumable_type TEXT NOT NULL,
+                quantity INTEGER NOT NULL DEFAULT 1,
+                meso_cost INTEGER NOT NULL DEFAULT 0,
+                FOREIGN KEY (session_id) REFERENCES hunting_sessions(id)
+            )",
+            [],
         )?;
-        Ok(())
-    }
 
-    // Characters
-    pub fn get_active_character(&self) -> Result<Option<Character>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, character_name, character_image, ocid, world_name,
-                    character_class, character_level, character_exp_rate, is_active, created_at, updated_at
-             FROM characters WHERE is_active = 1 LIMIT 1"
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_session_consumables_session ON session_consumables(session_id)",
+            [],
         )?;
 
-        let mut rows = stmt.query([])?;
+        self.migrate_session_items()?;
 
-        if let Some(row) = rows.next()? {
-            Ok(Some(Character {
-                id: row.get(0)?,
-                character_name: row.get(1)?,
-                character_image: row.get(2)?,
-                ocid: row.get(3)?,
-                world_name: row.get(4)?,
-                character_class: row.get(5)?,
-                character_level: row.get(6)?,
-                character_exp_rate: row.get(7)?,
-                is_active: row.get::<_, i32>(8)? == 1,
-                created_at: row.get(9)?,
-                updated_at: row.get(10)?,
-            }))
-        } else {
-            Ok(None)
-        }
-    }
+        // 아이템 시세 기록 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS item_price_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                item_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                price INTEGER NOT NULL,
+                source TEXT NOT NULL DEFAULT 'manual',
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (item_id) REFERENCES item_catalog(id),
+                UNIQUE(item_id, date, source)
+            )",
+            [],
+        )?;
 
-    pub fn register_character(&self, character: &Character) -> Result<i64> {
-        // ocid 또는 character_name으로 기존 캐릭터 매칭
-        // - ocid 일치: 닉네임이 변경된 경우
-        // - character_name 일치: 게임 업데이트로 ocid가 변경된 경우
-        let existing_id: Option<i64> = self.conn.query_row(
-            "SELECT id FROM characters WHERE ocid = ?1 OR character_name = ?2 LIMIT 1",
-            params![character.ocid, character.character_name],
-            |row| row.get(0),
-        ).optional()?;
+        // HEXA 코어 레벨 히스토리 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS hexa_skill_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                hexa_core_name TEXT NOT NULL,
+                hexa_core_type TEXT NOT NULL,
+                hexa_core_level INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id),
+                UNIQUE(character_id, date, hexa_core_name)
+            )",
+            [],
+        )?;
 
-        // 기존 활성 캐릭터 비활성화
-        self.conn.execute("UPDATE characters SET is_active = 0", [])?;
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_hexa_skill_history_character ON hexa_skill_history(character_id)",
+            [],
+        )?;
 
-        if let Some(id) = existing_id {
-            // 같은 캐릭터 → 정보 업데이트 + 활성화 (ocid, 닉네임 모두 갱신)
-            self.conn.execute(
-                "UPDATE characters SET character_name = ?1, character_image = ?2,
-                 ocid = ?3, world_name = ?4, character_class = ?5, character_level = ?6,
-                 character_exp_rate = ?7, is_active = 1, updated_at = datetime('now')
-                 WHERE id = ?8",
-                params![
-                    character.character_name,
-                    character.character_image,
-                    character.ocid,
-                    character.world_name,
-                    character.character_class,
-                    character.character_level,
-                    character.character_exp_rate,
-                    id,
-                ],
-            )?;
-            Ok(id)
-        } else {
-            // 새 캐릭터 등록
-            self.conn.execute(
-                "INSERT INTO characters (character_name, character_image, ocid, world_name,
-                                        character_class, character_level, character_exp_rate, is_active)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 1)",
-                params![
-                    character.character_name,
-                    character.character_image,
-                    character.ocid,
-                    character.world_name,
-                    character.character_class,
-                    character.character_level,
-                    character.character_exp_rate,
-                ],
-            )?;
-            Ok(self.conn.last_insert_rowid())
-        }
-    }
+        // 심볼 성장 히스토리 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS symbol_progress (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                symbol_name TEXT NOT NULL,
+                symbol_level INTEGER NOT NULL,
+                symbol_growth_count INTEGER NOT NULL,
+                symbol_require_growth_count INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id),
+                UNIQUE(character_id, date, symbol_name)
+            )",
+            [],
+        )?;
 
-    pub fn update_character(&self, id: i64, name: &str, level: i32, exp_rate: &str, image: &str) -> Result<()> {
+        // 심볼 일일 퀘스트 완료 테이블
         self.conn.execute(
-            "UPDATE characters SET character_name = ?1, character_level = ?2, character_exp_rate = ?3,
-             character_image = ?4, updated_at = datetime('now') WHERE id = ?5",
-            params![name, level, exp_rate, image, id],
+            "CREATE TABLE IF NOT EXISTS symbol_daily_quests (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                symbol_name TEXT NOT NULL,
+                completed INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id),
+                UNIQUE(character_id, date, symbol_name)
+            )",
+            [],
         )?;
-        Ok(())
-    }
 
-    // Hunting Sessions
-    pub fn get_hunting_sessions(&self, character_id: i64, date: &str) -> Result<Vec<HuntingSession>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, date, session_order, start_level, end_level,
-                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
-                    meso_gained, duration_minutes, sojaebi,
-                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
-                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
-                    start_screenshot, end_screenshot, items, memo, created_at, updated_at
-             FROM hunting_sessions WHERE character_id = ?1 AND date = ?2 ORDER BY session_order"
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_symbol_daily_quests_date ON symbol_daily_quests(date)",
+            [],
         )?;
 
-        let sessions = stmt.query_map(params![character_id, date], |row| {
-            row_to_hunting_session(row)
-        })?.collect::<Result<Vec<_>>>()?;
+        // 유니온 레벨/아티팩트 히스토리 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS union_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                union_level INTEGER NOT NULL,
+                union_grade TEXT NOT NULL,
+                union_artifact_level INTEGER NOT NULL,
+                union_artifact_exp INTEGER NOT NULL DEFAULT 0,
+                union_artifact_point INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id),
+                UNIQUE(character_id, date)
+            )",
+            [],
+        )?;
 
-        Ok(sessions)
-    }
+        // 스타포스 강화 기록 테이블 (Open API history/starforce)
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS starforce_history (
+                id TEXT PRIMARY KEY,
+                character_name TEXT NOT NULL,
+                world_name TEXT NOT NULL,
+                target_item TEXT NOT NULL,
+                date_create TEXT NOT NULL,
+                before_starforce_count INTEGER NOT NULL,
+                after_starforce_count INTEGER NOT NULL,
+                item_upgrade_result TEXT NOT NULL,
+                destroy_defence INTEGER NOT NULL DEFAULT 0,
+                chance_time INTEGER NOT NULL DEFAULT 0,
+                cost_discount_rate REAL NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )?;
 
-    pub fn save_hunting_session(&self, session: &HuntingSession) -> Result<i64> {
-        // 해당 캐릭터의 해당 날짜의 마지막 session_order 조회
-        let next_order: i32 = self.conn.query_row(
-            "SELECT COALESCE(MAX(session_order), 0) + 1 FROM hunting_sessions WHERE character_id = ?1 AND date = ?2",
-            params![session.character_id, session.date],
-            |row| row.get(0),
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_starforce_history_date ON starforce_history(date_create)",
+            [],
         )?;
 
+        // 큐브 사용 기록 테이블 (Open API history/cube)
         self.conn.execute(
-            "INSERT INTO hunting_sessions (character_id, date, session_order, start_level, end_level,
-                start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso, meso_gained,
-                duration_minutes, sojaebi,
-                start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
-                start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
-                start_screenshot, end_screenshot, items, memo)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26)",
-            params![
-                session.character_id, session.date, next_order, session.start_level, session.end_level,
-                session.start_exp_percent, session.end_exp_percent, session.exp_gained,
-                session.start_meso, session.end_meso, session.meso_gained,
-                session.duration_minutes, session.sojaebi,
-                session.start_sol_erda, session.end_sol_erda, session.start_sol_erda_gauge, session.end_sol_erda_gauge, session.sol_erda_gained,
-                session.start_sol_erda_piece, session.end_sol_erda_piece, session.sol_erda_piece_gained, session.sol_erda_piece_price,
-                session.start_screenshot, session.end_screenshot, session.items, session.memo
-            ],
+            "CREATE TABLE IF NOT EXISTS cube_history (
+                id TEXT PRIMARY KEY,
+                character_name TEXT NOT NULL,
+                date_create TEXT NOT NULL,
+                cube_type TEXT NOT NULL,
+                item_upgrade_result TEXT NOT NULL,
+                item_equipment_part TEXT NOT NULL,
+                item_level INTEGER NOT NULL,
+                target_item TEXT NOT NULL,
+                potential_option_grade TEXT,
+                additional_potential_option_grade TEXT,
+                after_potential_option TEXT NOT NULL DEFAULT '[]',
+                after_additional_potential_option TEXT NOT NULL DEFAULT '[]',
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
         )?;
 
-        Ok(self.conn.last_insert_rowid())
-    }
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_cube_history_date ON cube_history(date_create)",
+            [],
+        )?;
 
-    pub fn update_hunting_session(&self, session: &HuntingSession) -> Result<()> {
+        // 잠재능력 재설정 기록 테이블 (Open API history/potential)
         self.conn.execute(
-            "UPDATE hunting_sessions SET
+            "CREATE TABLE IF NOT EXISTS potential_history (
+                id TEXT PRIMARY KEY,
+                character_name TEXT NOT NULL,
+                date_create TEXT NOT NULL,
+                cube_type TEXT NOT NULL,
+                item_upgrade_result TEXT NOT NULL,
+                item_equipment_part TEXT NOT NULL,
+                item_level INTEGER NOT NULL,
+                target_item TEXT NOT NULL,
+                potential_option_grade TEXT,
+                additional_potential_option_grade TEXT,
+                after_potential_option TEXT NOT NULL DEFAULT '[]',
+                after_additional_potential_option TEXT NOT NULL DEFAULT '[]',
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_potential_history_date ON potential_history(date_create)",
+            [],
+        )?;
+
+        // 랭킹 스냅샷 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS ranking_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                world_rank INTEGER,
+                class_rank INTEGER,
+                union_rank INTEGER,
+                dojang_rank INTEGER,
+                dojang_floor INTEGER,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id),
+                UNIQUE(character_id, date)
+            )",
+            [],
+        )?;
+
+        // 메소 지출 테이블
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS meso_expenses (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER NOT NULL,
+                date TEXT NOT NULL,
+                category TEXT NOT NULL,
+                amount INTEGER NOT NULL,
+                memo TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_meso_expenses_character_date ON meso_expenses(character_id, date)",
+            [],
+        )?;
+
+        // 버닝/경험치 이벤트 테이블 (character_id NULL = 모든 캐릭터)
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS game_events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                character_id INTEGER,
+                name TEXT NOT NULL,
+                event_type TEXT NOT NULL,
+                start_date TEXT NOT NULL,
+                end_date TEXT NOT NULL,
+                exp_multiplier REAL NOT NULL DEFAULT 1.0,
+                memo TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (character_id) REFERENCES characters(id)
+            )",
+            [],
+        )?;
+
+        // 사냥 기록 수정 이력 (필드별 변경 전/후 JSON)
+        // 삭제 후 휴지통에서 복원해도 이력이 남도록 hunting_sessions 외래 키는 두지 않음
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS hunting_session_revisions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                changes TEXT NOT NULL,
+                reverted_revision_id INTEGER,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_hunting_session_revisions_session ON hunting_session_revisions(session_id)",
+            [],
+        )?;
+
+        self.init_search_index()?;
+        self.init_journal()?;
+
+        // 평문으로 저장된 API Key 암호화 (실패하면 다음 실행 때 다시 시도)
+        self.encrypt_plain_api_key().ok();
+
+        Ok(())
+    }
+
+    // 전문 검색 인덱스 (사냥 메모, 득템 이름, 보스 클리어, 캐릭터 이름)
+    // 원본 테이블의 트리거로 동기화하므로 저장/가져오기/초기화 경로를 따로 챙기지 않아도 됨
+    fn init_search_index(&self) -> Result<()> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'search_index')",
+            [],
+            |row| row.get(0),
+        )?;
+
+        self.conn.execute(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+                body,
+                source UNINDEXED,
+                source_id UNINDEXED,
+                character_id UNINDEXED,
+                date UNINDEXED,
+                tokenize = 'unicode61 remove_diacritics 2'
+            )",
+            [],
+        )?;
+
+        for source in SearchSource::ALL {
+            let table = source.table();
+            let name = source.as_str();
+            let (columns, condition) = source.index_columns();
+            let insert = |row: &str| {
+                format!(
+                    "INSERT INTO search_index (body, character_id, date, source, source_id)
+                     SELECT {}, '{}', {row}.id WHERE {};",
+                    columns.replace("{row}", row),
+                    name,
+                    condition.replace("{row}", row),
+                )
+            };
+            let delete = format!("DELETE FROM search_index WHERE source = '{}' AND source_id = old.id;", name);
+
+            self.conn.execute_batch(&format!(
+                "CREATE TRIGGER IF NOT EXISTS search_{name}_insert AFTER INSERT ON {table} BEGIN {} END;
+                 CREATE TRIGGER IF NOT EXISTS search_{name}_update AFTER UPDATE ON {table} BEGIN {delete} {} END;
+                 CREATE TRIGGER IF NOT EXISTS search_{name}_delete AFTER DELETE ON {table} BEGIN {delete} END;",
+                insert("new"),
+                insert("new"),
+            ))?;
+        }
+
+        // 별칭이 추가되면 해당 아이템의 득템 기록 다시 색인
+        let (columns, condition) = SearchSource::ItemDrop.index_columns();
+        self.conn.execute_batch(&format!(
+            "CREATE TRIGGER IF NOT EXISTS search_item_alias_insert AFTER INSERT ON item_aliases BEGIN
+                DELETE FROM search_index WHERE source = 'item_drop'
+                    AND source_id IN (SELECT id FROM item_drops WHERE item_id = new.item_id);
+                INSERT INTO search_index (body, character_id, date, source, source_id)
+                    SELECT {}, 'item_drop', item_drops.id FROM item_drops
+                    WHERE item_drops.item_id = new.item_id AND {};
+             END;",
+            columns.replace("{row}", "item_drops"),
+            condition.replace("{row}", "item_drops"),
+        ))?;
+
+        // 인덱스를 처음 만들 때 기존 기록 채우기
+        if !exists {
+            self.rebuild_search_index()?;
+        }
+        Ok(())
+    }
+
+    pub fn rebuild_search_index(&self) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM search_index", [])?;
+        for source in SearchSource::ALL {
+            let table = source.table();
+            let (columns, condition) = source.index_columns();
+            tx.execute(
+                &format!(
+                    "INSERT INTO search_index (body, character_id, date, source, source_id)
+                     SELECT {}, '{}', {table}.id FROM {table} WHERE {}",
+                    columns.replace("{row}", table),
+                    source.as_str(),
+                    condition.replace("{row}", table),
+                ),
+                [],
+            )?;
+        }
+        tx.commit()
+    }
+
+    // 관련도 순 검색 (같은 점수면 최근 기록 먼저)
+    pub fn search(&self, query: &str, character_id: Option<i64>, source: Option<SearchSource>, limit: i64) -> Result<Vec<SearchHit>> {
+        let Some(match_query) = search::build_match_query(query) else {
+            return Ok(Vec::new());
+        };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT search_index.source, search_index.source_id, search_index.character_id,
+                    c.character_name, search_index.date,
+                    snippet(search_index, 0, '[', ']', '…', 12), bm25(search_index)
+             FROM search_index
+             LEFT JOIN characters c ON c.id = search_index.character_id
+             WHERE search_index MATCH ?1
+               AND (?2 IS NULL OR search_index.character_id = ?2)
+               AND (?3 IS NULL OR search_index.source = ?3)
+             ORDER BY bm25(search_index), search_index.date DESC
+             LIMIT ?4"
+        )?;
+
+        let hits = stmt.query_map(
+            params![match_query, character_id, source.map(|s| s.as_str()), limit],
+            |row| {
+                let source: String = row.get(0)?;
+                Ok(SearchHit {
+                    source: SearchSource::parse(&source).ok_or(rusqlite::Error::InvalidColumnType(
+                        0,
+                        "source".to_string(),
+                        rusqlite::types::Type::Text,
+                    ))?,
+                    source_id: row.get(1)?,
+                    character_id: row.get(2)?,
+                    character_name: row.get(3)?,
+                    date: row.get(4)?,
+                    snippet: row.get(5)?,
+                    score: row.get(6)?,
+                })
+            },
+        )?.collect::<Result<Vec<_>>>()?;
+        Ok(hits)
+    }
+
+    // 변경 기록 (실행 취소/다시 실행, 휴지통)
+    // 대상 테이블의 트리거가 journal_control에 작업 id가 있을 때만 행의 변경 전/후 값을 기록하므로
+    // with_journal로 감싼 사용자 작업만 기록되고, 되돌리기/가져오기/초기화는 기록되지 않음
+    fn init_journal(&self) -> Result<()> {
+        // 사용자 작업 (삭제 작업은 휴지통 항목)
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS journal_operations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL,
+                table_name TEXT NOT NULL,
+                label TEXT NOT NULL,
+                undone INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )?;
+
+        // 작업이 바꾼 행 (변경 전/후 행을 JSON으로)
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS journal_entries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                operation_id INTEGER NOT NULL,
+                table_name TEXT NOT NULL,
+                row_id INTEGER NOT NULL,
+                action TEXT NOT NULL,
+                before_json TEXT,
+                after_json TEXT,
+                FOREIGN KEY (operation_id) REFERENCES journal_operations(id)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_journal_entries_operation ON journal_entries(operation_id)",
+            [],
+        )?;
+
+        // 지금 기록 중인 작업 (앱이 작업 도중 종료됐으면 비움)
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS journal_control (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                operation_id INTEGER
+            )",
+            [],
+        )?;
+        self.conn.execute(
+            "INSERT INTO journal_control (id, operation_id) VALUES (1, NULL)
+             ON CONFLICT(id) DO UPDATE SET operation_id = NULL",
+            [],
+        )?;
+
+        // 컬럼이 추가돼도 기록에 포함되도록 트리거는 매번 다시 만듦
+        let recording = "WHEN (SELECT operation_id FROM journal_control WHERE id = 1) IS NOT NULL";
+        for table in JOURNAL_TABLES {
+            let columns = self.table_columns(table)?;
+            let image = |row: &str| {
+                let pairs: Vec<String> = columns
+                    .iter()
+                    .map(|column| format!("'{}', {row}.{}", column, journal::quote_identifier(column)))
+                    .collect();
+                format!("json_object({})", pairs.join(", "))
+            };
+            let record = |action: &str, row_id: &str, before: &str, after: &str| {
+                format!(
+                    "INSERT INTO journal_entries (operation_id, table_name, row_id, action, before_json, after_json)
+                     SELECT operation_id, '{table}', {row_id}, '{action}', {before}, {after} FROM journal_control WHERE id = 1;"
+                )
+            };
+
+            self.conn.execute_batch(&format!(
+                "DROP TRIGGER IF EXISTS journal_{table}_insert;
+                 DROP TRIGGER IF EXISTS journal_{table}_update;
+                 DROP TRIGGER IF EXISTS journal_{table}_delete;
+                 CREATE TRIGGER journal_{table}_insert AFTER INSERT ON {table} {recording} BEGIN {} END;
+                 CREATE TRIGGER journal_{table}_update AFTER UPDATE ON {table} {recording} BEGIN {} END;
+                 CREATE TRIGGER journal_{table}_delete AFTER DELETE ON {table} {recording} BEGIN {} END;",
+                record("insert", "new.id", "NULL", &image("new")),
+                record("update", "new.id", &image("old"), &image("new")),
+                record("delete", "old.id", &image("old"), "NULL"),
+            ))?;
+        }
+
+        self.purge_journal()?;
+        Ok(())
+    }
+
+    fn table_columns(&self, table: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(&format!("PRAGMA table_info({})", table))?;
+        let columns = stmt.query_map([], |row| row.get(1))?.collect::<Result<Vec<String>>>()?;
+        Ok(columns)
+    }
+
+    // 사용자 작업 하나를 기록하면서 실행 (기록된 변경이 있으면 다시 실행 목록은 비움)
+    pub fn with_journal<T>(
+        &self,
+        kind: OperationKind,
+        table: &str,
+        label: &str,
+        f: impl FnOnce(&Self) -> Result<T>,
+    ) -> Result<T> {
+        // f가 실패하면 작업 기록과 함께 일부만 반영된 변경도 모두 되돌림
+        self.in_savepoint(|db| {
+            db.conn.execute(
+                "INSERT INTO journal_operations (kind, table_name, label) VALUES (?1, ?2, ?3)",
+                params![kind.as_str(), table, label],
+            )?;
+            let operation_id = db.conn.last_insert_rowid();
+            db.conn.execute("UPDATE journal_control SET operation_id = ?1 WHERE id = 1", params![operation_id])?;
+
+            let value = f(db)?;
+            db.conn.execute("UPDATE journal_control SET operation_id = NULL WHERE id = 1", [])?;
+
+            let recorded: i64 = db.conn.query_row(
+                "SELECT COUNT(*) FROM journal_entries WHERE operation_id = ?1",
+                params![operation_id],
+                |row| row.get(0),
+            )?;
+            if recorded == 0 {
+                db.delete_journal_operations("id = ?1", &operation_id)?;
+            } else {
+                db.delete_journal_operations("undone = 1 AND id <> ?1", &operation_id)?;
+            }
+            Ok(value)
+        })
+    }
+
+    // SAVEPOINT 안에서 실행하고 실패하면 되돌림 (트랜잭션 안에서도 중첩 가능)
+    fn in_savepoint<T>(&self, f: impl FnOnce(&Self) -> Result<T>) -> Result<T> {
+        self.conn.execute_batch("SAVEPOINT db_savepoint")?;
+        match f(self) {
+            Ok(value) => {
+                self.conn.execute_batch("RELEASE db_savepoint")?;
+                Ok(value)
+            }
+            Err(e) => {
+                self.conn.execute_batch("ROLLBACK TO db_savepoint; RELEASE db_savepoint")?;
+                Err(e)
+            }
+        }
+    }
+
+    // 조건에 맞는 작업과 기록된 행 삭제 (조건의 ?1에 param 전달)
+    fn delete_journal_operations(&self, condition: &str, param: &dyn ToSql) -> Result<usize> {
+        self.conn.execute(
+            &format!(
+                "DELETE FROM journal_entries WHERE operation_id IN (SELECT id FROM journal_operations WHERE {condition})"
+            ),
+            params![param],
+        )?;
+        self.conn.execute(&format!("DELETE FROM journal_operations WHERE {condition}"), params![param])
+    }
+
+    fn clear_journal(&self) -> Result<()> {
+        self.conn.execute("DELETE FROM journal_entries", [])?;
+        self.conn.execute("DELETE FROM journal_operations", [])?;
+        Ok(())
+    }
+
+    // 보관 기간이 지난 작업 정리 (휴지통 항목도 완전히 삭제됨)
+    pub fn purge_journal(&self) -> Result<usize> {
+        let days = self.get_app_settings()?.trash_retention_days.max(1);
+        let purged = self.delete_journal_operations("created_at < datetime('now', ?1)", &format!("-{} days", days))?;
+
+        // 휴지통에서도 사라진 사냥 기록의 수정 이력
+        self.conn.execute(
+            "DELETE FROM hunting_session_revisions
+             WHERE session_id NOT IN (SELECT id FROM hunting_sessions)
+               AND session_id NOT IN (SELECT row_id FROM journal_entries WHERE table_name = 'hunting_sessions')",
+            [],
+        )?;
+        Ok(purged)
+    }
+
+    fn journal_operation(&self, condition: &str) -> Result<Option<JournalOperation>> {
+        self.conn.query_row(
+            &format!(
+                "SELECT id, kind, table_name, label, undone, created_at FROM journal_operations WHERE {condition} LIMIT 1"
+            ),
+            [],
+            row_to_journal_operation,
+        ).optional()
+    }
+
+    pub fn get_journal_state(&self) -> Result<JournalState> {
+        Ok(JournalState {
+            undo: self.journal_operation("undone = 0 ORDER BY id DESC")?,
+            redo: self.journal_operation("undone = 1 ORDER BY id ASC")?,
+        })
+    }
+
+    // 가장 최근 작업 되돌리기 (되돌릴 작업이 없으면 None)
+    pub fn undo(&self) -> Result<Option<JournalOperation>> {
+        let Some(operation) = self.journal_operation("undone = 0 ORDER BY id DESC")? else {
+            return Ok(None);
+        };
+        self.apply_operation_or_discard(&operation, false)?;
+        Ok(Some(JournalOperation { undone: true, ..operation }))
+    }
+
+    // 가장 먼저 되돌린 작업 다시 실행 (다시 실행할 작업이 없으면 None)
+    pub fn redo(&self) -> Result<Option<JournalOperation>> {
+        let Some(operation) = self.journal_operation("undone = 1 ORDER BY id ASC")? else {
+            return Ok(None);
+        };
+        self.apply_operation_or_discard(&operation, true)?;
+        Ok(Some(JournalOperation { undone: false, ..operation }))
+    }
+
+    // 이후 변경과 충돌하는 작업은 기록에서 제외 (그 앞의 작업은 계속 되돌릴 수 있도록)
+    fn apply_operation_or_discard(&self, operation: &JournalOperation, forward: bool) -> Result<()> {
+        if let Err(e) = self.apply_operation(operation.id, forward) {
+            self.delete_journal_operations("id = ?1", &operation.id)?;
+            return Err(message_error(format!(
+                "'{}' 작업을 {} 수 없어 기록에서 제외했습니다: {}",
+                operation.label,
+                if forward { "다시 실행할" } else { "되돌릴" },
+                e
+            )));
+        }
+        Ok(())
+    }
+
+    // 작업의 변경을 한 트랜잭션으로 적용 (forward면 다시 실행, 아니면 역순으로 되돌리기)
+    fn apply_operation(&self, operation_id: i64, forward: bool) -> Result<()> {
+        let mut stmt = self.conn.prepare(
+            "SELECT table_name, row_id, action, before_json, after_json
+             FROM journal_entries WHERE operation_id = ?1 ORDER BY id"
+        )?;
+        let mut entries = stmt.query_map(params![operation_id], |row| {
+            Ok(JournalEntry {
+                table_name: row.get(0)?,
+                row_id: row.get(1)?,
+                action: row.get(2)?,
+                before_json: row.get(3)?,
+                after_json: row.get(4)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+        if !forward {
+            entries.reverse();
+        }
+
+        // 실행 취소/다시 실행으로 바뀐 사냥 기록도 수정 이력에 남김
+        let mut sessions = Vec::new();
+        for entry in entries.iter().filter(|e| e.table_name == "hunting_sessions" && e.action == "update") {
+            if let Some(session) = self.get_hunting_session(entry.row_id)? {
+                if !sessions.iter().any(|s: &HuntingSession| s.id == session.id) {
+                    sessions.push(session);
+                }
+            }
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        for entry in &entries {
+            self.apply_journal_entry(entry, forward)?;
+        }
+        let source = if forward { RevisionSource::Redo } else { RevisionSource::Undo };
+        for session in &sessions {
+            self.record_session_revision(session, source, None)?;
+        }
+        tx.execute(
+            "UPDATE journal_operations SET undone = ?1 WHERE id = ?2",
+            params![!forward, operation_id],
+        )?;
+        tx.commit()
+    }
+
+    fn apply_journal_entry(&self, entry: &JournalEntry, forward: bool) -> Result<()> {
+        if !JOURNAL_TABLES.contains(&entry.table_name.as_str()) {
+            return Err(message_error(format!("기록 대상이 아닌 테이블입니다: {}", entry.table_name)));
+        }
+        let table = journal::quote_identifier(&entry.table_name);
+
+        // 되돌릴 때는 insert <-> delete, update는 변경 전 값으로
+        let (action, image) = match (entry.action.as_str(), forward) {
+            (action, true) => (action, entry.after_json.as_deref()),
+            ("insert", false) => ("delete", None),
+            ("delete", false) => ("insert", entry.before_json.as_deref()),
+            (_, false) => ("update", entry.before_json.as_deref()),
+        };
+
+        if action == "delete" {
+            self.conn.execute(&format!("DELETE FROM {table} WHERE id = ?1"), params![entry.row_id])?;
+            return Ok(());
+        }
+
+        let image: serde_json::Map<String, serde_json::Value> = image
+            .and_then(|json| serde_json::from_str(json).ok())
+            .ok_or_else(|| message_error(format!("{} {} 기록이 손상되었습니다", entry.table_name, entry.row_id)))?;
+        let columns: Vec<String> = image.keys().map(|column| journal::quote_identifier(column)).collect();
+        let mut values: Vec<rusqlite::types::Value> = image.values().map(journal::to_sql_value).collect();
+
+        let changed = if action == "insert" {
+            let placeholders: Vec<String> = (1..=columns.len()).map(|i| format!("?{}", i)).collect();
+            self.conn.execute(
+                &format!("INSERT INTO {table} ({}) VALUES ({})", columns.join(", "), placeholders.join(", ")),
+                rusqlite::params_from_iter(values),
+            )?
+        } else {
+            let assignments: Vec<String> = columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| format!("{} = ?{}", column, i + 1))
+                .collect();
+            values.push(rusqlite::types::Value::Integer(entry.row_id));
+            self.conn.execute(
+                &format!("UPDATE {table} SET {} WHERE id = ?{}", assignments.join(", "), values.len()),
+                rusqlite::params_from_iter(values),
+            )?
+        };
+        if changed == 0 {
+            return Err(message_error(format!("{} {} 기록을 찾을 수 없습니다", entry.table_name, entry.row_id)));
+        }
+        Ok(())
+    }
+
+    // 휴지통 (보관 기간 안의 삭제 작업, 최근 삭제 순)
+    pub fn get_trash(&self) -> Result<Vec<TrashItem>> {
+        self.purge_journal()?;
+        let days = self.get_app_settings()?.trash_retention_days.max(1);
+        let mut stmt = self.conn.prepare(
+            "SELECT o.id, o.table_name, o.label, o.created_at, datetime(o.created_at, ?1),
+                    (SELECT e.before_json FROM journal_entries e
+                     WHERE e.operation_id = o.id AND e.table_name = o.table_name AND e.action = 'delete'
+                     ORDER BY e.id LIMIT 1),
+                    (SELECT COUNT(*) FROM journal_entries e
+                     WHERE e.operation_id = o.id AND e.table_name <> o.table_name)
+             FROM journal_operations o
+             WHERE o.kind = 'delete' AND o.undone = 0
+             ORDER BY o.id DESC"
+        )?;
+
+        let items = stmt.query_map(params![format!("+{} days", days)], |row| {
+            let row_json: Option<String> = row.get(5)?;
+            Ok(TrashItem {
+                operation_id: row.get(0)?,
+                table_name: row.get(1)?,
+                label: row.get(2)?,
+                deleted_at: row.get(3)?,
+                expires_at: row.get(4)?,
+                row: row_json
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .unwrap_or(serde_json::Value::Null),
+                related_rows: row.get(6)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+        Ok(items)
+    }
+
+    fn trash_operation(&self, operation_id: i64) -> Result<JournalOperation> {
+        self.journal_operation(&format!("id = {} AND kind = 'delete' AND undone = 0", operation_id))?
+            .ok_or_else(|| message_error("휴지통에 없는 항목입니다".to_string()))
+    }
+
+    // 삭제된 기록 복원 (복원한 항목은 실행 취소 기록에서도 빠짐)
+    pub fn restore_trash(&self, operation_id: i64) -> Result<()> {
+        self.trash_operation(operation_id)?;
+        self.apply_operation(operation_id, false)?;
+        self.delete_journal_operations("id = ?1", &operation_id)?;
+        Ok(())
+    }
+
+    // 휴지통 항목 완전히 삭제 (operation_id가 없으면 전체 비우기)
+    pub fn purge_trash(&self, operation_id: Option<i64>) -> Result<usize> {
+        match operation_id {
+            Some(id) => {
+                self.trash_operation(id)?;
+                self.delete_journal_operations("id = ?1", &id)
+            }
+            None => self.delete_journal_operations("kind = ?1 AND undone = 0", &OperationKind::Delete.as_str()),
+        }
+    }
+
+    // Settings (API Key 목록, 가장 먼저 등록한 키가 기본 키)
+    fn settings_from_row(&self, row: &rusqlite::Row) -> Result<Settings> {
+        let stored: String = row.get(2)?;
+        let decrypted = secret::decrypt(&stored, self.api_key_passphrase.as_deref()).ok();
+        Ok(Settings {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            locked: decrypted.is_none(),
+            api_key: decrypted.unwrap_or_default(),
+            key_protection: secret::protection(&stored),
+            created_at: row.get(3)?,
+            updated_at: row.get(4)?,
+        })
+    }
+
+    pub fn get_settings(&self) -> Result<Option<Settings>> {
+        self.conn
+            .query_row(
+                "SELECT id, name, api_key, created_at, updated_at FROM settings ORDER BY id LIMIT 1",
+                [],
+                |row| self.settings_from_row(row),
+            )
+            .optional()
+    }
+
+    pub fn get_api_keys(&self) -> Result<Vec<Settings>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, api_key, created_at, updated_at FROM settings ORDER BY id"
+        )?;
+        let keys = stmt.query_map([], |row| self.settings_from_row(row))?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(keys)
+    }
+
+    // 캐릭터 목록 조회로 연결된 키, 없으면 기본 키
+    pub fn get_api_key_for_ocid(&self, ocid: &str) -> Result<Option<Settings>> {
+        let linked = self.conn
+            .query_row(
+                "SELECT s.id, s.name, s.api_key, s.created_at, s.updated_at
+                 FROM settings s JOIN api_key_characters c ON c.api_key_id = s.id
+                 WHERE c.ocid = ?1 ORDER BY s.id LIMIT 1",
+                params![ocid],
+                |row| self.settings_from_row(row),
+            )
+            .optional()?;
+        match linked {
+            Some(settings) => Ok(Some(settings)),
+            None => self.get_settings(),
+        }
+    }
+
+    // 암호화된 그대로의 저장값 (id, 이름, 값)
+    fn get_stored_api_keys(&self) -> Result<Vec<(i64, String, String)>> {
+        let mut stmt = self.conn.prepare("SELECT id, name, api_key FROM settings ORDER BY id")?;
+        let keys = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(keys)
+    }
+
+    fn update_stored_api_key(&self, id: i64, stored: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE settings SET api_key = ?1, updated_at = datetime('now') WHERE id = ?2",
+            params![stored, id],
+        )?;
+        Ok(())
+    }
+
+    // 같은 이름이 있으면 키를 교체, 없으면 추가
+    // 잠금 해제된 암호가 있으면 암호로, 없으면 로컬 키로 암호화해서 저장
+    // 암호로 보호된 키가 있는데 잠겨 있으면 저장 거부 (로컬 키 암호화와 섞이지 않도록)
+    pub fn save_api_key(&self, name: &str, api_key: &str) -> Result<i64> {
+        if self.api_key_passphrase.is_none()
+            && self.get_stored_api_keys()?.iter().any(|(_, _, stored)| secret::protection(stored) == KeyProtection::Passphrase)
+        {
+            return Err(message_error("API Key가 암호로 잠겨 있습니다. 암호를 입력해 잠금을 해제한 뒤 저장해 주세요".to_string()));
+        }
+        let encrypted = secret::encrypt(api_key, self.api_key_passphrase.as_deref()).map_err(message_error)?;
+        self.save_stored_api_key(name, &encrypted)
+    }
+
+    fn save_stored_api_key(&self, name: &str, stored: &str) -> Result<i64> {
+        let existing_id: Option<i64> = self.conn
+            .query_row("SELECT id FROM settings WHERE name = ?1", params![name], |row| row.get(0))
+            .optional()?;
+        match existing_id {
+            Some(id) => {
+                self.update_stored_api_key(id, stored)?;
+                Ok(id)
+            }
+            None => {
+                self.conn.execute(
+                    "INSERT INTO settings (name, api_key) VALUES (?1, ?2)",
+                    params![name, stored],
+                )?;
+                Ok(self.conn.last_insert_rowid())
+            }
+        }
+    }
+
+    pub fn delete_api_key(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM api_key_characters WHERE api_key_id = ?1", params![id])?;
+        self.conn.execute("DELETE FROM settings WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    // 키로 조회한 캐릭터 목록으로 교체
+    pub fn set_api_key_characters(&self, api_key_id: i64, characters: &[ApiKeyCharacter]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM api_key_characters WHERE api_key_id = ?1", params![api_key_id])?;
+        for c in characters {
+            tx.execute(
+                "INSERT OR REPLACE INTO api_key_characters
+                    (api_key_id, ocid, character_name, world_name, character_class, character_level)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![api_key_id, c.ocid, c.character_name, c.world_name, c.character_class, c.character_level],
+            )?;
+        }
+        tx.commit()
+    }
+
+    pub fn get_api_key_characters(&self, api_key_id: i64) -> Result<Vec<ApiKeyCharacter>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT api_key_id, ocid, character_name, world_name, character_class, character_level
+             FROM api_key_characters WHERE api_key_id = ?1
+             ORDER BY character_level DESC, character_name"
+        )?;
+        let characters = stmt.query_map(params![api_key_id], row_to_api_key_character)?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(characters)
+    }
+
+    fn encrypt_plain_api_key(&self) -> Result<()> {
+        for (id, _, stored) in self.get_stored_api_keys()? {
+            if secret::protection(&stored) == KeyProtection::Plain {
+                self.update_stored_api_key(id, &secret::encrypt(&stored, None).map_err(message_error)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn decrypt_api_key(&self, stored: &str) -> Result<String> {
+        secret::decrypt(stored, self.api_key_passphrase.as_deref()).map_err(message_error)
+    }
+
+    // 암호가 맞으면 이 연결이 열려 있는 동안 API Key 사용 가능
+    pub fn unlock_api_key(&mut self, passphrase: &str) -> Result<()> {
+        let stored_keys = self.get_stored_api_keys()?;
+        let (_, _, stored) = stored_keys
+            .iter()
+            .find(|(_, _, stored)| secret::protection(stored) == KeyProtection::Passphrase)
+            .ok_or_else(|| message_error("암호로 보호된 API Key가 없습니다".to_string()))?;
+        secret::decrypt(stored, Some(passphrase)).map_err(message_error)?;
+        self.api_key_passphrase = Some(passphrase.to_string());
+        Ok(())
+    }
+
+    // 모든 API Key를 새 암호(None이면 로컬 키)로 다시 암호화
+    pub fn set_api_key_passphrase(&mut self, passphrase: Option<&str>) -> Result<()> {
+        let stored_keys = self.get_stored_api_keys()?;
+        if stored_keys.is_empty() {
+            return Err(message_error("API Key가 설정되지 않았습니다".to_string()));
+        }
+        let mut encrypted = Vec::new();
+        for (id, _, stored) in &stored_keys {
+            let api_key = self.decrypt_api_key(stored)?;
+            encrypted.push((*id, secret::encrypt(&api_key, passphrase).map_err(message_error)?));
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        for (id, stored) in &encrypted {
+            self.update_stored_api_key(*id, stored)?;
+        }
+        tx.commit()?;
+        self.api_key_passphrase = passphrase.map(|p| p.to_string());
+        Ok(())
+    }
+
+    // Characters
+    pub fn get_active_character(&self) -> Result<Option<Character>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_name, character_image, ocid, world_name,
+                    character_class, character_level, character_exp_rate, is_active, created_at, updated_at
+             FROM characters WHERE is_active = 1 LIMIT 1"
+        )?;
+
+        let mut rows = stmt.query([])?;
+
+        if let Some(row) = rows.next()? {
+            Ok(Some(Character {
+                id: row.get(0)?,
+                character_name: row.get(1)?,
+                character_image: row.get(2)?,
+                ocid: row.get(3)?,
+                world_name: row.get(4)?,
+                character_class: row.get(5)?,
+                character_level: row.get(6)?,
+                character_exp_rate: row.get(7)?,
+                is_active: row.get::<_, i32>(8)? == 1,
+                created_at: row.get(9)?,
+                updated_at: row.get(10)?,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn get_characters(&self) -> Result<Vec<Character>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_name, character_image, ocid, world_name,
+                    character_class, character_level, character_exp_rate, is_active, created_at, updated_at
+             FROM characters ORDER BY id"
+        )?;
+
+        let characters = stmt.query_map([], |row| {
+            Ok(Character {
+                id: row.get(0)?,
+                character_name: row.get(1)?,
+                character_image: row.get(2)?,
+                ocid: row.get(3)?,
+                world_name: row.get(4)?,
+                character_class: row.get(5)?,
+                character_level: row.get(6)?,
+                character_exp_rate: row.get(7)?,
+                is_active: row.get::<_, i32>(8)? == 1,
+                created_at: row.get(9)?,
+                updated_at: row.get(10)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(characters)
+    }
+
+    pub fn register_character(&self, character: &Character) -> Result<i64> {
+        // ocid 또는 character_name으로 기존 캐릭터 매칭
+        // - ocid 일치: 닉네임이 변경된 경우
+        // - character_name 일치: 게임 업데이트로 ocid가 변경된 경우
+        let existing_id: Option<i64> = self.conn.query_row(
+            "SELECT id FROM characters WHERE ocid = ?1 OR character_name = ?2 LIMIT 1",
+            params![character.ocid, character.character_name],
+            |row| row.get(0),
+        ).optional()?;
+
+        // 기존 활성 캐릭터 비활성화
+        self.conn.execute("UPDATE characters SET is_active = 0", [])?;
+
+        if let Some(id) = existing_id {
+            // 같은 캐릭터 → 정보 업데이트 + 활성화 (ocid, 닉네임 모두 갱신)
+            self.conn.execute(
+                "UPDATE characters SET character_name = ?1, character_image = ?2,
+                 ocid = ?3, world_name = ?4, character_class = ?5, character_level = ?6,
+                 character_exp_rate = ?7, is_active = 1, updated_at = datetime('now')
+                 WHERE id = ?8",
+                params![
+                    character.character_name,
+                    character.character_image,
+                    character.ocid,
+                    character.world_name,
+                    character.character_class,
+                    character.character_level,
+                    character.character_exp_rate,
+                    id,
+                ],
+            )?;
+            Ok(id)
+        } else {
+            // 새 캐릭터 등록
+            self.conn.execute(
+                "INSERT INTO characters (character_name, character_image, ocid, world_name,
+                                        character_class, character_level, character_exp_rate, is_active)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 1)",
+                params![
+                    character.character_name,
+                    character.character_image,
+                    character.ocid,
+                    character.world_name,
+                    character.character_class,
+                    character.character_level,
+                    character.character_exp_rate,
+                ],
+            )?;
+            Ok(self.conn.last_insert_rowid())
+        }
+    }
+
+    pub fn update_character(&self, id: i64, name: &str, level: i32, exp_rate: &str, image: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE characters SET character_name = ?1, character_level = ?2, character_exp_rate = ?3,
+             character_image = ?4, updated_at = datetime('now') WHERE id = ?5",
+            params![name, level, exp_rate, image, id],
+        )?;
+        Ok(())
+    }
+
+    // Hunting Sessions
+    pub fn get_hunting_sessions(&self, character_id: i64, date: &str) -> Result<Vec<HuntingSession>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, session_order, start_level, end_level,
+                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
+                    meso_gained, duration_minutes, sojaebi,
+                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
+                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
+                    start_screenshot, end_screenshot, memo, created_at, updated_at, map_id
+             FROM hunting_sessions WHERE character_id = ?1 AND date = ?2 ORDER BY session_order"
+        )?;
+
+        let mut sessions = stmt.query_map(params![character_id, date], |row| {
+            row_to_hunting_session(row)
+        })?.collect::<Result<Vec<_>>>()?;
+        self.load_session_consumables(&mut sessions)?;
+
+        Ok(sessions)
+    }
+
+    pub fn save_hunting_session(&self, session: &HuntingSession) -> Result<i64> {
+        // 해당 캐릭터의 해당 날짜의 마지막 session_order 조회
+        let next_order: i32 = self.conn.query_row(
+            "SELECT COALESCE(MAX(session_order), 0) + 1 FROM hunting_sessions WHERE character_id = ?1 AND date = ?2",
+            params![session.character_id, session.date],
+            |row| row.get(0),
+        )?;
+
+        self.conn.execute(
+            "INSERT INTO hunting_sessions (character_id, date, session_order, start_level, end_level,
+                start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso, meso_gained,
+                duration_minutes, sojaebi,
+                start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
+                start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
+                start_screenshot, end_screenshot, memo, map_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26)",
+            params![
+                session.character_id, session.date, next_order, session.start_level, session.end_level,
+                session.start_exp_percent, session.end_exp_percent, session.exp_gained,
+                session.start_meso, session.end_meso, session.meso_gained,
+                session.duration_minutes, session.sojaebi,
+                session.start_sol_erda, session.end_sol_erda, session.start_sol_erda_gauge, session.end_sol_erda_gauge, session.sol_erda_gained,
+                session.start_sol_erda_piece, session.end_sol_erda_piece, session.sol_erda_piece_gained, session.sol_erda_piece_price,
+                session.start_screenshot, session.end_screenshot, session.memo, session.map_id
+            ],
+        )?;
+
+        let id = self.conn.last_insert_rowid();
+        self.save_session_consumables(id, &session.consumables)?;
+        Ok(id)
+    }
+
+    // 여러 세션을 한 번에 저장 (하나라도 실패하면 전체 취소)
+    pub fn save_hunting_sessions(&self, sessions: &[HuntingSession]) -> Result<usize> {
+        self.in_savepoint(|db| {
+            for session in sessions {
+                db.save_hunting_session(session)?;
+            }
+            Ok(sessions.len())
+        })
+    }
+
+    pub fn get_hunting_session(&self, id: i64) -> Result<Option<HuntingSession>> {
+        let session = self.conn.query_row(
+            "SELECT id, character_id, date, session_order, start_level, end_level,
+                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
+                    meso_gained, duration_minutes, sojaebi,
+                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
+                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
+                    start_screenshot, end_screenshot, memo, created_at, updated_at, map_id
+             FROM hunting_sessions WHERE id = ?1",
+            params![id],
+            row_to_hunting_session,
+        ).optional()?;
+
+        let mut sessions: Vec<HuntingSession> = session.into_iter().collect();
+        self.load_session_consumables(&mut sessions)?;
+        Ok(sessions.pop())
+    }
+
+    // 수정 전 값은 수정 이력에 남김
+    pub fn update_hunting_session(&self, session: &HuntingSession) -> Result<()> {
+        let before = self.get_hunting_session(session.id)?;
+        self.write_hunting_session(session)?;
+        if let Some(before) = before {
+            self.record_session_revision(&before, RevisionSource::Edit, None)?;
+        }
+        Ok(())
+    }
+
+    fn write_hunting_session(&self, session: &HuntingSession) -> Result<()> {
+        self.conn.execute(
+            "UPDATE hunting_sessions SET
                 start_level = ?1, end_level = ?2, start_exp_percent = ?3, end_exp_percent = ?4,
                 exp_gained = ?5, start_meso = ?6, end_meso = ?7, meso_gained = ?8,
                 duration_minutes = ?9, sojaebi = ?10,
                 start_sol_erda = ?11, end_sol_erda = ?12, start_sol_erda_gauge = ?13, end_sol_erda_gauge = ?14, sol_erda_gained = ?15,
                 start_sol_erda_piece = ?16, end_sol_erda_piece = ?17, sol_erda_piece_gained = ?18, sol_erda_piece_price = ?19,
-                start_screenshot = ?20, end_screenshot = ?21, items = ?22, memo = ?23, updated_at = datetime('now')
-             WHERE id = ?24",
+                start_screenshot = ?20, end_screenshot = ?21, memo = ?22, updated_at = datetime('now')
+             WHERE id = ?23",
+            params![
+                session.start_level, session.end_level, session.start_exp_percent, session.end_exp_percent,
+                session.exp_gained, session.start_meso, session.end_meso, session.meso_gained,
+                session.duration_minutes, session.sojaebi,
+                session.start_sol_erda, session.end_sol_erda, session.start_sol_erda_gauge, session.end_sol_erda_gauge, session.sol_erda_gained,
+                session.start_sol_erda_piece, session.end_sol_erda_piece, session.sol_erda_piece_gained, session.sol_erda_piece_price,
+                session.start_screenshot, session.end_screenshot, session.memo, session.id
+            ],
+        )?;
+        self.save_session_consumables(session.id, &session.consumables)
+    }
+
+    // 세션의 소모품 목록을 통째로 교체
+    fn save_session_consumables(&self, session_id: i64, consumables: &[SessionConsumable]) -> Result<()> {
+        self.conn.execute("DELETE FROM session_consumables WHERE session_id = ?1", params![session_id])?;
+        for consumable in consumables {
+            self.conn.execute(
+                "INSERT INTO session_consumables (session_id, consumable_type, quantity, meso_cost)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![session_id, consumable.consumable_type, consumable.quantity, consumable.meso_cost],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn load_session_consumables(&self, sessions: &mut [HuntingSession]) -> Result<()> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, consumable_type, quantity, meso_cost
+             FROM session_consumables WHERE session_id = ?1 ORDER BY id"
+        )?;
+        for session in sessions.iter_mut() {
+            session.consumables = stmt.query_map(params![session.id], |row| {
+                row_to_session_consumable(row)
+            })?.collect::<Result<Vec<_>>>()?;
+        }
+        Ok(())
+    }
+
+    // 사냥터는 세션 수정과 별도로 지정
+    pub fn set_hunting_session_map(&self, id: i64, map_id: Option<i64>) -> Result<()> {
+        let before = self.get_hunting_session(id)?;
+        self.conn.execute(
+            "UPDATE hunting_sessions SET map_id = ?1, updated_at = datetime('now') WHERE id = ?2",
+            params![map_id, id],
+        )?;
+        if let Some(before) = before {
+            self.record_session_revision(&before, RevisionSource::Edit, None)?;
+        }
+        Ok(())
+    }
+
+    // 현재 값과 비교해서 바뀐 필드가 있으면 수정 이력 추가
+    fn record_session_revision(&self, before: &HuntingSession, source: RevisionSource, reverted_revision_id: Option<i64>) -> Result<()> {
+        let Some(after) = self.get_hunting_session(before.id)? else {
+            return Ok(());
+        };
+        let changes = session_revision::diff_sessions(before, &after);
+        if changes.is_empty() {
+            return Ok(());
+        }
+        self.conn.execute(
+            "INSERT INTO hunting_session_revisions (session_id, source, changes, reverted_revision_id)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                before.id,
+                source.as_str(),
+                serde_json::to_string(&changes).unwrap_or_default(),
+                reverted_revision_id
+            ],
+        )?;
+        Ok(())
+    }
+
+    // 최근 수정 순
+    pub fn get_hunting_session_revisions(&self, session_id: i64) -> Result<Vec<HuntingSessionRevision>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, session_id, source, changes, reverted_revision_id, created_at
+             FROM hunting_session_revisions WHERE session_id = ?1 ORDER BY id DESC"
+        )?;
+        let revisions = stmt.query_map(params![session_id], |row| {
+            row_to_hunting_session_revision(row)
+        })?.collect::<Result<Vec<_>>>()?;
+        Ok(revisions)
+    }
+
+    // 수정 하나를 되돌림 (그 수정에서 바뀐 필드만 변경 전 값으로, 되돌린 것도 이력에 남음)
+    pub fn revert_hunting_session_revision(&self, revision_id: i64) -> Result<HuntingSession> {
+        let revision = self.conn.query_row(
+            "SELECT id, session_id, source, changes, reverted_revision_id, created_at
+             FROM hunting_session_revisions WHERE id = ?1",
+            params![revision_id],
+            row_to_hunting_session_revision,
+        ).optional()?
+            .ok_or_else(|| message_error("수정 이력을 찾을 수 없습니다".to_string()))?;
+        let before = self.get_hunting_session(revision.session_id)?
+            .ok_or_else(|| message_error("삭제된 사냥 기록입니다".to_string()))?;
+
+        let reverted = session_revision::revert_changes(&before, &revision.changes).map_err(message_error)?;
+        self.write_hunting_session(&reverted)?;
+        self.conn.execute(
+            "UPDATE hunting_sessions SET map_id = ?1 WHERE id = ?2",
+            params![reverted.map_id, reverted.id],
+        )?;
+        self.record_session_revision(&before, RevisionSource::Revert, Some(revision_id))?;
+
+        self.get_hunting_session(revision.session_id)?
+            .ok_or_else(|| message_error("삭제된 사냥 기록입니다".to_string()))
+    }
+
+    pub fn delete_hunting_session(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM session_consumables WHERE session_id = ?1", params![id])?;
+        self.conn.execute("DELETE FROM hunting_sessions WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub fn get_daily_totals(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<DailyTotal>> {
+        let start_date = format!("{:04}-{:02}-01", year, month);
+        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
+
+        let mut stmt = self.conn.prepare(
+            "SELECT date,
+                    SUM(exp_gained) as total_exp,
+                    SUM(meso_gained) as total_meso,
+                    SUM(sojaebi) as total_sojaebi,
+                    COUNT(*) as session_count
+             FROM hunting_sessions
+             WHERE character_id = ?1 AND date >= ?2 AND date <= ?3
+             GROUP BY date
+             ORDER BY date"
+        )?;
+
+        let totals = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            Ok(DailyTotal {
+                date: row.get(0)?,
+                total_exp_gained: row.get(1)?,
+                total_meso_gained: row.get(2)?,
+                total_sojaebi: row.get(3)?,
+                session_count: row.get(4)?,
+                exp_multiplier: 1.0,
+                events: Vec::new(),
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(totals)
+    }
+
+    // Exp History
+    pub fn save_exp_history(&self, character_id: i64, level: i32, exp_rate: f64) -> Result<()> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        let total_exp = (level as f64) * 100.0 + exp_rate;
+
+        // UPSERT: 오늘 기록이 있으면 업데이트, 없으면 삽입
+        self.conn.execute(
+            "INSERT INTO exp_history (character_id, date, level, exp_rate, total_exp)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(character_id, date) DO UPDATE SET
+                level = excluded.level,
+                exp_rate = excluded.exp_rate,
+                total_exp = excluded.total_exp",
+            params![character_id, today, level, exp_rate, total_exp],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_exp_history(&self, character_id: i64, days: i32) -> Result<Vec<ExpHistory>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, level, exp_rate, total_exp, created_at
+             FROM exp_history
+             WHERE character_id = ?1
+             ORDER BY date DESC
+             LIMIT ?2"
+        )?;
+
+        let history = stmt.query_map(params![character_id, days], |row| {
+            Ok(ExpHistory {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                date: row.get(2)?,
+                level: row.get(3)?,
+                exp_rate: row.get(4)?,
+                total_exp: row.get(5)?,
+                created_at: row.get(6)?,
+                exp_multiplier: 1.0,
+                events: Vec::new(),
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(history)
+    }
+
+    // Data Export/Import/Reset
+    pub fn export_data(&self, api_key_export: ApiKeyExport, passphrase: Option<&str>) -> Result<String> {
+        // API Key는 선택에 따라 제외 / 암호화 / 평문
+        let mut api_keys = Vec::new();
+        if api_key_export != ApiKeyExport::Omit {
+            for (id, name, stored) in self.get_stored_api_keys()? {
+                let api_key = match (api_key_export, passphrase) {
+                    (ApiKeyExport::Plain, _) => self.decrypt_api_key(&stored)?,
+                    (_, Some(passphrase)) => {
+                        secret::encrypt(&self.decrypt_api_key(&stored)?, Some(passphrase)).map_err(message_error)?
+                    }
+                    (_, None) if secret::protection(&stored) == KeyProtection::Plain => {
+                        secret::encrypt(&stored, None).map_err(message_error)?
+                    }
+                    (_, None) => stored,
+                };
+                api_keys.push(serde_json::json!({
+                    "name": name,
+                    "api_key": api_key,
+                    "characters": self.get_api_key_characters(id)?,
+                }));
+            }
+        }
+        // 이전 버전 호환용 (기본 키만)
+        let settings = api_keys.first().map(|k| serde_json::json!({ "api_key": k["api_key"] }));
+        let app_settings = self.get_app_settings()?;
+
+        // 모든 캐릭터
+        let mut char_stmt = self.conn.prepare(
+            "SELECT id, character_name, character_image, ocid, world_name, character_class,
+                    character_level, character_exp_rate, is_active, created_at, updated_at
+             FROM characters ORDER BY id"
+        )?;
+        let characters = char_stmt.query_map([], |row| {
+            Ok(Character {
+                id: row.get(0)?,
+                character_name: row.get(1)?,
+                character_image: row.get(2)?,
+                ocid: row.get(3)?,
+                world_name: row.get(4)?,
+                character_class: row.get(5)?,
+                character_level: row.get(6)?,
+                character_exp_rate: row.get(7)?,
+                is_active: row.get(8)?,
+                created_at: row.get(9)?,
+                updated_at: row.get(10)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 사냥 세션
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, session_order, start_level, end_level,
+                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
+                    meso_gained, duration_minutes, sojaebi,
+                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
+                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
+                    start_screenshot, end_screenshot, memo, created_at, updated_at, map_id
+             FROM hunting_sessions ORDER BY date, session_order"
+        )?;
+        let mut sessions = stmt.query_map([], |row| {
+            row_to_hunting_session(row)
+        })?.collect::<Result<Vec<_>>>()?;
+        self.load_session_consumables(&mut sessions)?;
+
+        // 사냥 기록 수정 이력
+        let mut revision_stmt = self.conn.prepare(
+            "SELECT id, session_id, source, changes, reverted_revision_id, created_at
+             FROM hunting_session_revisions ORDER BY id"
+        )?;
+        let session_revisions = revision_stmt.query_map([], |row| {
+            row_to_hunting_session_revision(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 보스 설정
+        let mut boss_stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, party_size, enabled, created_at, updated_at
+             FROM boss_settings ORDER BY id"
+        )?;
+        let boss_settings = boss_stmt.query_map([], |row| {
+            Ok(BossSetting {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                boss_id: row.get(2)?,
+                difficulty: row.get(3)?,
+                party_size: row.get(4)?,
+                enabled: row.get(5)?,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 보스 클리어
+        let mut clear_stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date,
+                    crystal_price, party_size, memo, created_at
+             FROM boss_clears ORDER BY id"
+        )?;
+        let boss_clears = clear_stmt.query_map([], |row| {
+            row_to_boss_clear(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 득템 기록
+        let mut drop_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, item_name, price, screenshot,
+                    sale_status, sale_price, sale_fee, sold_date, item_id, created_at
+             FROM item_drops ORDER BY id"
+        )?;
+        let item_drops = drop_stmt.query_map([], |row| {
+            row_to_item_drop(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 메소 지출
+        let mut expense_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, category, amount, memo, created_at
+             FROM meso_expenses ORDER BY id"
+        )?;
+        let meso_expenses = expense_stmt.query_map([], |row| {
+            row_to_meso_expense(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 아이템 시세 (카탈로그 id 대신 이름으로 복원)
+        let mut price_stmt = self.conn.prepare(
+            "SELECT p.id, p.item_id, c.canonical_name, p.date, p.price, p.source, p.created_at
+             FROM item_price_history p
+             JOIN item_catalog c ON c.id = p.item_id
+             ORDER BY p.id"
+        )?;
+        let item_prices = price_stmt.query_map([], |row| {
+            row_to_item_price(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 모든 조각 시세
+        let mut piece_price_stmt = self.conn.prepare(
+            "SELECT id, date, price, created_at FROM sol_erda_piece_prices ORDER BY date"
+        )?;
+        let sol_erda_piece_prices = piece_price_stmt.query_map([], |row| {
+            row_to_sol_erda_piece_price(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 사냥터 목록 (세션의 map_id 복원용)
+        let hunting_maps = self.get_hunting_maps()?;
+
+        // 경험치 히스토리
+        let exp_history = self.get_exp_history_in_range(None, None, None)?;
+
+        // HEXA 코어 레벨 기록
+        let mut hexa_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, hexa_core_name, hexa_core_type, hexa_core_level, created_at
+             FROM hexa_skill_history ORDER BY id"
+        )?;
+        let hexa_skill_history = hexa_stmt.query_map([], |row| {
+            row_to_hexa_skill_history(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 심볼 성장 기록
+        let mut symbol_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, symbol_name, symbol_level,
+                    symbol_growth_count, symbol_require_growth_count, created_at
+             FROM symbol_progress ORDER BY id"
+        )?;
+        let symbol_progress = symbol_stmt.query_map([], |row| {
+            row_to_symbol_progress(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 심볼 일일 퀘스트 완료 기록
+        let mut quest_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, symbol_name, completed, created_at
+             FROM symbol_daily_quests ORDER BY id"
+        )?;
+        let symbol_daily_quests = quest_stmt.query_map([], |row| {
+            Ok(SymbolDailyQuest {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                date: row.get(2)?,
+                symbol_name: row.get(3)?,
+                completed: row.get::<_, i32>(4)? == 1,
+                created_at: row.get(5)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 유니온 기록
+        let mut union_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, union_level, union_grade,
+                    union_artifact_level, union_artifact_exp, union_artifact_point, created_at
+             FROM union_history ORDER BY id"
+        )?;
+        let union_history = union_stmt.query_map([], |row| {
+            Ok(UnionHistory {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                date: row.get(2)?,
+                union_level: row.get(3)?,
+                union_grade: row.get(4)?,
+                union_artifact_level: row.get(5)?,
+                union_artifact_exp: row.get(6)?,
+                union_artifact_point: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 랭킹 스냅샷
+        let mut ranking_stmt = self.conn.prepare(
+            "SELECT id, character_id, date, world_rank, class_rank, union_rank, dojang_rank, dojang_floor, created_at
+             FROM ranking_history ORDER BY id"
+        )?;
+        let ranking_history = ranking_stmt.query_map([], |row| {
+            Ok(RankingSnapshot {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                date: row.get(2)?,
+                world_rank: row.get(3)?,
+                class_rank: row.get(4)?,
+                union_rank: row.get(5)?,
+                dojang_rank: row.get(6)?,
+                dojang_floor: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        // 스타포스/큐브/잠재능력 재설정 기록
+        let starforce_history = self.get_starforce_records()?;
+        let cube_history = self.get_potential_records_from("cube_history")?;
+        let potential_history = self.get_potential_records_from("potential_history")?;
+
+        // 모든 이벤트 기간
+        let mut event_stmt = self.conn.prepare(
+            "SELECT id, character_id, name, event_type, start_date, end_date, exp_multiplier, memo, created_at
+             FROM game_events ORDER BY id"
+        )?;
+        let game_events = event_stmt.query_map([], |row| {
+            row_to_game_event(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        let export = serde_json::json!({
+            "version": 2,
+            "exported_at": chrono::Utc::now().to_rfc3339(),
+            "settings": settings,
+            "api_keys": api_keys,
+            "app_settings": app_settings,
+            "characters": characters,
+            "hunting_sessions": sessions,
+            "hunting_session_revisions": session_revisions,
+            "hunting_maps": hunting_maps,
+            "boss_settings": boss_settings,
+            "boss_clears": boss_clears,
+            "item_drops": item_drops,
+            "meso_expenses": meso_expenses,
+            "item_prices": item_prices,
+            "sol_erda_piece_prices": sol_erda_piece_prices,
+            "game_events": game_events,
+            "exp_history": exp_history,
+            "hexa_skill_history": hexa_skill_history,
+            "symbol_progress": symbol_progress,
+            "symbol_daily_quests": symbol_daily_quests,
+            "union_history": union_history,
+            "ranking_history": ranking_history,
+            "starforce_history": starforce_history,
+            "cube_history": cube_history,
+            "potential_history": potential_history,
+        });
+
+        Ok(serde_json::to_string_pretty(&export).unwrap_or_default())
+    }
+
+    pub fn import_data(&self, json_data: &str) -> Result<()> {
+        let data: serde_json::Value = serde_json::from_str(json_data)
+            .map_err(|_| rusqlite::Error::InvalidQuery)?;
+
+        let version = data.get("version").and_then(|v| v.as_i64()).unwrap_or(1);
+
+        // 구조체 그대로 저장하는 기록은 삭제 전에 먼저 검사
+        let events = match data.get("game_events").and_then(|v| v.as_array()) {
+            Some(events) => events
+                .iter()
+                .map(|event| serde_json::from_value::<GameEvent>(event.clone()))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| message_error(format!("이벤트 기록을 읽을 수 없습니다: {}", e)))?,
+            None => Vec::new(),
+        };
+        let starforce_history: Vec<StarforceRecord> = match data.get("starforce_history") {
+            Some(history) => serde_json::from_value(history.clone())
+                .map_err(|e| message_error(format!("스타포스 기록을 읽을 수 없습니다: {}", e)))?,
+            None => Vec::new(),
+        };
+        let cube_history: Vec<PotentialRecord> = match data.get("cube_history") {
+            Some(history) => serde_json::from_value(history.clone())
+                .map_err(|e| message_error(format!("큐브 기록을 읽을 수 없습니다: {}", e)))?,
+            None => Vec::new(),
+        };
+        let potential_history: Vec<PotentialRecord> = match data.get("potential_history") {
+            Some(history) => serde_json::from_value(history.clone())
+                .map_err(|e| message_error(format!("잠재능력 재설정 기록을 읽을 수 없습니다: {}", e)))?,
+            None => Vec::new(),
+        };
+        let empty = Vec::new();
+        let sessions = data.get("hunting_sessions").and_then(|v| v.as_array()).unwrap_or(&empty);
+        // 세션별 소모품 (이전 버전 백업처럼 없으면 None, 아래에서 items 문자열로 변환)
+        let session_consumables = sessions
+            .iter()
+            .map(|session| match session.get("consumables").filter(|v| !v.is_null()) {
+                Some(value) => serde_json::from_value::<Vec<SessionConsumable>>(value.clone()).map(Some),
+                None => Ok(None),
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| message_error(format!("사냥 기록의 소모품을 읽을 수 없습니다: {}", e)))?;
+        let session_revisions = match data.get("hunting_session_revisions").and_then(|v| v.as_array()) {
+            Some(revisions) => revisions
+                .iter()
+                .map(|revision| serde_json::from_value::<HuntingSessionRevision>(revision.clone()))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| message_error(format!("사냥 기록 수정 이력을 읽을 수 없습니다: {}", e)))?,
+            None => Vec::new(),
+        };
+
+        // 모든 데이터 삭제 (전체 복원)
+        self.conn.execute("DELETE FROM game_events", [])?;
+        self.conn.execute("DELETE FROM sol_erda_piece_prices", [])?;
+        self.conn.execute("DELETE FROM item_price_history", [])?;
+        self.conn.execute("DELETE FROM meso_expenses", [])?;
+        self.conn.execute("DELETE FROM potential_history", [])?;
+        self.conn.execute("DELETE FROM cube_history", [])?;
+        self.conn.execute("DELETE FROM starforce_history", [])?;
+        self.conn.execute("DELETE FROM ranking_history", [])?;
+        self.conn.execute("DELETE FROM union_history", [])?;
+        self.conn.execute("DELETE FROM symbol_daily_quests", [])?;
+        self.conn.execute("DELETE FROM symbol_progress", [])?;
+        self.conn.execute("DELETE FROM hexa_skill_history", [])?;
+        self.conn.execute("DELETE FROM exp_history", [])?;
+        self.conn.execute("DELETE FROM item_drops", [])?;
+        self.conn.execute("DELETE FROM boss_clears", [])?;
+        self.conn.execute("DELETE FROM boss_settings", [])?;
+        self.conn.execute("DELETE FROM session_consumables", [])?;
+        self.conn.execute("DELETE FROM hunting_session_revisions", [])?;
+        self.conn.execute("DELETE FROM hunting_sessions", [])?;
+        self.conn.execute("DELETE FROM characters", [])?;
+        // 가져오기 이전 기록은 실행 취소/복원 대상에서 제외
+        self.clear_journal()?;
+
+        // API Key 복원 (평문은 암호화해서 저장, 암호화된 값은 그대로, 없으면 현재 키 유지)
+        let api_keys: Vec<(String, String, Vec<ApiKeyCharacter>)> = match data.get("api_keys").and_then(|v| v.as_array()) {
+            Some(keys) => keys.iter().filter_map(|k| {
+                let name = k.get("name")?.as_str()?.to_string();
+                let api_key = k.get("api_key")?.as_str()?.to_string();
+                let characters = k.get("characters")
+                    .and_then(|c| serde_json::from_value(c.clone()).ok())
+                    .unwrap_or_default();
+                Some((name, api_key, characters))
+            }).collect(),
+            None => data.get("settings").and_then(|s| s.get("api_key")).and_then(|v| v.as_str())
+                .map(|api_key| vec![(DEFAULT_API_KEY_NAME.to_string(), api_key.to_string(), Vec::new())])
+                .unwrap_or_default(),
+        };
+        if !api_keys.is_empty() {
+            self.conn.execute("DELETE FROM api_key_characters", [])?;
+            self.conn.execute("DELETE FROM settings", [])?;
+            for (name, api_key, characters) in &api_keys {
+                let id = match secret::protection(api_key) {
+                    KeyProtection::Plain => self.save_stored_api_key(
+                        name,
+                        &secret::encrypt(api_key, self.api_key_passphrase.as_deref()).map_err(message_error)?,
+                    )?,
+                    _ => self.save_stored_api_key(name, api_key)?,
+                };
+                self.set_api_key_characters(id, characters)?;
+            }
+        }
+
+        // 앱 설정 복원 (version 2)
+        if let Some(app_settings) = data.get("app_settings") {
+            if !app_settings.is_null() {
+                let price = app_settings.get("sol_erda_piece_price").and_then(|v| v.as_i64()).unwrap_or(6500000);
+                let folder = app_settings.get("screenshot_folder_path").and_then(|v| v.as_str());
+                self.save_app_settings(price)?;
+                self.save_screenshot_folder_path(folder)?;
+                if let Some(rate) = app_settings.get("auction_fee_rate").and_then(|v| v.as_f64()) {
+                    self.save_auction_fee_rate(rate)?;
+                }
+                if let Some(count) = app_settings.get("backup_keep_count").and_then(|v| v.as_i64()) {
+                    self.save_backup_keep_count(count as i32)?;
+                }
+                if let Some(days) = app_settings.get("trash_retention_days").and_then(|v| v.as_i64()) {
+                    self.save_trash_retention_days(days as i32)?;
+                }
+            }
+        }
+
+        // 캐릭터 복원 (캐릭터 id가 새로 매겨지므로 백업의 id와 새 id 대응)
+        let mut character_ids = std::collections::HashMap::new();
+        let mut fallback_character_id = None;
+        if version >= 2 {
+            // v2: 모든 캐릭터 배열
+            if let Some(characters) = data.get("characters").and_then(|v| v.as_array()) {
+                for char_data in characters {
+                    let is_active = char_data.get("is_active").and_then(|v| v.as_bool())
+                        .or_else(|| char_data.get("is_active").and_then(|v| v.as_i64()).map(|n| n == 1))
+                        .unwrap_or(false);
+                    self.conn.execute(
+                        "INSERT INTO characters (character_name, character_image, ocid, world_name,
+                                                character_class, character_level, character_exp_rate, is_active)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                        params![
+                            char_data.get("character_name").and_then(|v| v.as_str()).unwrap_or_default(),
+                            char_data.get("character_image").and_then(|v| v.as_str()).unwrap_or_default(),
+                            char_data.get("ocid").and_then(|v| v.as_str()).unwrap_or_default(),
+                            char_data.get("world_name").and_then(|v| v.as_str()).unwrap_or_default(),
+                            char_data.get("character_class").and_then(|v| v.as_str()).unwrap_or_default(),
+                            char_data.get("character_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                            char_data.get("character_exp_rate").and_then(|v| v.as_str()),
+                            if is_active { 1 } else { 0 },
+                        ],
+                    )?;
+                    if let Some(old_id) = char_data.get("id").and_then(|v| v.as_i64()) {
+                        character_ids.insert(old_id, self.conn.last_insert_rowid());
+                    }
+                }
+            }
+        } else {
+            // v1: 단일 캐릭터 (하위 호환)
+            if let Some(char_data) = data.get("character") {
+                if !char_data.is_null() {
+                    let character = Character {
+                        id: 0,
+                        character_name: char_data.get("character_name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                        character_image: char_data.get("character_image").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                        ocid: char_data.get("ocid").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                        world_name: char_data.get("world_name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                        character_class: char_data.get("character_class").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                        character_level: char_data.get("character_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                        character_exp_rate: char_data.get("character_exp_rate").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                        is_active: true,
+                        created_at: String::new(),
+                        updated_at: String::new(),
+                    };
+                    // v1 백업의 기록은 모두 이 캐릭터 것
+                    fallback_character_id = Some(self.register_character(&character)?);
+                }
+            }
+        }
+
+        // 백업의 character_id를 새 id로 변환 (대응하는 캐릭터가 없는 기록은 건너뜀)
+        let remap_character_id = |old_id: Option<i64>| -> Option<i64> {
+            old_id
+                .and_then(|old_id| character_ids.get(&old_id).copied())
+                .or(fallback_character_id)
+        };
+        let character_id = |row: &serde_json::Value| remap_character_id(row.get("character_id").and_then(|v| v.as_i64()));
+
+        // 백업의 사냥터 id를 현재 사냥터 id로 변환
+        let mut map_ids = std::collections::HashMap::new();
+        if let Some(maps) = data.get("hunting_maps").and_then(|v| v.as_array()) {
+            for map in maps {
+                if let Some(old_id) = map.get("id").and_then(|v| v.as_i64()) {
+                    let new_id = self.save_hunting_map(
+                        map.get("region").and_then(|v| v.as_str()).unwrap_or_default(),
+                        map.get("map_name").and_then(|v| v.as_str()).unwrap_or_default(),
+                    )?;
+                    map_ids.insert(old_id, new_id);
+                }
+            }
+        }
+
+        // 사냥 세션 복원 (수정 이력 복원용으로 백업의 세션 id와 새 id 대응)
+        let mut session_ids = std::collections::HashMap::new();
+        for (session_data, consumables) in sessions.iter().zip(session_consumables) {
+            let mut memo = session_data.get("memo").and_then(|v| v.as_str()).map(|s| s.to_string());
+            let consumables = match consumables {
+                Some(consumables) => consumables,
+                // 이전 버전 백업: items JSON 문자열
+                None => {
+                    let items = session_data.get("items").and_then(|v| v.as_str()).unwrap_or("[]");
+                    let (consumables, unknown) = consumable::parse_legacy_items(items);
+                    append_legacy_items_memo(&mut memo, &unknown);
+                    consumables
+                }
+            };
+            let Some(session_character_id) = character_id(session_data) else {
+                continue;
+            };
+            let session = HuntingSession {
+                id: 0,
+                character_id: session_character_id,
+                date: session_data.get("date").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                session_order: session_data.get("session_order").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                start_level: session_data.get("start_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                end_level: session_data.get("end_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                start_exp_percent: session_data.get("start_exp_percent").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                end_exp_percent: session_data.get("end_exp_percent").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                exp_gained: session_data.get("exp_gained").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                start_meso: session_data.get("start_meso").and_then(|v| v.as_i64()).unwrap_or(0),
+                end_meso: session_data.get("end_meso").and_then(|v| v.as_i64()).unwrap_or(0),
+                meso_gained: session_data.get("meso_gained").and_then(|v| v.as_i64()).unwrap_or(0),
+                duration_minutes: session_data.get("duration_minutes").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                sojaebi: session_data.get("sojaebi").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                start_sol_erda: session_data.get("start_sol_erda").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                end_sol_erda: session_data.get("end_sol_erda").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                start_sol_erda_gauge: session_data.get("start_sol_erda_gauge").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                end_sol_erda_gauge: session_data.get("end_sol_erda_gauge").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                sol_erda_gained: session_data.get("sol_erda_gained").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                start_sol_erda_piece: session_data.get("start_sol_erda_piece").and_then(|v| v.as_i64()).unwrap_or(0),
+                end_sol_erda_piece: session_data.get("end_sol_erda_piece").and_then(|v| v.as_i64()).unwrap_or(0),
+                sol_erda_piece_gained: session_data.get("sol_erda_piece_gained").and_then(|v| v.as_i64()).unwrap_or(0),
+                sol_erda_piece_price: session_data.get("sol_erda_piece_price").and_then(|v| v.as_i64()).unwrap_or(6500000),
+                start_screenshot: session_data.get("start_screenshot").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                end_screenshot: session_data.get("end_screenshot").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                consumables,
+                memo,
+                created_at: String::new(),
+                updated_at: String::new(),
+                map_id: session_data.get("map_id").and_then(|v| v.as_i64())
+                    .and_then(|id| map_ids.get(&id).copied()),
+            };
+            let new_id = self.save_hunting_session(&session)?;
+            if let Some(old_id) = session_data.get("id").and_then(|v| v.as_i64()) {
+                session_ids.insert(old_id, new_id);
+            }
+        }
+
+        // 사냥 기록 수정 이력 복원
+        let mut revision_ids = std::collections::HashMap::new();
+        for revision in session_revisions {
+            let Some(session_id) = session_ids.get(&revision.session_id) else {
+                continue;
+            };
+            self.conn.execute(
+                "INSERT INTO hunting_session_revisions (session_id, source, changes, reverted_revision_id, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    session_id,
+                    revision.source.as_str(),
+                    serde_json::to_string(&revision.changes).unwrap_or_default(),
+                    revision.reverted_revision_id.and_then(|id| revision_ids.get(&id).copied()),
+                    revision.created_at
+                ],
+            )?;
+            revision_ids.insert(revision.id, self.conn.last_insert_rowid());
+        }
+
+        // 보스 설정 복원 (version 2)
+        if let Some(boss_settings) = data.get("boss_settings").and_then(|v| v.as_array()) {
+            for bs in boss_settings {
+                let Some(setting_character_id) = character_id(bs) else {
+                    continue;
+                };
+                let setting = BossSetting {
+                    id: 0,
+                    character_id: setting_character_id,
+                    boss_id: bs.get("boss_id").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                    difficulty: bs.get("difficulty").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                    party_size: bs.get("party_size").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                    enabled: bs.get("enabled").and_then(|v| v.as_bool())
+                        .or_else(|| bs.get("enabled").and_then(|v| v.as_i64()).map(|n| n == 1))
+                        .unwrap_or(true),
+                    created_at: String::new(),
+                    updated_at: String::new(),
+                };
+                self.save_boss_setting(&setting)?;
+            }
+        }
+
+        // 보스 클리어 복원 (version 2)
+        if let Some(boss_clears) = data.get("boss_clears").and_then(|v| v.as_array()) {
+            for bc in boss_clears {
+                let Some(clear_character_id) = character_id(bc) else {
+                    continue;
+                };
+                self.conn.execute(
+                    "INSERT INTO boss_clears (character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, memo)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        clear_character_id,
+                        bc.get("boss_id").and_then(|v| v.as_str()).unwrap_or_default(),
+                        bc.get("difficulty").and_then(|v| v.as_str()).unwrap_or_default(),
+                        bc.get("cleared_date").and_then(|v| v.as_str()).unwrap_or_default(),
+                        bc.get("week_start_date").and_then(|v| v.as_str()).unwrap_or_default(),
+                        bc.get("crystal_price").and_then(|v| v.as_i64()).unwrap_or(0),
+                        bc.get("party_size").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
+                        bc.get("memo").and_then(|v| v.as_str()),
+                    ],
+                )?;
+            }
+        }
+
+        // 득템 기록 복원 (version 2)
+        if let Some(item_drops) = data.get("item_drops").and_then(|v| v.as_array()) {
+            for item in item_drops {
+                let Some(drop_character_id) = character_id(item) else {
+                    continue;
+                };
+                // 이름이 비어 있는 기록은 카탈로그에 연결할 수 없어 건너뜀
+                let item_name = item.get("item_name").and_then(|v| v.as_str()).unwrap_or_default();
+                if item_catalog::normalize_item_name(item_name).is_empty() {
+                    continue;
+                }
+                let id = self.save_item_drop(
+                    drop_character_id,
+                    item.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    item_name,
+                    item.get("price").and_then(|v| v.as_i64()).unwrap_or(0),
+                    item.get("screenshot").and_then(|v| v.as_str()),
+                )?;
+                if let Some(status) = item.get("sale_status").and_then(|v| v.as_str()) {
+                    self.update_item_drop_sale(
+                        id,
+                        status,
+                        item.get("sale_price").and_then(|v| v.as_i64()),
+                        item.get("sale_fee").and_then(|v| v.as_i64()),
+                        item.get("sold_date").and_then(|v| v.as_str()),
+                    )?;
+                }
+            }
+        }
+
+        // 조각 시세 복원
+        if let Some(prices) = data.get("sol_erda_piece_prices").and_then(|v| v.as_array()) {
+            for price in prices {
+                self.save_sol_erda_piece_price(
+                    price.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    price.get("price").and_then(|v| v.as_i64()).unwrap_or(6500000),
+                )?;
+            }
+        }
+
+        // 아이템 시세 복원
+        if let Some(prices) = data.get("item_prices").and_then(|v| v.as_array()) {
+            for price in prices {
+                let item_name = price.get("item_name").and_then(|v| v.as_str()).unwrap_or_default();
+                if item_catalog::normalize_item_name(item_name).is_empty() {
+                    continue;
+                }
+                let (item_id, _) = self.resolve_catalog_item(item_name)?;
+                self.save_item_price(
+                    item_id,
+                    price.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    price.get("price").and_then(|v| v.as_i64()).unwrap_or(0),
+                    price.get("source").and_then(|v| v.as_str()).unwrap_or("manual"),
+                )?;
+            }
+        }
+
+        // 메소 지출 복원
+        if let Some(expenses) = data.get("meso_expenses").and_then(|v| v.as_array()) {
+            for expense in expenses {
+                let Some(expense_character_id) = character_id(expense) else {
+                    continue;
+                };
+                self.save_meso_expense(
+                    expense_character_id,
+                    expense.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    expense.get("category").and_then(|v| v.as_str()).unwrap_or("etc"),
+                    expense.get("amount").and_then(|v| v.as_i64()).unwrap_or(0),
+                    expense.get("memo").and_then(|v| v.as_str()),
+                )?;
+            }
+        }
+
+        // 이벤트 기간 복원
+        for mut event in events {
+            // 캐릭터 지정 이벤트는 새 id로, 캐릭터를 찾을 수 없으면 건너뜀
+            if event.character_id.is_some() {
+                let Some(event_character_id) = remap_character_id(event.character_id) else {
+                    continue;
+                };
+                event.character_id = Some(event_character_id);
+            }
+            self.save_game_event(&event)?;
+        }
+
+        // 경험치 히스토리 복원
+        if let Some(history) = data.get("exp_history").and_then(|v| v.as_array()) {
+            for exp in history {
+                let Some(exp_character_id) = character_id(exp) else {
+                    continue;
+                };
+                self.conn.execute(
+                    "INSERT OR REPLACE INTO exp_history (character_id, date, level, exp_rate, total_exp)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        exp_character_id,
+                        exp.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                        exp.get("level").and_then(|v| v.as_i64()).unwrap_or(0),
+                        exp.get("exp_rate").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                        exp.get("total_exp").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    ],
+                )?;
+            }
+        }
+
+        // HEXA 코어 레벨 기록 복원
+        if let Some(history) = data.get("hexa_skill_history").and_then(|v| v.as_array()) {
+            for core in history {
+                let Some(core_character_id) = character_id(core) else {
+                    continue;
+                };
+                self.save_hexa_skill_level(
+                    core_character_id,
+                    core.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    core.get("hexa_core_name").and_then(|v| v.as_str()).unwrap_or_default(),
+                    core.get("hexa_core_type").and_then(|v| v.as_str()).unwrap_or_default(),
+                    core.get("hexa_core_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                )?;
+            }
+        }
+
+        // 심볼 성장 기록 복원
+        if let Some(history) = data.get("symbol_progress").and_then(|v| v.as_array()) {
+            for symbol in history {
+                let Some(symbol_character_id) = character_id(symbol) else {
+                    continue;
+                };
+                self.save_symbol_progress(
+                    symbol_character_id,
+                    symbol.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    symbol.get("symbol_name").and_then(|v| v.as_str()).unwrap_or_default(),
+                    symbol.get("symbol_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+                    symbol.get("symbol_growth_count").and_then(|v| v.as_i64()).unwrap_or(0),
+                    symbol.get("symbol_require_growth_count").and_then(|v| v.as_i64()).unwrap_or(0),
+                )?;
+            }
+        }
+
+        // 심볼 일일 퀘스트 복원
+        if let Some(quests) = data.get("symbol_daily_quests").and_then(|v| v.as_array()) {
+            for quest in quests {
+                let Some(quest_character_id) = character_id(quest) else {
+                    continue;
+                };
+                self.set_symbol_daily_quest(
+                    quest_character_id,
+                    quest.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                    quest.get("symbol_name").and_then(|v| v.as_str()).unwrap_or_default(),
+                    quest.get("completed").and_then(|v| v.as_bool()).unwrap_or(false),
+                )?;
+            }
+        }
+
+        // 유니온 기록 복원 (save_union_history는 오늘 날짜로 저장하므로 직접 INSERT)
+        if let Some(history) = data.get("union_history").and_then(|v| v.as_array()) {
+            for union in history {
+                let Some(union_character_id) = character_id(union) else {
+                    continue;
+                };
+                self.conn.execute(
+                    "INSERT OR REPLACE INTO union_history (character_id, date, union_level, union_grade,
+                                                          union_artifact_level, union_artifact_exp, union_artifact_point)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        union_character_id,
+                        union.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
+                        union.get("union_level").and_then(|v| v.as_i64()).unwrap_or(0),
+                        union.get("union_grade").and_then(|v| v.as_str()).unwrap_or_default(),
+                        union.get("union_artifact_level").and_then(|v| v.as_i64()).unwrap_or(0),
+                        union.get("union_artifact_exp").and_then(|v| v.as_i64()).unwrap_or(0),
+                        union.get("union_artifact_point").and_then(|v| v.as_i64()).unwrap_or(0),
+                    ],
+                )?;
+            }
+        }
+
+        // 랭킹 스냅샷 복원
+        if let Some(history) = data.get("ranking_history").and_then(|v| v.as_array()) {
+            for ranking in history {
+                let Some(ranking_character_id) = character_id(ranking) else {
+                    continue;
+                };
+                self.save_ranking_snapshot(&RankingSnapshot {
+                    id: 0,
+                    character_id: ranking_character_id,
+                    date: ranking.get("date").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                    world_rank: ranking.get("world_rank").and_then(|v| v.as_i64()),
+                    class_rank: ranking.get("class_rank").and_then(|v| v.as_i64()),
+                    union_rank: ranking.get("union_rank").and_then(|v| v.as_i64()),
+                    dojang_rank: ranking.get("dojang_rank").and_then(|v| v.as_i64()),
+                    dojang_floor: ranking.get("dojang_floor").and_then(|v| v.as_i64()).map(|v| v as i32),
+                    created_at: String::new(),
+                })?;
+            }
+        }
+
+        // 스타포스/큐브/잠재능력 재설정 기록 복원 (캐릭터 이름 기준이라 id 변환 없음)
+        self.save_starforce_records(&starforce_history)?;
+        self.save_cube_records(&cube_history)?;
+        self.save_potential_records(&potential_history)?;
+
+        Ok(())
+    }
+
+    pub fn reset_data(&self) -> Result<()> {
+        self.conn.execute("DELETE FROM game_events", [])?;
+        self.conn.execute("DELETE FROM sol_erda_piece_prices", [])?;
+        self.conn.execute("DELETE FROM item_price_history", [])?;
+        self.conn.execute("DELETE FROM meso_expenses", [])?;
+        self.conn.execute("DELETE FROM potential_history", [])?;
+        self.conn.execute("DELETE FROM cube_history", [])?;
+        self.conn.execute("DELETE FROM starforce_history", [])?;
+        self.conn.execute("DELETE FROM ranking_history", [])?;
+        self.conn.execute("DELETE FROM union_history", [])?;
+        self.conn.execute("DELETE FROM symbol_daily_quests", [])?;
+        self.conn.execute("DELETE FROM symbol_progress", [])?;
+        self.conn.execute("DELETE FROM hexa_skill_history", [])?;
+        self.conn.execute("DELETE FROM exp_history", [])?;
+        self.conn.execute("DELETE FROM item_drops", [])?;
+        self.conn.execute("DELETE FROM boss_clears", [])?;
+        self.conn.execute("DELETE FROM boss_settings", [])?;
+        self.conn.execute("DELETE FROM session_consumables", [])?;
+        self.conn.execute("DELETE FROM hunting_session_revisions", [])?;
+        self.conn.execute("DELETE FROM hunting_sessions", [])?;
+        self.conn.execute("DELETE FROM characters", [])?;
+        self.conn.execute("DELETE FROM api_key_characters", [])?;
+        self.conn.execute("DELETE FROM settings", [])?;
+        self.clear_journal()?;
+        Ok(())
+    }
+
+    // Boss Settings
+    pub fn get_boss_settings(&self, character_id: i64) -> Result<Vec<BossSetting>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, party_size, enabled, created_at, updated_at
+             FROM boss_settings
+             WHERE character_id = ?1
+             ORDER BY boss_id, difficulty"
+        )?;
+
+        let settings = stmt.query_map(params![character_id], |row| {
+            Ok(BossSetting {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                boss_id: row.get(2)?,
+                difficulty: row.get(3)?,
+                party_size: row.get(4)?,
+                enabled: row.get::<_, i32>(5)? == 1,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(settings)
+    }
+
+    pub fn save_boss_setting(&self, setting: &BossSetting) -> Result<i64> {
+        // UPSERT: 이미 있으면 업데이트, 없으면 삽입
+        self.conn.execute(
+            "INSERT INTO boss_settings (character_id, boss_id, difficulty, party_size, enabled)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(character_id, boss_id, difficulty) DO UPDATE SET
+                party_size = excluded.party_size,
+                enabled = excluded.enabled,
+                updated_at = datetime('now')",
+            params![
+                setting.character_id,
+                setting.boss_id,
+                setting.difficulty,
+                setting.party_size,
+                if setting.enabled { 1 } else { 0 }
+            ],
+        )?;
+
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn delete_boss_setting(&self, character_id: i64, boss_id: &str, difficulty: &str) -> Result<()> {
+        self.conn.execute(
+            "DELETE FROM boss_settings WHERE character_id = ?1 AND boss_id = ?2 AND difficulty = ?3",
+            params![character_id, boss_id, difficulty],
+        )?;
+        Ok(())
+    }
+
+    // 캐릭터별 활성화된 보스 설정 개수
+    pub fn get_enabled_boss_setting_counts(&self) -> Result<Vec<(i64, i32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT character_id, COUNT(*) FROM boss_settings WHERE enabled = 1 GROUP BY character_id"
+        )?;
+
+        let counts = stmt.query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(counts)
+    }
+
+    pub fn update_boss_setting_party_size(&self, character_id: i64, boss_id: &str, difficulty: &str, party_size: i32) -> Result<()> {
+        self.conn.execute(
+            "UPDATE boss_settings SET party_size = ?1, updated_at = datetime('now')
+             WHERE character_id = ?2 AND boss_id = ?3 AND difficulty = ?4",
+            params![party_size, character_id, boss_id, difficulty],
+        )?;
+        Ok(())
+    }
+
+    // Boss Clears
+    // 목요일 기준 주간 시작일 계산
+    pub fn get_week_start_date(date: &str) -> String {
+        let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_else(|_| {
+            chrono::Local::now().date_naive()
+        });
+
+        // 목요일 = 3 (월=0, 화=1, 수=2, 목=3, 금=4, 토=5, 일=6)
+        let weekday = parsed.weekday().num_days_from_monday();
+        let days_since_thursday = if weekday >= 3 {
+            weekday - 3
+        } else {
+            weekday + 4 // 월화수는 이전 주 목요일
+        };
+
+        let week_start = parsed - chrono::Duration::days(days_since_thursday as i64);
+        week_start.format("%Y-%m-%d").to_string()
+    }
+
+    // 월초 날짜 계산 (월간 보스용)
+    pub fn get_month_start_date(date: &str) -> String {
+        let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_else(|_| {
+            chrono::Local::now().date_naive()
+        });
+
+        // 해당 월의 1일로 설정
+        let month_start = NaiveDate::from_ymd_opt(parsed.year(), parsed.month(), 1)
+            .unwrap_or(parsed);
+        month_start.format("%Y-%m-%d").to_string()
+    }
+
+    pub fn save_boss_clear(&self, clear: &BossClear, is_monthly: bool) -> Result<i64> {
+        let period_start = if is_monthly {
+            Self::get_month_start_date(&clear.cleared_date)
+        } else {
+            Self::get_week_start_date(&clear.cleared_date)
+        };
+
+        self.conn.execute(
+            "INSERT INTO boss_clears (character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, memo)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(character_id, boss_id, week_start_date) DO UPDATE SET
+                difficulty = excluded.difficulty,
+                cleared_date = excluded.cleared_date,
+                crystal_price = excluded.crystal_price,
+                party_size = excluded.party_size,
+                memo = excluded.memo",
             params![
-                session.start_level, session.end_level, session.start_exp_percent, session.end_exp_percent,
-                session.exp_gained, session.start_meso, session.end_meso, session.meso_gained,
-                session.duration_minutes, session.sojaebi,
-                session.start_sol_erda, session.end_sol_erda, session.start_sol_erda_gauge, session.end_sol_erda_gauge, session.sol_erda_gained,
-                session.start_sol_erda_piece, session.end_sol_erda_piece, session.sol_erda_piece_gained, session.sol_erda_piece_price,
-                session.start_screenshot, session.end_screenshot, session.items, session.memo, session.id
+                clear.character_id,
+                clear.boss_id,
+                clear.difficulty,
+                clear.cleared_date,
+                period_start,
+                clear.crystal_price,
+                clear.party_size,
+                clear.memo
             ],
         )?;
+
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn delete_boss_clear(&self, character_id: i64, boss_id: &str, week_start_date: &str) -> Result<()> {
+        self.conn.execute(
+            "DELETE FROM boss_clears WHERE character_id = ?1 AND boss_id = ?2 AND week_start_date = ?3",
+            params![character_id, boss_id, week_start_date],
+        )?;
         Ok(())
     }
 
-    pub fn delete_hunting_session(&self, id: i64) -> Result<()> {
-        self.conn.execute("DELETE FROM hunting_sessions WHERE id = ?1", params![id])?;
+    pub fn get_boss_clears_by_week(&self, character_id: i64, week_start_date: &str) -> Result<Vec<BossClear>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, memo, created_at
+             FROM boss_clears
+             WHERE character_id = ?1 AND week_start_date = ?2
+             ORDER BY cleared_date"
+        )?;
+
+        let clears = stmt.query_map(params![character_id, week_start_date], |row| {
+            row_to_boss_clear(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(clears)
+    }
+
+    pub fn get_boss_clears_by_date(&self, character_id: i64, date: &str) -> Result<Vec<BossClear>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, memo, created_at
+             FROM boss_clears
+             WHERE character_id = ?1 AND cleared_date = ?2
+             ORDER BY boss_id"
+        )?;
+
+        let clears = stmt.query_map(params![character_id, date], |row| {
+            row_to_boss_clear(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(clears)
+    }
+
+    pub fn get_weekly_boss_summary(&self, character_id: i64, week_start_date: &str) -> Result<WeeklyBossSummary> {
+        let result = self.conn.query_row(
+            "SELECT week_start_date,
+                    COALESCE(SUM(crystal_price / party_size), 0) as total_income,
+                    COUNT(*) as boss_count
+             FROM boss_clears
+             WHERE character_id = ?1 AND week_start_date = ?2
+             GROUP BY week_start_date",
+            params![character_id, week_start_date],
+            |row| {
+                Ok(WeeklyBossSummary {
+                    week_start_date: row.get(0)?,
+                    total_crystal_income: row.get(1)?,
+                    boss_count: row.get(2)?,
+                })
+            }
+        );
+
+        match result {
+            Ok(summary) => Ok(summary),
+            Err(_) => Ok(WeeklyBossSummary {
+                week_start_date: week_start_date.to_string(),
+                total_crystal_income: 0,
+                boss_count: 0,
+            })
+        }
+    }
+
+    // 월별 보스 클리어 요약 (달력용)
+    pub fn get_monthly_boss_clears(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<BossClear>> {
+        let start_date = format!("{:04}-{:02}-01", year, month);
+        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, memo, created_at
+             FROM boss_clears
+             WHERE character_id = ?1 AND cleared_date >= ?2 AND cleared_date <= ?3
+             ORDER BY cleared_date, boss_id"
+        )?;
+
+        let clears = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            row_to_boss_clear(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(clears)
+    }
+
+    // App Settings (조각 가격 등)
+    pub fn get_app_settings(&self) -> Result<AppSettings> {
+        let result = self.conn.query_row(
+            "SELECT id, sol_erda_piece_price, screenshot_folder_path, auction_fee_rate, backup_keep_count, trash_retention_days, created_at, updated_at
+             FROM app_settings ORDER BY id DESC LIMIT 1",
+            [],
+            |row| {
+                Ok(AppSettings {
+                    id: row.get(0)?,
+                    sol_erda_piece_price: row.get(1)?,
+                    screenshot_folder_path: row.get(2)?,
+                    auction_fee_rate: row.get(3)?,
+                    backup_keep_count: row.get(4)?,
+                    trash_retention_days: row.get(5)?,
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                })
+            }
+        );
+
+        match result {
+            Ok(settings) => Ok(settings),
+            Err(_) => Ok(AppSettings {
+                id: 0,
+                sol_erda_piece_price: 6500000, // 기본값 650만 메소
+                screenshot_folder_path: None,
+                auction_fee_rate: 0.05,
+                backup_keep_count: 10,
+                trash_retention_days: journal::DEFAULT_TRASH_RETENTION_DAYS,
+                created_at: String::new(),
+                updated_at: String::new(),
+            })
+        }
+    }
+
+    pub fn save_app_settings(&self, sol_erda_piece_price: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE app_settings SET sol_erda_piece_price = ?1, updated_at = datetime('now') WHERE id = 1",
+            params![sol_erda_piece_price],
+        )?;
+        Ok(())
+    }
+
+    // Sol Erda Piece Prices
+    pub fn save_sol_erda_piece_price(&self, date: &str, price: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO sol_erda_piece_prices (date, price) VALUES (?1, ?2)
+             ON CONFLICT(date) DO UPDATE SET price = excluded.price",
+            params![date, price],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_sol_erda_piece_price(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM sol_erda_piece_prices WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub fn get_sol_erda_piece_price_history(&self) -> Result<Vec<SolErdaPiecePrice>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, date, price, created_at FROM sol_erda_piece_prices ORDER BY date"
+        )?;
+
+        let prices = stmt.query_map([], |row| {
+            row_to_sol_erda_piece_price(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(prices)
+    }
+
+    // 해당 날짜에 유효한 조각 가격 (기록이 없으면 설정값)
+    pub fn get_sol_erda_piece_price_on(&self, date: &str) -> Result<i64> {
+        let price = self.conn.query_row(
+            "SELECT price FROM sol_erda_piece_prices WHERE date <= ?1 ORDER BY date DESC LIMIT 1",
+            params![date],
+            |row| row.get(0),
+        ).optional()?;
+
+        match price {
+            Some(price) => Ok(price),
+            None => Ok(self.get_app_settings()?.sol_erda_piece_price),
+        }
+    }
+
+    pub fn save_auction_fee_rate(&self, rate: f64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE app_settings SET auction_fee_rate = ?1, updated_at = datetime('now') WHERE id = 1",
+            params![rate],
+        )?;
+        Ok(())
+    }
+
+    pub fn save_backup_keep_count(&self, count: i32) -> Result<()> {
+        self.conn.execute(
+            "UPDATE app_settings SET backup_keep_count = ?1, updated_at = datetime('now') WHERE id = 1",
+            params![count],
+        )?;
+        Ok(())
+    }
+
+    pub fn save_trash_retention_days(&self, days: i32) -> Result<()> {
+        self.conn.execute(
+            "UPDATE app_settings SET trash_retention_days = ?1, updated_at = datetime('now') WHERE id = 1",
+            params![days],
+        )?;
+        Ok(())
+    }
+
+    pub fn save_screenshot_folder_path(&self, path: Option<&str>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE app_settings SET screenshot_folder_path = ?1, updated_at = datetime('now') WHERE id = 1",
+            params![path],
+        )?;
+        Ok(())
+    }
+
+    // Item Drops
+    pub fn save_item_drop(&self, character_id: i64, date: &str, item_name: &str, price: i64, screenshot: Option<&str>) -> Result<i64> {
+        let (item_id, canonical_name) = self.resolve_catalog_item(item_name)?;
+        self.conn.execute(
+            "INSERT INTO item_drops (character_id, date, item_name, price, screenshot, item_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![character_id, date, canonical_name, price, screenshot, item_id],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn get_item_drops(&self, character_id: i64, date: &str) -> Result<Vec<ItemDrop>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, item_name, price, screenshot,
+                    sale_status, sale_price, sale_fee, sold_date, item_id, created_at
+             FROM item_drops
+             WHERE character_id = ?1 AND date = ?2
+             ORDER BY price DESC"
+        )?;
+
+        let drops = stmt.query_map(params![character_id, date], |row| {
+            row_to_item_drop(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(drops)
+    }
+
+    pub fn update_item_drop(&self, id: i64, item_name: &str, price: i64, screenshot: Option<&str>) -> Result<()> {
+        let (item_id, canonical_name) = self.resolve_catalog_item(item_name)?;
+        self.conn.execute(
+            "UPDATE item_drops SET item_name = ?1, price = ?2, screenshot = ?3, item_id = ?4 WHERE id = ?5",
+            params![canonical_name, price, screenshot, item_id, id],
+        )?;
+        Ok(())
+    }
+
+    // 판매 상태 변경 (sold가 아니면 판매 정보는 비움)
+    pub fn update_item_drop_sale(&self, id: i64, sale_status: &str, sale_price: Option<i64>, sale_fee: Option<i64>, sold_date: Option<&str>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE item_drops SET sale_status = ?1, sale_price = ?2, sale_fee = ?3, sold_date = ?4 WHERE id = ?5",
+            params![sale_status, sale_price, sale_fee, sold_date, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_item_drop(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM item_drops WHERE id = ?1", params![id])?;
         Ok(())
     }
 
-    pub fn get_daily_totals(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<DailyTotal>> {
+    // 월별 득템 실현/미실현 금액
+    // 실현: 해당 월에 판매 완료된 아이템의 수수료 차감 금액, 미실현: 해당 월 득템 중 미판매 아이템의 예상 가치
+    pub fn get_monthly_drop_summary(&self, character_id: i64, year: i32, month: i32) -> Result<ItemDropSummary> {
+        let start_date = format!("{:04}-{:02}-01", year, month);
+        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
+
+        self.conn.query_row(
+            "SELECT
+                COALESCE(SUM(CASE WHEN sale_status = 'sold' AND sold_date BETWEEN ?2 AND ?3 THEN sale_price ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN sale_status = 'sold' AND sold_date BETWEEN ?2 AND ?3 THEN COALESCE(sale_fee, 0) ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN sale_status = 'listed' AND date BETWEEN ?2 AND ?3 THEN price ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN sale_status = 'kept' AND date BETWEEN ?2 AND ?3 THEN price ELSE 0 END), 0)
+             FROM item_drops
+             WHERE character_id = ?1",
+            params![character_id, start_date, end_date],
+            |row| {
+                let gross_sales: i64 = row.get(0)?;
+                let auction_fee: i64 = row.get(1)?;
+                let listed_value: i64 = row.get(2)?;
+                let kept_value: i64 = row.get(3)?;
+                Ok(ItemDropSummary {
+                    gross_sales,
+                    auction_fee,
+                    realized_meso: gross_sales - auction_fee,
+                    listed_value,
+                    kept_value,
+                    unrealized_value: listed_value + kept_value,
+                })
+            },
+        )
+    }
+
+    pub fn get_monthly_item_drops(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<ItemDrop>> {
+        let start_date = format!("{:04}-{:02}-01", year, month);
+        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, item_name, price, screenshot,
+                    sale_status, sale_price, sale_fee, sold_date, item_id, created_at
+             FROM item_drops
+             WHERE character_id = ?1 AND date >= ?2 AND date <= ?3
+             ORDER BY date, price DESC"
+        )?;
+
+        let drops = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            row_to_item_drop(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(drops)
+    }
+
+    // 일별 합계와 조각 개수, 조각 가치 (세션마다 저장된 획득 시점 시세로 평가)
+    pub fn get_daily_totals_with_pieces(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<(DailyTotal, i64, i64)>> {
         let start_date = format!("{:04}-{:02}-01", year, month);
         let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
 
@@ -663,7 +3809,9 @@ impl Database {
                     SUM(exp_gained) as total_exp,
                     SUM(meso_gained) as total_meso,
                     SUM(sojaebi) as total_sojaebi,
-                    COUNT(*) as session_count
+                    COUNT(*) as session_count,
+                    SUM(sol_erda_piece_gained) as total_pieces,
+                    SUM(sol_erda_piece_gained * sol_erda_piece_price) as total_piece_value
              FROM hunting_sessions
              WHERE character_id = ?1 AND date >= ?2 AND date <= ?3
              GROUP BY date
@@ -671,729 +3819,1097 @@ impl Database {
         )?;
 
         let totals = stmt.query_map(params![character_id, start_date, end_date], |row| {
-            Ok(DailyTotal {
+            let daily = DailyTotal {
                 date: row.get(0)?,
                 total_exp_gained: row.get(1)?,
                 total_meso_gained: row.get(2)?,
                 total_sojaebi: row.get(3)?,
                 session_count: row.get(4)?,
-            })
+                exp_multiplier: 1.0,
+                events: Vec::new(),
+            };
+            let total_pieces: i64 = row.get(5)?;
+            let total_piece_value: i64 = row.get(6)?;
+            Ok((daily, total_pieces, total_piece_value))
         })?.collect::<Result<Vec<_>>>()?;
 
         Ok(totals)
     }
 
-    // Exp History
-    pub fn save_exp_history(&self, character_id: i64, level: i32, exp_rate: f64) -> Result<()> {
-        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
-        let total_exp = (level as f64) * 100.0 + exp_rate;
+    // 특정 년도에서 데이터가 있는 월 목록 반환
+    pub fn get_months_with_data(&self, character_id: i64, year: i32) -> Result<Vec<i32>> {
+        let start_date = format!("{:04}-01-01", year);
+        let end_date = format!("{:04}-12-31", year);
 
-        // UPSERT: 오늘 기록이 있으면 업데이트, 없으면 삽입
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT CAST(substr(date, 6, 2) AS INTEGER) as month FROM (
+                SELECT date FROM hunting_sessions WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
+                UNION
+                SELECT cleared_date as date FROM boss_clears WHERE character_id = ?1 AND cleared_date BETWEEN ?2 AND ?3
+                UNION
+                SELECT date FROM item_drops WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
+                UNION
+                SELECT date FROM meso_expenses WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
+            ) ORDER BY month"
+        )?;
+
+        let months = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            row.get::<_, i32>(0)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(months)
+    }
+    // HEXA Skill History
+    pub fn save_hexa_skill_level(&self, character_id: i64, date: &str, core_name: &str, core_type: &str, level: i32) -> Result<()> {
+        // UPSERT: 같은 날짜의 같은 코어는 마지막 조회 값으로 갱신
         self.conn.execute(
-            "INSERT INTO exp_history (character_id, date, level, exp_rate, total_exp)
+            "INSERT INTO hexa_skill_history (character_id, date, hexa_core_name, hexa_core_type, hexa_core_level)
              VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(character_id, date, hexa_core_name) DO UPDATE SET
+                hexa_core_type = excluded.hexa_core_type,
+                hexa_core_level = excluded.hexa_core_level",
+            params![character_id, date, core_name, core_type, level],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_hexa_skill_history(&self, character_id: i64, core_name: Option<&str>) -> Result<Vec<HexaSkillHistory>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, hexa_core_name, hexa_core_type, hexa_core_level, created_at
+             FROM hexa_skill_history
+             WHERE character_id = ?1 AND (?2 IS NULL OR hexa_core_name = ?2)
+             ORDER BY date, hexa_core_type, hexa_core_name"
+        )?;
+
+        let history = stmt.query_map(params![character_id, core_name], |row| {
+            row_to_hexa_skill_history(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(history)
+    }
+
+    // 코어별 가장 최근 기록
+    pub fn get_latest_hexa_skill_levels(&self, character_id: i64) -> Result<Vec<HexaSkillHistory>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT h.id, h.character_id, h.date, h.hexa_core_name, h.hexa_core_type, h.hexa_core_level, h.created_at
+             FROM hexa_skill_history h
+             WHERE h.character_id = ?1
+               AND h.date = (SELECT MAX(date) FROM hexa_skill_history
+                             WHERE character_id = h.character_id AND hexa_core_name = h.hexa_core_name)
+             ORDER BY h.hexa_core_type, h.hexa_core_name"
+        )?;
+
+        let levels = stmt.query_map(params![character_id], |row| {
+            row_to_hexa_skill_history(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(levels)
+    }
+
+    // 최근 N일 사냥 기록의 솔 에르다 획득량과 마지막 세션 기준 보유량
+    pub fn get_sol_erda_rate(&self, character_id: i64, days: i32) -> Result<SolErdaRate> {
+        let since = (chrono::Local::now().date_naive() - chrono::Duration::days(days as i64))
+            .format("%Y-%m-%d").to_string();
+
+        let (total_minutes, sol_erda_gained, sol_erda_piece_gained): (i64, f64, i64) = self.conn.query_row(
+            "SELECT COALESCE(SUM(duration_minutes), 0),
+                    COALESCE(SUM(sol_erda_gained), 0.0),
+                    COALESCE(SUM(sol_erda_piece_gained), 0)
+             FROM hunting_sessions
+             WHERE character_id = ?1 AND date >= ?2",
+            params![character_id, since],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        let current: Option<(i32, i32, i64)> = self.conn.query_row(
+            "SELECT end_sol_erda, end_sol_erda_gauge, end_sol_erda_piece
+             FROM hunting_sessions
+             WHERE character_id = ?1
+             ORDER BY date DESC, session_order DESC
+             LIMIT 1",
+            params![character_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        ).optional()?;
+
+        let (current_sol_erda, current_sol_erda_piece) = match current {
+            Some((count, gauge, pieces)) => (count as f64 + gauge as f64 / 1000.0, pieces),
+            None => (0.0, 0),
+        };
+
+        Ok(SolErdaRate {
+            total_minutes,
+            sol_erda_gained,
+            sol_erda_piece_gained,
+            current_sol_erda,
+            current_sol_erda_piece,
+        })
+    }
+
+    // Symbols
+    pub fn save_symbol_progress(&self, character_id: i64, date: &str, symbol_name: &str, level: i32, growth_count: i64, require_growth_count: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO symbol_progress (character_id, date, symbol_name, symbol_level, symbol_growth_count, symbol_require_growth_count)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(character_id, date, symbol_name) DO UPDATE SET
+                symbol_level = excluded.symbol_level,
+                symbol_growth_count = excluded.symbol_growth_count,
+                symbol_require_growth_count = excluded.symbol_require_growth_count",
+            params![character_id, date, symbol_name, level, growth_count, require_growth_count],
+        )?;
+        Ok(())
+    }
+
+    // 심볼별 가장 최근 기록
+    pub fn get_latest_symbol_progress(&self, character_id: i64) -> Result<Vec<SymbolProgress>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.id, s.character_id, s.date, s.symbol_name, s.symbol_level,
+                    s.symbol_growth_count, s.symbol_require_growth_count, s.created_at
+             FROM symbol_progress s
+             WHERE s.character_id = ?1
+               AND s.date = (SELECT MAX(date) FROM symbol_progress
+                             WHERE character_id = s.character_id AND symbol_name = s.symbol_name)
+             ORDER BY s.id"
+        )?;
+
+        let progress = stmt.query_map(params![character_id], |row| {
+            row_to_symbol_progress(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(progress)
+    }
+
+    pub fn get_symbol_progress_history(&self, character_id: i64, symbol_name: Option<&str>) -> Result<Vec<SymbolProgress>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, symbol_name, symbol_level,
+                    symbol_growth_count, symbol_require_growth_count, created_at
+             FROM symbol_progress
+             WHERE character_id = ?1 AND (?2 IS NULL OR symbol_name = ?2)
+             ORDER BY date, id"
+        )?;
+
+        let history = stmt.query_map(params![character_id, symbol_name], |row| {
+            row_to_symbol_progress(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(history)
+    }
+
+    pub fn set_symbol_daily_quest(&self, character_id: i64, date: &str, symbol_name: &str, completed: bool) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO symbol_daily_quests (character_id, date, symbol_name, completed)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(character_id, date, symbol_name) DO UPDATE SET
+                completed = excluded.completed",
+            params![character_id, date, symbol_name, if completed { 1 } else { 0 }],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_symbol_daily_quests(&self, character_id: i64, date: &str) -> Result<Vec<SymbolDailyQuest>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, symbol_name, completed, created_at
+             FROM symbol_daily_quests
+             WHERE character_id = ?1 AND date = ?2
+             ORDER BY id"
+        )?;
+
+        let quests = stmt.query_map(params![character_id, date], |row| {
+            Ok(SymbolDailyQuest {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                date: row.get(2)?,
+                symbol_name: row.get(3)?,
+                completed: row.get::<_, i32>(4)? == 1,
+                created_at: row.get(5)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(quests)
+    }
+
+    // 기간 내 첫 기록일과 일일 퀘스트 완료 일수 (기록이 하나도 없으면 None)
+    pub fn get_symbol_quest_stats(&self, character_id: i64, symbol_name: &str, since: &str) -> Result<Option<(String, i64)>> {
+        let (first_date, completed): (Option<String>, i64) = self.conn.query_row(
+            "SELECT MIN(date), COALESCE(SUM(completed), 0)
+             FROM symbol_daily_quests
+             WHERE character_id = ?1 AND symbol_name = ?2 AND date >= ?3",
+            params![character_id, symbol_name, since],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        Ok(first_date.map(|date| (date, completed)))
+    }
+
+    // Union
+    pub fn save_union_history(&self, union: &UnionHistory) -> Result<()> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+        self.conn.execute(
+            "INSERT INTO union_history (character_id, date, union_level, union_grade,
+                                        union_artifact_level, union_artifact_exp, union_artifact_point)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
              ON CONFLICT(character_id, date) DO UPDATE SET
-                level = excluded.level,
-                exp_rate = excluded.exp_rate,
-                total_exp = excluded.total_exp",
-            params![character_id, today, level, exp_rate, total_exp],
+                union_level = excluded.union_level,
+                union_grade = excluded.union_grade,
+                union_artifact_level = excluded.union_artifact_level,
+                union_artifact_exp = excluded.union_artifact_exp,
+                union_artifact_point = excluded.union_artifact_point",
+            params![
+                union.character_id, today, union.union_level, union.union_grade,
+                union.union_artifact_level, union.union_artifact_exp, union.union_artifact_point
+            ],
         )?;
         Ok(())
     }
 
-    pub fn get_exp_history(&self, character_id: i64, days: i32) -> Result<Vec<ExpHistory>> {
+    pub fn get_union_history(&self, character_id: i64) -> Result<Vec<UnionHistory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, date, level, exp_rate, total_exp, created_at
-             FROM exp_history
+            "SELECT id, character_id, date, union_level, union_grade,
+                    union_artifact_level, union_artifact_exp, union_artifact_point, created_at
+             FROM union_history
              WHERE character_id = ?1
-             ORDER BY date DESC
-             LIMIT ?2"
+             ORDER BY date"
         )?;
 
-        let history = stmt.query_map(params![character_id, days], |row| {
-            Ok(ExpHistory {
+        let history = stmt.query_map(params![character_id], |row| {
+            Ok(UnionHistory {
                 id: row.get(0)?,
                 character_id: row.get(1)?,
                 date: row.get(2)?,
-                level: row.get(3)?,
-                exp_rate: row.get(4)?,
-                total_exp: row.get(5)?,
-                created_at: row.get(6)?,
+                union_level: row.get(3)?,
+                union_grade: row.get(4)?,
+                union_artifact_level: row.get(5)?,
+                union_artifact_exp: row.get(6)?,
+                union_artifact_point: row.get(7)?,
+                created_at: row.get(8)?,
             })
         })?.collect::<Result<Vec<_>>>()?;
 
         Ok(history)
     }
 
-    // Data Export/Import/Reset
-    pub fn export_data(&self) -> Result<String> {
-        let settings = self.get_settings()?;
-        let app_settings = self.get_app_settings()?;
+    // Ranking History
+    pub fn save_ranking_snapshot(&self, snapshot: &RankingSnapshot) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO ranking_history (character_id, date, world_rank, class_rank, union_rank, dojang_rank, dojang_floor)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(character_id, date) DO UPDATE SET
+                world_rank = excluded.world_rank,
+                class_rank = excluded.class_rank,
+                union_rank = excluded.union_rank,
+                dojang_rank = excluded.dojang_rank,
+                dojang_floor = excluded.dojang_floor",
+            params![
+                snapshot.character_id, snapshot.date, snapshot.world_rank, snapshot.class_rank,
+                snapshot.union_rank, snapshot.dojang_rank, snapshot.dojang_floor
+            ],
+        )?;
+        Ok(())
+    }
 
-        // 모든 캐릭터
-        let mut char_stmt = self.conn.prepare(
-            "SELECT id, character_name, character_image, ocid, world_name, character_class,
-                    character_level, character_exp_rate, is_active, created_at, updated_at
-             FROM characters ORDER BY id"
+    // 랭킹 스냅샷과 경험치 히스토리를 날짜 기준으로 합쳐 최근 N개 반환
+    pub fn get_ranking_progress(&self, character_id: i64, days: i32) -> Result<Vec<RankingProgress>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT d.date, r.world_rank, r.class_rank, r.union_rank, r.dojang_rank, r.dojang_floor,
+                    e.level, e.exp_rate, e.total_exp
+             FROM (
+                SELECT date FROM ranking_history WHERE character_id = ?1
+                UNION
+                SELECT date FROM exp_history WHERE character_id = ?1
+             ) d
+             LEFT JOIN ranking_history r ON r.character_id = ?1 AND r.date = d.date
+             LEFT JOIN exp_history e ON e.character_id = ?1 AND e.date = d.date
+             ORDER BY d.date DESC
+             LIMIT ?2"
         )?;
-        let characters = char_stmt.query_map([], |row| {
-            Ok(Character {
+
+        let progress = stmt.query_map(params![character_id, days], |row| {
+            Ok(RankingProgress {
+                date: row.get(0)?,
+                world_rank: row.get(1)?,
+                class_rank: row.get(2)?,
+                union_rank: row.get(3)?,
+                dojang_rank: row.get(4)?,
+                dojang_floor: row.get(5)?,
+                level: row.get(6)?,
+                exp_rate: row.get(7)?,
+                total_exp: row.get(8)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(progress)
+    }
+
+    // Enhancement History
+    // Nexon 기록 ID 기준으로 중복 저장 방지, 새로 저장된 건수 반환
+    pub fn save_starforce_records(&self, records: &[StarforceRecord]) -> Result<usize> {
+        let mut inserted = 0;
+        for r in records {
+            inserted += self.conn.execute(
+                "INSERT OR IGNORE INTO starforce_history (id, character_name, world_name, target_item, date_create,
+                    before_starforce_count, after_starforce_count, item_upgrade_result,
+                    destroy_defence, chance_time, cost_discount_rate)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    r.id, r.character_name, r.world_name, r.target_item, r.date_create,
+                    r.before_starforce_count, r.after_starforce_count, r.item_upgrade_result,
+                    if r.destroy_defence { 1 } else { 0 }, if r.chance_time { 1 } else { 0 }, r.cost_discount_rate
+                ],
+            )?;
+        }
+        Ok(inserted)
+    }
+
+    // table: "cube_history" 또는 "potential_history"
+    fn save_potential_records_into(&self, table: &str, records: &[PotentialRecord]) -> Result<usize> {
+        let sql = format!(
+            "INSERT OR IGNORE INTO {} (id, character_name, date_create, cube_type, item_upgrade_result,
+                item_equipment_part, item_level, target_item, potential_option_grade,
+                additional_potential_option_grade, after_potential_option, after_additional_potential_option)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            table
+        );
+        let mut inserted = 0;
+        for r in records {
+            inserted += self.conn.execute(
+                &sql,
+                params![
+                    r.id, r.character_name, r.date_create, r.cube_type, r.item_upgrade_result,
+                    r.item_equipment_part, r.item_level, r.target_item, r.potential_option_grade,
+                    r.additional_potential_option_grade, r.after_potential_option, r.after_additional_potential_option
+                ],
+            )?;
+        }
+        Ok(inserted)
+    }
+
+    pub fn save_cube_records(&self, records: &[PotentialRecord]) -> Result<usize> {
+        self.save_potential_records_into("cube_history", records)
+    }
+
+    pub fn save_potential_records(&self, records: &[PotentialRecord]) -> Result<usize> {
+        self.save_potential_records_into("potential_history", records)
+    }
+
+    pub fn get_starforce_records(&self) -> Result<Vec<StarforceRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_name, world_name, target_item, date_create,
+                    before_starforce_count, after_starforce_count, item_upgrade_result,
+                    destroy_defence, chance_time, cost_discount_rate
+             FROM starforce_history
+             ORDER BY date_create, id"
+        )?;
+
+        let records = stmt.query_map([], |row| {
+            Ok(StarforceRecord {
                 id: row.get(0)?,
                 character_name: row.get(1)?,
-                character_image: row.get(2)?,
-                ocid: row.get(3)?,
-                world_name: row.get(4)?,
-                character_class: row.get(5)?,
-                character_level: row.get(6)?,
-                character_exp_rate: row.get(7)?,
-                is_active: row.get(8)?,
-                created_at: row.get(9)?,
-                updated_at: row.get(10)?,
+                world_name: row.get(2)?,
+                target_item: row.get(3)?,
+                date_create: row.get(4)?,
+                before_starforce_count: row.get(5)?,
+                after_starforce_count: row.get(6)?,
+                item_upgrade_result: row.get(7)?,
+                destroy_defence: row.get::<_, i32>(8)? == 1,
+                chance_time: row.get::<_, i32>(9)? == 1,
+                cost_discount_rate: row.get(10)?,
             })
         })?.collect::<Result<Vec<_>>>()?;
 
-        // 모든 사냥 세션
+        Ok(records)
+    }
+
+    // table: "cube_history" 또는 "potential_history"
+    fn get_potential_records_from(&self, table: &str) -> Result<Vec<PotentialRecord>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT id, character_name, date_create, cube_type, item_upgrade_result, item_equipment_part,
+                    item_level, target_item, potential_option_grade, additional_potential_option_grade,
+                    after_potential_option, after_additional_potential_option
+             FROM {}
+             ORDER BY date_create, id",
+            table
+        ))?;
+
+        let records = stmt.query_map([], |row| {
+            Ok(PotentialRecord {
+                id: row.get(0)?,
+                character_name: row.get(1)?,
+                date_create: row.get(2)?,
+                cube_type: row.get(3)?,
+                item_upgrade_result: row.get(4)?,
+                item_equipment_part: row.get(5)?,
+                item_level: row.get(6)?,
+                target_item: row.get(7)?,
+                potential_option_grade: row.get(8)?,
+                additional_potential_option_grade: row.get(9)?,
+                after_potential_option: row.get(10)?,
+                after_additional_potential_option: row.get(11)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(records)
+    }
+
+    // 기간 내 스타포스 시도 집계 (date_create 앞 10자리 = YYYY-MM-DD)
+    pub fn get_starforce_attempt_groups(&self, start_date: &str, end_date: &str) -> Result<Vec<StarforceAttemptGroup>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, date, session_order, start_level, end_level,
-                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
-                    meso_gained, duration_minutes, sojaebi,
-                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
-                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
-                    start_screenshot, end_screenshot, items, memo, created_at, updated_at
-             FROM hunting_sessions ORDER BY date, session_order"
+            "SELECT character_name, target_item, before_starforce_count, destroy_defence, cost_discount_rate,
+                    COUNT(*) as attempts,
+                    SUM(CASE WHEN item_upgrade_result = '성공' THEN 1 ELSE 0 END) as successes,
+                    SUM(CASE WHEN item_upgrade_result = '파괴' THEN 1 ELSE 0 END) as destroys
+             FROM starforce_history
+             WHERE substr(date_create, 1, 10) BETWEEN ?1 AND ?2
+             GROUP BY character_name, target_item, before_starforce_count, destroy_defence, cost_discount_rate
+             ORDER BY character_name, target_item, before_starforce_count"
         )?;
-        let sessions = stmt.query_map([], |row| {
-            row_to_hunting_session(row)
+
+        let groups = stmt.query_map(params![start_date, end_date], |row| {
+            Ok(StarforceAttemptGroup {
+                character_name: row.get(0)?,
+                target_item: row.get(1)?,
+                before_starforce_count: row.get(2)?,
+                destroy_defence: row.get::<_, i32>(3)? == 1,
+                cost_discount_rate: row.get(4)?,
+                attempts: row.get(5)?,
+                successes: row.get(6)?,
+                destroys: row.get(7)?,
+            })
         })?.collect::<Result<Vec<_>>>()?;
 
-        // 모든 보스 설정
-        let mut boss_stmt = self.conn.prepare(
-            "SELECT id, character_id, boss_id, difficulty, party_size, enabled, created_at, updated_at
-             FROM boss_settings ORDER BY id"
+        Ok(groups)
+    }
+
+    // 기간 내 아이템별 큐브/잠재능력 재설정 사용 횟수
+    pub fn get_cube_usage_groups(&self, start_date: &str, end_date: &str) -> Result<Vec<CubeUsageGroup>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT character_name, target_item, cube_type, COUNT(*) FROM (
+                SELECT character_name, target_item, cube_type, date_create FROM cube_history
+                UNION ALL
+                SELECT character_name, target_item, cube_type, date_create FROM potential_history
+             )
+             WHERE substr(date_create, 1, 10) BETWEEN ?1 AND ?2
+             GROUP BY character_name, target_item, cube_type
+             ORDER BY character_name, target_item, cube_type"
         )?;
-        let boss_settings = boss_stmt.query_map([], |row| {
-            Ok(BossSetting {
-                id: row.get(0)?,
-                character_id: row.get(1)?,
-                boss_id: row.get(2)?,
-                difficulty: row.get(3)?,
-                party_size: row.get(4)?,
-                enabled: row.get(5)?,
-                created_at: row.get(6)?,
-                updated_at: row.get(7)?,
+
+        let groups = stmt.query_map(params![start_date, end_date], |row| {
+            Ok(CubeUsageGroup {
+                character_name: row.get(0)?,
+                target_item: row.get(1)?,
+                cube_type: row.get(2)?,
+                count: row.get(3)?,
             })
         })?.collect::<Result<Vec<_>>>()?;
 
-        // 모든 보스 클리어
-        let mut clear_stmt = self.conn.prepare(
-            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date,
-                    crystal_price, party_size, created_at
-             FROM boss_clears ORDER BY id"
+        Ok(groups)
+    }
+
+    // 큐브/재설정 기록에 남은 아이템 레벨 (스타포스 비용 계산용)
+    pub fn get_known_item_levels(&self) -> Result<Vec<(String, i32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT target_item, MAX(item_level) FROM (
+                SELECT target_item, item_level FROM cube_history
+                UNION ALL
+                SELECT target_item, item_level FROM potential_history
+             )
+             WHERE item_level > 0
+             GROUP BY target_item"
         )?;
-        let boss_clears = clear_stmt.query_map([], |row| {
-            Ok(BossClear {
-                id: row.get(0)?,
-                character_id: row.get(1)?,
-                boss_id: row.get(2)?,
-                difficulty: row.get(3)?,
-                cleared_date: row.get(4)?,
-                week_start_date: row.get(5)?,
-                crystal_price: row.get(6)?,
-                party_size: row.get(7)?,
-                created_at: row.get(8)?,
-            })
+
+        let levels = stmt.query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?))
         })?.collect::<Result<Vec<_>>>()?;
 
-        // 모든 득템 기록
-        let mut drop_stmt = self.conn.prepare(
-            "SELECT id, character_id, date, item_name, price, screenshot, created_at
-             FROM item_drops ORDER BY id"
+        Ok(levels)
+    }
+
+    // Meso Expenses
+    pub fn save_meso_expense(&self, character_id: i64, date: &str, category: &str, amount: i64, memo: Option<&str>) -> Result<i64> {
+        self.conn.execute(
+            "INSERT INTO meso_expenses (character_id, date, category, amount, memo)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![character_id, date, category, amount, memo],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn update_meso_expense(&self, id: i64, date: &str, category: &str, amount: i64, memo: Option<&str>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE meso_expenses SET date = ?1, category = ?2, amount = ?3, memo = ?4 WHERE id = ?5",
+            params![date, category, amount, memo, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_meso_expense(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM meso_expenses WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub fn get_meso_expenses(&self, character_id: i64, start_date: &str, end_date: &str) -> Result<Vec<MesoExpense>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, category, amount, memo, created_at
+             FROM meso_expenses
+             WHERE character_id = ?1 AND date >= ?2 AND date <= ?3
+             ORDER BY date, id"
         )?;
-        let item_drops = drop_stmt.query_map([], |row| {
-            Ok(ItemDrop {
-                id: row.get(0)?,
-                character_id: row.get(1)?,
-                date: row.get(2)?,
-                item_name: row.get(3)?,
-                price: row.get(4)?,
-                screenshot: row.get(5)?,
-                created_at: row.get(6)?,
-            })
-        })?.collect::<Result<Vec<_>>>()?;
 
-        let export = serde_json::json!({
-            "version": 2,
-            "exported_at": chrono::Utc::now().to_rfc3339(),
-            "settings": settings,
-            "app_settings": app_settings,
-            "characters": characters,
-            "hunting_sessions": sessions,
-            "boss_settings": boss_settings,
-            "boss_clears": boss_clears,
-            "item_drops": item_drops,
-        });
+        let expenses = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            row_to_meso_expense(row)
+        })?.collect::<Result<Vec<_>>>()?;
 
-        Ok(serde_json::to_string_pretty(&export).unwrap_or_default())
+        Ok(expenses)
     }
 
-    pub fn import_data(&self, json_data: &str) -> Result<()> {
-        let data: serde_json::Value = serde_json::from_str(json_data)
-            .map_err(|_| rusqlite::Error::InvalidQuery)?;
+    // 일별 수입(사냥 메소, 결정석, 득템 판매 실수령액)과 지출 합계
+    pub fn get_daily_meso_flow(&self, character_id: i64, start_date: &str, end_date: &str) -> Result<Vec<DailyMesoFlow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT date,
+                    SUM(hunting_meso), SUM(crystal_income), SUM(item_drop_income), SUM(expense)
+             FROM (
+                SELECT date, meso_gained as hunting_meso, 0 as crystal_income, 0 as item_drop_income, 0 as expense
+                FROM hunting_sessions WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
+                UNION ALL
+                SELECT cleared_date, 0, crystal_price / party_size, 0, 0
+                FROM boss_clears WHERE character_id = ?1 AND cleared_date BETWEEN ?2 AND ?3
+                UNION ALL
+                SELECT sold_date, 0, 0, sale_price - COALESCE(sale_fee, 0), 0
+                FROM item_drops WHERE character_id = ?1 AND sale_status = 'sold' AND sold_date BETWEEN ?2 AND ?3
+                UNION ALL
+                SELECT date, 0, 0, 0, amount
+                FROM meso_expenses WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
+             )
+             GROUP BY date
+             ORDER BY date"
+        )?;
 
-        let version = data.get("version").and_then(|v| v.as_i64()).unwrap_or(1);
+        let flows = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            Ok(DailyMesoFlow {
+                date: row.get(0)?,
+                hunting_meso: row.get(1)?,
+                crystal_income: row.get(2)?,
+                item_drop_income: row.get(3)?,
+                expense: row.get(4)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
 
-        // 모든 데이터 삭제 (전체 복원)
-        self.conn.execute("DELETE FROM item_drops", [])?;
-        self.conn.execute("DELETE FROM boss_clears", [])?;
-        self.conn.execute("DELETE FROM boss_settings", [])?;
-        self.conn.execute("DELETE FROM hunting_sessions", [])?;
-        self.conn.execute("DELETE FROM characters", [])?;
-        self.conn.execute("DELETE FROM settings", [])?;
+        Ok(flows)
+    }
 
-        // API Key 복원
-        if let Some(settings) = data.get("settings") {
-            if let Some(api_key) = settings.get("api_key").and_then(|v| v.as_str()) {
-                self.save_api_key(api_key)?;
+    // Item Catalog
+    fn seed_item_catalog(&self) -> Result<()> {
+        for (name, category, aliases) in SEED_ITEMS {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO item_catalog (canonical_name, normalized_name, category) VALUES (?1, ?2, ?3)",
+                params![name, item_catalog::normalize_item_name(name), category],
+            )?;
+            let item_id: i64 = self.conn.query_row(
+                "SELECT id FROM item_catalog WHERE canonical_name = ?1",
+                params![name],
+                |row| row.get(0),
+            )?;
+            for alias in *aliases {
+                self.conn.execute(
+                    "INSERT OR IGNORE INTO item_aliases (normalized_alias, alias, item_id) VALUES (?1, ?2, ?3)",
+                    params![item_catalog::normalize_item_name(alias), alias, item_id],
+                )?;
             }
         }
+        Ok(())
+    }
 
-        // 앱 설정 복원 (version 2)
-        if let Some(app_settings) = data.get("app_settings") {
-            if !app_settings.is_null() {
-                let price = app_settings.get("sol_erda_piece_price").and_then(|v| v.as_i64()).unwrap_or(6500000);
-                let folder = app_settings.get("screenshot_folder_path").and_then(|v| v.as_str());
-                self.save_app_settings(price)?;
-                self.save_screenshot_folder_path(folder)?;
-            }
+    // hunting_sessions.items(JSON 문자열)를 session_consumables로 옮기고 컬럼 삭제
+    fn migrate_session_items(&self) -> Result<()> {
+        let has_items_column: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM pragma_table_info('hunting_sessions') WHERE name = 'items')",
+            [],
+            |row| row.get(0),
+        )?;
+        if !has_items_column {
+            return Ok(());
         }
 
-        // 캐릭터 복원
-        if version >= 2 {
-            // v2: 모든 캐릭터 배열
-            if let Some(characters) = data.get("characters").and_then(|v| v.as_array()) {
-                for char_data in characters {
-                    let is_active = char_data.get("is_active").and_then(|v| v.as_bool())
-                        .or_else(|| char_data.get("is_active").and_then(|v| v.as_i64()).map(|n| n == 1))
-                        .unwrap_or(false);
-                    self.conn.execute(
-                        "INSERT INTO characters (character_name, character_image, ocid, world_name,
-                                                character_class, character_level, character_exp_rate, is_active)
-                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-                        params![
-                            char_data.get("character_name").and_then(|v| v.as_str()).unwrap_or_default(),
-                            char_data.get("character_image").and_then(|v| v.as_str()).unwrap_or_default(),
-                            char_data.get("ocid").and_then(|v| v.as_str()).unwrap_or_default(),
-                            char_data.get("world_name").and_then(|v| v.as_str()).unwrap_or_default(),
-                            char_data.get("character_class").and_then(|v| v.as_str()).unwrap_or_default(),
-                            char_data.get("character_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                            char_data.get("character_exp_rate").and_then(|v| v.as_str()),
-                            if is_active { 1 } else { 0 },
-                        ],
+        // 중간에 중단되면 다음 실행 때 메모가 중복으로 붙으므로 컬럼 삭제까지 한 번에 적용
+        self.in_savepoint(|db| {
+            let rows = db.conn.prepare(
+                "SELECT id, items, memo FROM hunting_sessions WHERE items != '[]'"
+            )?.query_map([], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?))
+            })?.collect::<Result<Vec<_>>>()?;
+
+            for (id, items, mut memo) in rows {
+                let (consumables, unknown) = consumable::parse_legacy_items(&items);
+                db.save_session_consumables(id, &consumables)?;
+                if !unknown.is_empty() {
+                    append_legacy_items_memo(&mut memo, &unknown);
+                    db.conn.execute(
+                        "UPDATE hunting_sessions SET memo = ?1 WHERE id = ?2",
+                        params![memo, id],
                     )?;
                 }
             }
-        } else {
-            // v1: 단일 캐릭터 (하위 호환)
-            if let Some(char_data) = data.get("character") {
-                if !char_data.is_null() {
-                    let character = Character {
-                        id: 0,
-                        character_name: char_data.get("character_name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                        character_image: char_data.get("character_image").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                        ocid: char_data.get("ocid").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                        world_name: char_data.get("world_name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                        character_class: char_data.get("character_class").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                        character_level: char_data.get("character_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                        character_exp_rate: char_data.get("character_exp_rate").and_then(|v| v.as_str()).map(|s| s.to_string()),
-                        is_active: true,
-                        created_at: String::new(),
-                        updated_at: String::new(),
-                    };
-                    self.register_character(&character)?;
-                }
-            }
-        }
-
-        // 사냥 세션 복원
-        if let Some(sessions) = data.get("hunting_sessions").and_then(|v| v.as_array()) {
-            for session_data in sessions {
-                let session = HuntingSession {
-                    id: 0,
-                    character_id: session_data.get("character_id").and_then(|v| v.as_i64()).unwrap_or(1),
-                    date: session_data.get("date").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                    session_order: session_data.get("session_order").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
-                    start_level: session_data.get("start_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    end_level: session_data.get("end_level").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    start_exp_percent: session_data.get("start_exp_percent").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                    end_exp_percent: session_data.get("end_exp_percent").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                    exp_gained: session_data.get("exp_gained").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                    start_meso: session_data.get("start_meso").and_then(|v| v.as_i64()).unwrap_or(0),
-                    end_meso: session_data.get("end_meso").and_then(|v| v.as_i64()).unwrap_or(0),
-                    meso_gained: session_data.get("meso_gained").and_then(|v| v.as_i64()).unwrap_or(0),
-                    duration_minutes: session_data.get("duration_minutes").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    sojaebi: session_data.get("sojaebi").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                    start_sol_erda: session_data.get("start_sol_erda").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    end_sol_erda: session_data.get("end_sol_erda").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    start_sol_erda_gauge: session_data.get("start_sol_erda_gauge").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    end_sol_erda_gauge: session_data.get("end_sol_erda_gauge").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-                    sol_erda_gained: session_data.get("sol_erda_gained").and_then(|v| v.as_f64()).unwrap_or(0.0),
-                    start_sol_erda_piece: session_data.get("start_sol_erda_piece").and_then(|v| v.as_i64()).unwrap_or(0),
-                    end_sol_erda_piece: session_data.get("end_sol_erda_piece").and_then(|v| v.as_i64()).unwrap_or(0),
-                    sol_erda_piece_gained: session_data.get("sol_erda_piece_gained").and_then(|v| v.as_i64()).unwrap_or(0),
-                    sol_erda_piece_price: session_data.get("sol_erda_piece_price").and_then(|v| v.as_i64()).unwrap_or(6500000),
-                    start_screenshot: session_data.get("start_screenshot").and_then(|v| v.as_str()).map(|s| s.to_string()),
-                    end_screenshot: session_data.get("end_screenshot").and_then(|v| v.as_str()).map(|s| s.to_string()),
-                    items: session_data.get("items").and_then(|v| v.as_str()).unwrap_or("[]").to_string(),
-                    memo: session_data.get("memo").and_then(|v| v.as_str()).map(|s| s.to_string()),
-                    created_at: String::new(),
-                    updated_at: String::new(),
-                };
-                self.save_hunting_session(&session)?;
-            }
-        }
 
-        // 보스 설정 복원 (version 2)
-        if let Some(boss_settings) = data.get("boss_settings").and_then(|v| v.as_array()) {
-            for bs in boss_settings {
-                let setting = BossSetting {
-                    id: 0,
-                    character_id: bs.get("character_id").and_then(|v| v.as_i64()).unwrap_or(1),
-                    boss_id: bs.get("boss_id").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                    difficulty: bs.get("difficulty").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
-                    party_size: bs.get("party_size").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
-                    enabled: bs.get("enabled").and_then(|v| v.as_bool())
-                        .or_else(|| bs.get("enabled").and_then(|v| v.as_i64()).map(|n| n == 1))
-                        .unwrap_or(true),
-                    created_at: String::new(),
-                    updated_at: String::new(),
-                };
-                self.save_boss_setting(&setting)?;
-            }
-        }
+            db.conn.execute("ALTER TABLE hunting_sessions DROP COLUMN items", [])?;
+            Ok(())
+        })
+    }
 
-        // 보스 클리어 복원 (version 2)
-        if let Some(boss_clears) = data.get("boss_clears").and_then(|v| v.as_array()) {
-            for bc in boss_clears {
-                self.conn.execute(
-                    "INSERT INTO boss_clears (character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-                    params![
-                        bc.get("character_id").and_then(|v| v.as_i64()).unwrap_or(1),
-                        bc.get("boss_id").and_then(|v| v.as_str()).unwrap_or_default(),
-                        bc.get("difficulty").and_then(|v| v.as_str()).unwrap_or_default(),
-                        bc.get("cleared_date").and_then(|v| v.as_str()).unwrap_or_default(),
-                        bc.get("week_start_date").and_then(|v| v.as_str()).unwrap_or_default(),
-                        bc.get("crystal_price").and_then(|v| v.as_i64()).unwrap_or(0),
-                        bc.get("party_size").and_then(|v| v.as_i64()).unwrap_or(1) as i32,
-                    ],
-                )?;
-            }
-        }
+    // 카탈로그 연결이 없는 기존 득템 기록을 정식 명칭/별칭이 일치하는 항목에 연결
+    // 입력한 이름은 그대로 두고, 일치하지 않는 기록은 사용자가 확인하도록 연결하지 않음
+    fn migrate_item_drop_names(&self) -> Result<()> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT item_name FROM item_drops WHERE item_id IS NULL"
+        )?;
+        let names = stmt.query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>>>()?;
 
-        // 득템 기록 복원 (version 2)
-        if let Some(item_drops) = data.get("item_drops").and_then(|v| v.as_array()) {
-            for item in item_drops {
-                self.save_item_drop(
-                    item.get("character_id").and_then(|v| v.as_i64()).unwrap_or(1),
-                    item.get("date").and_then(|v| v.as_str()).unwrap_or_default(),
-                    item.get("item_name").and_then(|v| v.as_str()).unwrap_or_default(),
-                    item.get("price").and_then(|v| v.as_i64()).unwrap_or(0),
-                    item.get("screenshot").and_then(|v| v.as_str()),
-                )?;
-            }
+        for name in names {
+            let Some((item_id, _)) = self.find_catalog_item(&name)? else {
+                continue;
+            };
+            self.conn.execute(
+                "UPDATE item_drops SET item_id = ?1 WHERE item_id IS NULL AND item_name = ?2",
+                params![item_id, name],
+            )?;
         }
-
         Ok(())
     }
 
-    pub fn reset_data(&self) -> Result<()> {
-        self.conn.execute("DELETE FROM item_drops", [])?;
-        self.conn.execute("DELETE FROM boss_clears", [])?;
-        self.conn.execute("DELETE FROM boss_settings", [])?;
-        self.conn.execute("DELETE FROM hunting_sessions", [])?;
-        self.conn.execute("DELETE FROM characters", [])?;
-        self.conn.execute("DELETE FROM settings", [])?;
-        Ok(())
+    // 정식 명칭 또는 별칭으로 카탈로그 검색
+    pub fn find_catalog_item(&self, name: &str) -> Result<Option<(i64, String)>> {
+        let normalized = item_catalog::normalize_item_name(name);
+        self.conn.query_row(
+            "SELECT c.id, c.canonical_name FROM item_catalog c
+             WHERE c.normalized_name = ?1
+                OR c.id = (SELECT item_id FROM item_aliases WHERE normalized_alias = ?1)
+             LIMIT 1",
+            params![normalized],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ).optional()
     }
 
-    // Boss Settings
-    pub fn get_boss_settings(&self, character_id: i64) -> Result<Vec<BossSetting>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, boss_id, difficulty, party_size, enabled, created_at, updated_at
-             FROM boss_settings
-             WHERE character_id = ?1
-             ORDER BY boss_id, difficulty"
-        )?;
-
-        let settings = stmt.query_map(params![character_id], |row| {
-            Ok(BossSetting {
-                id: row.get(0)?,
-                character_id: row.get(1)?,
-                boss_id: row.get(2)?,
-                difficulty: row.get(3)?,
-                party_size: row.get(4)?,
-                enabled: row.get::<_, i32>(5)? == 1,
-                created_at: row.get(6)?,
-                updated_at: row.get(7)?,
-            })
-        })?.collect::<Result<Vec<_>>>()?;
-
-        Ok(settings)
+    // 정식 명칭/별칭과 정확히 일치하지 않으면 기타 카테고리로 새로 등록 (유사 이름은 자동완성 제안만)
+    pub fn resolve_catalog_item(&self, name: &str) -> Result<(i64, String)> {
+        if item_catalog::normalize_item_name(name).is_empty() {
+            return Err(message_error("아이템 이름이 비어 있습니다".to_string()));
+        }
+        if let Some(item) = self.find_catalog_item(name)? {
+            return Ok(item);
+        }
+        let canonical_name = name.trim();
+        let id = self.save_catalog_item(canonical_name, "etc")?;
+        Ok((id, canonical_name.to_string()))
     }
 
-    pub fn save_boss_setting(&self, setting: &BossSetting) -> Result<i64> {
-        // UPSERT: 이미 있으면 업데이트, 없으면 삽입
+    pub fn save_catalog_item(&self, canonical_name: &str, category: &str) -> Result<i64> {
         self.conn.execute(
-            "INSERT INTO boss_settings (character_id, boss_id, difficulty, party_size, enabled)
-             VALUES (?1, ?2, ?3, ?4, ?5)
-             ON CONFLICT(character_id, boss_id, difficulty) DO UPDATE SET
-                party_size = excluded.party_size,
-                enabled = excluded.enabled,
-                updated_at = datetime('now')",
-            params![
-                setting.character_id,
-                setting.boss_id,
-                setting.difficulty,
-                setting.party_size,
-                if setting.enabled { 1 } else { 0 }
-            ],
+            "INSERT INTO item_catalog (canonical_name, normalized_name, category) VALUES (?1, ?2, ?3)",
+            params![canonical_name, item_catalog::normalize_item_name(canonical_name), category],
         )?;
-
         Ok(self.conn.last_insert_rowid())
     }
 
-    pub fn delete_boss_setting(&self, character_id: i64, boss_id: &str, difficulty: &str) -> Result<()> {
+    pub fn update_catalog_item_category(&self, id: i64, category: &str) -> Result<()> {
         self.conn.execute(
-            "DELETE FROM boss_settings WHERE character_id = ?1 AND boss_id = ?2 AND difficulty = ?3",
-            params![character_id, boss_id, difficulty],
+            "UPDATE item_catalog SET category = ?1 WHERE id = ?2",
+            params![category, id],
         )?;
         Ok(())
     }
 
-    pub fn update_boss_setting_party_size(&self, character_id: i64, boss_id: &str, difficulty: &str, party_size: i32) -> Result<()> {
+    pub fn add_item_alias(&self, item_id: i64, alias: &str) -> Result<()> {
         self.conn.execute(
-            "UPDATE boss_settings SET party_size = ?1, updated_at = datetime('now')
-             WHERE character_id = ?2 AND boss_id = ?3 AND difficulty = ?4",
-            params![party_size, character_id, boss_id, difficulty],
+            "INSERT INTO item_aliases (normalized_alias, alias, item_id) VALUES (?1, ?2, ?3)
+             ON CONFLICT(normalized_alias) DO UPDATE SET alias = excluded.alias, item_id = excluded.item_id",
+            params![item_catalog::normalize_item_name(alias), alias, item_id],
         )?;
         Ok(())
     }
 
-    // Boss Clears
-    // 목요일 기준 주간 시작일 계산
-    pub fn get_week_start_date(date: &str) -> String {
-        let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_else(|_| {
-            chrono::Local::now().date_naive()
-        });
-
-        // 목요일 = 3 (월=0, 화=1, 수=2, 목=3, 금=4, 토=5, 일=6)
-        let weekday = parsed.weekday().num_days_from_monday();
-        let days_since_thursday = if weekday >= 3 {
-            weekday - 3
-        } else {
-            weekday + 4 // 월화수는 이전 주 목요일
-        };
-
-        let week_start = parsed - chrono::Duration::days(days_since_thursday as i64);
-        week_start.format("%Y-%m-%d").to_string()
-    }
-
-    // 월초 날짜 계산 (월간 보스용)
-    pub fn get_month_start_date(date: &str) -> String {
-        let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_else(|_| {
-            chrono::Local::now().date_naive()
-        });
+    // 오타 등으로 따로 생긴 항목을 대상 항목에 병합 (원래 이름은 별칭으로 남김)
+    pub fn merge_catalog_item(&self, source_id: i64, target_id: i64) -> Result<()> {
+        let source_name: String = self.conn.query_row(
+            "SELECT canonical_name FROM item_catalog WHERE id = ?1",
+            params![source_id],
+            |row| row.get(0),
+        )?;
+        let target_name: String = self.conn.query_row(
+            "SELECT canonical_name FROM item_catalog WHERE id = ?1",
+            params![target_id],
+            |row| row.get(0),
+        )?;
 
-        // 해당 월의 1일로 설정
-        let month_start = NaiveDate::from_ymd_opt(parsed.year(), parsed.month(), 1)
-            .unwrap_or(parsed);
-        month_start.format("%Y-%m-%d").to_string()
+        self.conn.execute(
+            "UPDATE item_drops SET item_id = ?1, item_name = ?2 WHERE item_id = ?3",
+            params![target_id, target_name, source_id],
+        )?;
+        self.conn.execute(
+            "UPDATE item_aliases SET item_id = ?1 WHERE item_id = ?2",
+            params![target_id, source_id],
+        )?;
+        // 같은 날짜/출처 시세가 이미 있으면 대상 항목 값을 유지
+        self.conn.execute(
+            "UPDATE OR IGNORE item_price_history SET item_id = ?1 WHERE item_id = ?2",
+            params![target_id, source_id],
+        )?;
+        self.conn.execute("DELETE FROM item_price_history WHERE item_id = ?1", params![source_id])?;
+        self.conn.execute("DELETE FROM item_catalog WHERE id = ?1", params![source_id])?;
+        self.add_item_alias(target_id, &source_name)
     }
 
-    pub fn save_boss_clear(&self, clear: &BossClear, is_monthly: bool) -> Result<i64> {
-        let period_start = if is_monthly {
-            Self::get_month_start_date(&clear.cleared_date)
-        } else {
-            Self::get_week_start_date(&clear.cleared_date)
-        };
+    // 자동완성 검색 (접두어 일치 우선, 득템 횟수 순, 자리가 남으면 유사 이름 제안)
+    pub fn search_item_catalog(&self, query: &str, limit: i32) -> Result<Vec<CatalogItem>> {
+        let normalized = item_catalog::normalize_item_name(query);
+        let mut stmt = self.conn.prepare(
+            "SELECT c.id, c.canonical_name, c.category,
+                    (SELECT COUNT(*) FROM item_drops d WHERE d.item_id = c.id) as drop_count,
+                    MIN(CASE WHEN n.name LIKE ?1 || '%' THEN 0 ELSE 1 END) as prefix_rank
+             FROM item_catalog c
+             JOIN (
+                SELECT id as item_id, normalized_name as name FROM item_catalog
+                UNION ALL
+                SELECT item_id, normalized_alias FROM item_aliases
+             ) n ON n.item_id = c.id
+             WHERE n.name LIKE '%' || ?1 || '%'
+             GROUP BY c.id
+             ORDER BY prefix_rank, drop_count DESC, c.canonical_name
+             LIMIT ?2"
+        )?;
 
-        self.conn.execute(
-            "INSERT INTO boss_clears (character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-             ON CONFLICT(character_id, boss_id, week_start_date) DO UPDATE SET
-                difficulty = excluded.difficulty,
-                cleared_date = excluded.cleared_date,
-                crystal_price = excluded.crystal_price,
-                party_size = excluded.party_size",
-            params![
-                clear.character_id,
-                clear.boss_id,
-                clear.difficulty,
-                clear.cleared_date,
-                period_start,
-                clear.crystal_price,
-                clear.party_size
-            ],
+        let mut items = stmt.query_map(params![normalized, limit], |row| {
+            Ok(CatalogItem {
+                id: row.get(0)?,
+                canonical_name: row.get(1)?,
+                category: row.get(2)?,
+                drop_count: row.get(3)?,
+            })
+        })?.collect::<Result<Vec<_>>>()?;
+
+        if normalized.is_empty() || items.len() >= limit.max(0) as usize {
+            return Ok(items);
+        }
+        let mut stmt = self.conn.prepare(
+            "SELECT id, canonical_name FROM item_catalog
+             UNION ALL
+             SELECT item_id, alias FROM item_aliases"
         )?;
+        let candidates = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<Result<Vec<_>>>()?;
+        for id in item_catalog::find_similar_items(query, &candidates) {
+            if items.len() >= limit as usize {
+                break;
+            }
+            if items.iter().any(|item| item.id == id) {
+                continue;
+            }
+            items.push(self.conn.query_row(
+                "SELECT id, canonical_name, category,
+                        (SELECT COUNT(*) FROM item_drops WHERE item_id = item_catalog.id)
+                 FROM item_catalog WHERE id = ?1",
+                params![id],
+                |row| Ok(CatalogItem {
+                    id: row.get(0)?,
+                    canonical_name: row.get(1)?,
+                    category: row.get(2)?,
+                    drop_count: row.get(3)?,
+                }),
+            )?);
+        }
 
-        Ok(self.conn.last_insert_rowid())
+        Ok(items)
     }
 
-    pub fn delete_boss_clear(&self, character_id: i64, boss_id: &str, week_start_date: &str) -> Result<()> {
+    // Item Prices
+    pub fn save_item_price(&self, item_id: i64, date: &str, price: i64, source: &str) -> Result<()> {
         self.conn.execute(
-            "DELETE FROM boss_clears WHERE character_id = ?1 AND boss_id = ?2 AND week_start_date = ?3",
-            params![character_id, boss_id, week_start_date],
+            "INSERT INTO item_price_history (item_id, date, price, source)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(item_id, date, source) DO UPDATE SET price = excluded.price",
+            params![item_id, date, price, source],
         )?;
         Ok(())
     }
 
-    pub fn get_boss_clears_by_week(&self, character_id: i64, week_start_date: &str) -> Result<Vec<BossClear>> {
+    pub fn delete_item_price(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM item_price_history WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    // 아이템별 시세 추이 (since 이후, 날짜순)
+    pub fn get_item_price_trend(&self, item_id: i64, since: &str) -> Result<Vec<ItemPrice>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, created_at
-             FROM boss_clears
-             WHERE character_id = ?1 AND week_start_date = ?2
-             ORDER BY cleared_date"
+            "SELECT p.id, p.item_id, c.canonical_name, p.date, p.price, p.source, p.created_at
+             FROM item_price_history p
+             JOIN item_catalog c ON c.id = p.item_id
+             WHERE p.item_id = ?1 AND p.date >= ?2
+             ORDER BY p.date, p.source"
         )?;
 
-        let clears = stmt.query_map(params![character_id, week_start_date], |row| {
-            row_to_boss_clear(row)
+        let prices = stmt.query_map(params![item_id, since], |row| {
+            row_to_item_price(row)
         })?.collect::<Result<Vec<_>>>()?;
 
-        Ok(clears)
+        Ok(prices)
     }
 
-    pub fn get_boss_clears_by_date(&self, character_id: i64, date: &str) -> Result<Vec<BossClear>> {
+    // 미판매(listed, kept) 득템을 최신 시세로 평가 (같은 날짜면 직접 입력한 시세 우선)
+    pub fn get_held_item_values(&self, character_id: i64) -> Result<Vec<HeldItemValue>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, created_at
-             FROM boss_clears
-             WHERE character_id = ?1 AND cleared_date = ?2
-             ORDER BY boss_id"
+            "SELECT d.id, d.date, d.item_name, d.item_id, d.sale_status, d.price, p.price, p.date
+             FROM item_drops d
+             LEFT JOIN item_price_history p ON p.id = (
+                SELECT id FROM item_price_history
+                WHERE item_id = d.item_id
+                ORDER BY date DESC, CASE source WHEN 'manual' THEN 0 ELSE 1 END, id DESC
+                LIMIT 1
+             )
+             WHERE d.character_id = ?1 AND d.sale_status != 'sold'
+             ORDER BY d.date, d.id"
         )?;
 
-        let clears = stmt.query_map(params![character_id, date], |row| {
-            row_to_boss_clear(row)
+        let values = stmt.query_map(params![character_id], |row| {
+            let entry_price: i64 = row.get(5)?;
+            let latest_price: Option<i64> = row.get(6)?;
+            Ok(HeldItemValue {
+                drop_id: row.get(0)?,
+                date: row.get(1)?,
+                item_name: row.get(2)?,
+                item_id: row.get(3)?,
+                sale_status: row.get(4)?,
+                entry_price,
+                latest_price,
+                latest_price_date: row.get(7)?,
+                current_value: latest_price.unwrap_or(entry_price),
+            })
         })?.collect::<Result<Vec<_>>>()?;
 
-        Ok(clears)
+        Ok(values)
     }
 
-    pub fn get_weekly_boss_summary(&self, character_id: i64, week_start_date: &str) -> Result<WeeklyBossSummary> {
-        let result = self.conn.query_row(
-            "SELECT week_start_date,
-                    COALESCE(SUM(crystal_price / party_size), 0) as total_income,
-                    COUNT(*) as boss_count
-             FROM boss_clears
-             WHERE character_id = ?1 AND week_start_date = ?2
-             GROUP BY week_start_date",
-            params![character_id, week_start_date],
-            |row| {
-                Ok(WeeklyBossSummary {
-                    week_start_date: row.get(0)?,
-                    total_crystal_income: row.get(1)?,
-                    boss_count: row.get(2)?,
-                })
-            }
-        );
+    // Session Metrics
+    pub fn get_all_hunting_sessions(&self, character_id: i64) -> Result<Vec<HuntingSession>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, session_order, start_level, end_level,
+                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
+                    meso_gained, duration_minutes, sojaebi,
+                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
+                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
+                    start_screenshot, end_screenshot, memo, created_at, updated_at, map_id
+             FROM hunting_sessions WHERE character_id = ?1 ORDER BY date, session_order"
+        )?;
 
-        match result {
-            Ok(summary) => Ok(summary),
-            Err(_) => Ok(WeeklyBossSummary {
-                week_start_date: week_start_date.to_string(),
-                total_crystal_income: 0,
-                boss_count: 0,
-            })
-        }
-    }
+        let mut sessions = stmt.query_map(params![character_id], |row| {
+            row_to_hunting_session(row)
+        })?.collect::<Result<Vec<_>>>()?;
+        self.load_session_consumables(&mut sessions)?;
 
-    // 월별 보스 클리어 요약 (달력용)
-    pub fn get_monthly_boss_clears(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<BossClear>> {
-        let start_date = format!("{:04}-{:02}-01", year, month);
-        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
+        Ok(sessions)
+    }
 
+    // 날짜별 득템 가치 (판매 완료는 수수료 차감 실수령액, 나머지는 입력 가격)
+    pub fn get_daily_drop_values(&self, character_id: i64) -> Result<Vec<(String, i64)>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date, crystal_price, party_size, created_at
-             FROM boss_clears
-             WHERE character_id = ?1 AND cleared_date >= ?2 AND cleared_date <= ?3
-             ORDER BY cleared_date, boss_id"
+            "SELECT date,
+                    SUM(CASE WHEN sale_status = 'sold' THEN sale_price - COALESCE(sale_fee, 0) ELSE price END)
+             FROM item_drops
+             WHERE character_id = ?1
+             GROUP BY date"
         )?;
 
-        let clears = stmt.query_map(params![character_id, start_date, end_date], |row| {
-            row_to_boss_clear(row)
+        let values = stmt.query_map(params![character_id], |row| {
+            Ok((row.get(0)?, row.get(1)?))
         })?.collect::<Result<Vec<_>>>()?;
 
-        Ok(clears)
+        Ok(values)
     }
 
-    // App Settings (조각 가격 등)
-    pub fn get_app_settings(&self) -> Result<AppSettings> {
-        let result = self.conn.query_row(
-            "SELECT id, sol_erda_piece_price, screenshot_folder_path, created_at, updated_at FROM app_settings ORDER BY id DESC LIMIT 1",
-            [],
-            |row| {
-                Ok(AppSettings {
-                    id: row.get(0)?,
-                    sol_erda_piece_price: row.get(1)?,
-                    screenshot_folder_path: row.get(2)?,
-                    created_at: row.get(3)?,
-                    updated_at: row.get(4)?,
-                })
-            }
-        );
+    // Hunting Maps
+    pub fn get_hunting_maps(&self) -> Result<Vec<HuntingMap>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, region, map_name FROM hunting_maps ORDER BY id"
+        )?;
 
-        match result {
-            Ok(settings) => Ok(settings),
-            Err(_) => Ok(AppSettings {
-                id: 0,
-                sol_erda_piece_price: 6500000, // 기본값 650만 메소
-                screenshot_folder_path: None,
-                created_at: String::new(),
-                updated_at: String::new(),
+        let maps = stmt.query_map([], |row| {
+            Ok(HuntingMap {
+                id: row.get(0)?,
+                region: row.get(1)?,
+                map_name: row.get(2)?,
             })
-        }
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(maps)
     }
 
-    pub fn save_app_settings(&self, sol_erda_piece_price: i64) -> Result<()> {
+    // 이미 있는 맵이면 기존 id 반환
+    pub fn save_hunting_map(&self, region: &str, map_name: &str) -> Result<i64> {
         self.conn.execute(
-            "UPDATE app_settings SET sol_erda_piece_price = ?1, updated_at = datetime('now') WHERE id = 1",
-            params![sol_erda_piece_price],
+            "INSERT OR IGNORE INTO hunting_maps (region, map_name) VALUES (?1, ?2)",
+            params![region, map_name],
         )?;
-        Ok(())
+        self.conn.query_row(
+            "SELECT id FROM hunting_maps WHERE region = ?1 AND map_name = ?2",
+            params![region, map_name],
+            |row| row.get(0),
+        )
     }
 
-    pub fn save_screenshot_folder_path(&self, path: Option<&str>) -> Result<()> {
+    // Game Events
+    pub fn save_game_event(&self, event: &GameEvent) -> Result<i64> {
         self.conn.execute(
-            "UPDATE app_settings SET screenshot_folder_path = ?1, updated_at = datetime('now') WHERE id = 1",
-            params![path],
+            "INSERT INTO game_events (character_id, name, event_type, start_date, end_date, exp_multiplier, memo)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                event.character_id,
+                event.name,
+                event.event_type,
+                event.start_date,
+                event.end_date,
+                event.exp_multiplier,
+                event.memo,
+            ],
         )?;
-        Ok(())
+        Ok(self.conn.last_insert_rowid())
     }
 
-    // Item Drops
-    pub fn save_item_drop(&self, character_id: i64, date: &str, item_name: &str, price: i64, screenshot: Option<&str>) -> Result<i64> {
+    pub fn update_game_event(&self, event: &GameEvent) -> Result<()> {
         self.conn.execute(
-            "INSERT INTO item_drops (character_id, date, item_name, price, screenshot)
-             VALUES (?1, ?2, ?3, ?4, ?5)",
-            params![character_id, date, item_name, price, screenshot],
+            "UPDATE game_events SET character_id = ?1, name = ?2, event_type = ?3, start_date = ?4, end_date = ?5,
+                    exp_multiplier = ?6, memo = ?7
+             WHERE id = ?8",
+            params![
+                event.character_id,
+                event.name,
+                event.event_type,
+                event.start_date,
+                event.end_date,
+                event.exp_multiplier,
+                event.memo,
+                event.id,
+            ],
         )?;
-        Ok(self.conn.last_insert_rowid())
+        Ok(())
     }
 
-    pub fn get_item_drops(&self, character_id: i64, date: &str) -> Result<Vec<ItemDrop>> {
+    pub fn delete_game_event(&self, id: i64) -> Result<()> {
+        self.conn.execute("DELETE FROM game_events WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    // 캐릭터에 적용되는 이벤트 (공통 이벤트 포함)
+    pub fn get_game_events(&self, character_id: i64) -> Result<Vec<GameEvent>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, date, item_name, price, screenshot, created_at
-             FROM item_drops
-             WHERE character_id = ?1 AND date = ?2
-             ORDER BY price DESC"
+            "SELECT id, character_id, name, event_type, start_date, end_date, exp_multiplier, memo, created_at
+             FROM game_events
+             WHERE character_id IS NULL OR character_id = ?1
+             ORDER BY start_date, id"
         )?;
 
-        let drops = stmt.query_map(params![character_id, date], |row| {
-            Ok(ItemDrop {
-                id: row.get(0)?,
-                character_id: row.get(1)?,
-                date: row.get(2)?,
-                item_name: row.get(3)?,
-                price: row.get(4)?,
-                screenshot: row.get(5)?,
-                created_at: row.get(6)?,
-            })
+        let events = stmt.query_map(params![character_id], |row| {
+            row_to_game_event(row)
         })?.collect::<Result<Vec<_>>>()?;
 
-        Ok(drops)
+        Ok(events)
     }
 
-    pub fn update_item_drop(&self, id: i64, item_name: &str, price: i64, screenshot: Option<&str>) -> Result<()> {
-        self.conn.execute(
-            "UPDATE item_drops SET item_name = ?1, price = ?2, screenshot = ?3 WHERE id = ?4",
-            params![item_name, price, screenshot, id],
+    // Table Export (캐릭터/기간 필터는 None이면 전체)
+    pub fn get_hunting_sessions_in_range(&self, character_id: Option<i64>, start_date: Option<&str>, end_date: Option<&str>) -> Result<Vec<HuntingSession>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, date, session_order, start_level, end_level,
+                    start_exp_percent, end_exp_percent, exp_gained, start_meso, end_meso,
+                    meso_gained, duration_minutes, sojaebi,
+                    start_sol_erda, end_sol_erda, start_sol_erda_gauge, end_sol_erda_gauge, sol_erda_gained,
+                    start_sol_erda_piece, end_sol_erda_piece, sol_erda_piece_gained, sol_erda_piece_price,
+                    start_screenshot, end_screenshot, memo, created_at, updated_at, map_id
+             FROM hunting_sessions
+             WHERE (?1 IS NULL OR character_id = ?1)
+               AND (?2 IS NULL OR date >= ?2)
+               AND (?3 IS NULL OR date <= ?3)
+             ORDER BY character_id, date, session_order"
         )?;
-        Ok(())
-    }
 
-    pub fn delete_item_drop(&self, id: i64) -> Result<()> {
-        self.conn.execute("DELETE FROM item_drops WHERE id = ?1", params![id])?;
-        Ok(())
+        let mut sessions = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            row_to_hunting_session(row)
+        })?.collect::<Result<Vec<_>>>()?;
+        self.load_session_consumables(&mut sessions)?;
+
+        Ok(sessions)
     }
 
-    pub fn get_monthly_item_drops(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<ItemDrop>> {
-        let start_date = format!("{:04}-{:02}-01", year, month);
-        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
+    pub fn get_boss_clears_in_range(&self, character_id: Option<i64>, start_date: Option<&str>, end_date: Option<&str>) -> Result<Vec<BossClear>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, character_id, boss_id, difficulty, cleared_date, week_start_date,
+                    crystal_price, party_size, memo, created_at
+             FROM boss_clears
+             WHERE (?1 IS NULL OR character_id = ?1)
+               AND (?2 IS NULL OR cleared_date >= ?2)
+               AND (?3 IS NULL OR cleared_date <= ?3)
+             ORDER BY character_id, cleared_date, id"
+        )?;
+
+        let clears = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            row_to_boss_clear(row)
+        })?.collect::<Result<Vec<_>>>()?;
+
+        Ok(clears)
+    }
 
+    pub fn get_item_drops_in_range(&self, character_id: Option<i64>, start_date: Option<&str>, end_date: Option<&str>) -> Result<Vec<ItemDrop>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, character_id, date, item_name, price, screenshot, created_at
+            "SELECT id, character_id, date, item_name, price, screenshot,
+                    sale_status, sale_price, sale_fee, sold_date, item_id, created_at
              FROM item_drops
-             WHERE character_id = ?1 AND date >= ?2 AND date <= ?3
-             ORDER BY date, price DESC"
+             WHERE (?1 IS NULL OR character_id = ?1)
+               AND (?2 IS NULL OR date >= ?2)
+               AND (?3 IS NULL OR date <= ?3)
+             ORDER BY character_id, date, id"
         )?;
 
         let drops = stmt.query_map(params![character_id, start_date, end_date], |row| {
-            Ok(ItemDrop {
-                id: row.get(0)?,
-                character_id: row.get(1)?,
-                date: row.get(2)?,
-                item_name: row.get(3)?,
-                price: row.get(4)?,
-                screenshot: row.get(5)?,
-                created_at: row.get(6)?,
-            })
+            row_to_item_drop(row)
         })?.collect::<Result<Vec<_>>>()?;
 
         Ok(drops)
     }
 
-    // 일별 합계 (조각 포함)
-    pub fn get_daily_totals_with_pieces(&self, character_id: i64, year: i32, month: i32) -> Result<Vec<(DailyTotal, i64, i64)>> {
-        let start_date = format!("{:04}-{:02}-01", year, month);
-        let end_date = format!("{:04}-{:02}-{:02}", year, month, last_day_of_month(year, month as u32));
-
+    pub fn get_exp_history_in_range(&self, character_id: Option<i64>, start_date: Option<&str>, end_date: Option<&str>) -> Result<Vec<ExpHistory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT date,
-                    SUM(exp_gained) as total_exp,
-                    SUM(meso_gained) as total_meso,
-                    SUM(sojaebi) as total_sojaebi,
-                    COUNT(*) as session_count,
-                    SUM(sol_erda_piece_gained) as total_pieces,
-                    CAST(COALESCE(AVG(sol_erda_piece_price), 6500000) AS INTEGER) as avg_piece_price
-             FROM hunting_sessions
-             WHERE character_id = ?1 AND date >= ?2 AND date <= ?3
-             GROUP BY date
-             ORDER BY date"
+            "SELECT id, character_id, date, level, exp_rate, total_exp, created_at
+             FROM exp_history
+             WHERE (?1 IS NULL OR character_id = ?1)
+               AND (?2 IS NULL OR date >= ?2)
+               AND (?3 IS NULL OR date <= ?3)
+             ORDER BY character_id, date"
         )?;
 
-        let totals = stmt.query_map(params![character_id, start_date, end_date], |row| {
-            let daily = DailyTotal {
-                date: row.get(0)?,
-                total_exp_gained: row.get(1)?,
-                total_meso_gained: row.get(2)?,
-                total_sojaebi: row.get(3)?,
-                session_count: row.get(4)?,
-            };
-            let total_pieces: i64 = row.get(5)?;
-            let avg_piece_price: i64 = row.get(6)?;
-            Ok((daily, total_pieces, avg_piece_price))
+        let history = stmt.query_map(params![character_id, start_date, end_date], |row| {
+            Ok(ExpHistory {
+                id: row.get(0)?,
+                character_id: row.get(1)?,
+                date: row.get(2)?,
+                level: row.get(3)?,
+                exp_rate: row.get(4)?,
+                total_exp: row.get(5)?,
+                created_at: row.get(6)?,
+                exp_multiplier: 1.0,
+                events: Vec::new(),
+            })
         })?.collect::<Result<Vec<_>>>()?;
 
-        Ok(totals)
+        Ok(history)
     }
 
-    // 특정 년도에서 데이터가 있는 월 목록 반환
-    pub fn get_months_with_data(&self, character_id: i64, year: i32) -> Result<Vec<i32>> {
-        let start_date = format!("{:04}-01-01", year);
-        let end_date = format!("{:04}-12-31", year);
-
+    // 캐릭터별로 date 이전 마지막 누적 경험치 (기간 첫 기록의 증가량 계산용)
+    pub fn get_last_total_exp_before(&self, character_id: Option<i64>, date: &str) -> Result<Vec<(i64, f64)>> {
         let mut stmt = self.conn.prepare(
-            "SELECT DISTINCT CAST(substr(date, 6, 2) AS INTEGER) as month FROM (
-                SELECT date FROM hunting_sessions WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
-                UNION
-                SELECT cleared_date as date FROM boss_clears WHERE character_id = ?1 AND cleared_date BETWEEN ?2 AND ?3
-                UNION
-                SELECT date FROM item_drops WHERE character_id = ?1 AND date BETWEEN ?2 AND ?3
-            ) ORDER BY month"
+            "SELECT h.character_id, h.total_exp
+             FROM exp_history h
+             WHERE (?1 IS NULL OR h.character_id = ?1)
+               AND h.date = (SELECT MAX(date) FROM exp_history WHERE character_id = h.character_id AND date < ?2)"
         )?;
 
-        let months = stmt.query_map(params![character_id, start_date, end_date], |row| {
-            row.get::<_, i32>(0)
-        })?.collect::<Result<Vec<_>>>()?;
+        let totals = stmt.query_map(params![character_id, date], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(months)
+        Ok(totals)
     }
 }
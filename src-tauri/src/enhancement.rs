@@ -0,0 +1,153 @@
+use crate::db::{CubeUsageGroup, StarforceAttemptGroup};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// 스타포스 1회 강화 비용 (메소, 현재 비용표 기준)
+// 기본 비용 = 1000 + 아이템레벨³ × (현재 성 + 1)^k / d  (100 메소 단위 반올림)
+// 할인 이벤트는 기본 비용에 적용, 파괴 방지는 15~17성에서만 기본 비용의 200% 추가
+pub fn starforce_cost(item_level: i32, star: i32, destroy_defence: bool, discount_rate: f64) -> i64 {
+    let level = item_level as f64;
+    let next = (star + 1) as f64;
+
+    let raw = match star {
+        0..=9 => 1000.0 + level.powi(3) * next / 36.0,
+        10 => 1000.0 + level.powi(3) * next.powf(2.7) / 571.0,
+        11 => 1000.0 + level.powi(3) * next.powf(2.7) / 314.0,
+        12 => 1000.0 + level.powi(3) * next.powf(2.7) / 214.0,
+        13 => 1000.0 + level.powi(3) * next.powf(2.7) / 157.0,
+        14 => 1000.0 + level.powi(3) * next.powf(2.7) / 107.0,
+        17 => 1000.0 + level.powi(3) * next.powf(2.7) / 150.0,
+        18 => 1000.0 + level.powi(3) * next.powf(2.7) / 70.0,
+        19 => 1000.0 + level.powi(3) * next.powf(2.7) / 45.0,
+        21 => 1000.0 + level.powi(3) * next.powf(2.7) / 125.0,
+        _ => 1000.0 + level.powi(3) * next.powf(2.7) / 200.0,
+    };
+    let base = (raw / 100.0).round() * 100.0;
+
+    let discounted = base * (1.0 - discount_rate.clamp(0.0, 1.0));
+    let protection = if destroy_defence && (15..=17).contains(&star) { base * 2.0 } else { 0.0 };
+
+    (discounted + protection).round() as i64
+}
+
+// Open API 강화 기록 조회 가능 시작일 (이전 날짜는 요청하지 않음)
+pub const CUBE_HISTORY_START: &str = "2022-11-25";
+pub const STARFORCE_HISTORY_START: &str = "2023-12-27";
+pub const POTENTIAL_HISTORY_START: &str = "2024-01-25";
+
+// 이벤트 목록의 할인율 문자열("30" 또는 "30%")을 0.0 ~ 1.0 비율로 변환
+pub fn parse_discount_rate(value: &str) -> f64 {
+    value
+        .trim()
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .map(|rate| rate / 100.0)
+        .unwrap_or(0.0)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CubeUsage {
+    pub cube_type: String,
+    pub count: i64,
+}
+
+// 아이템별 강화 지출 장부
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EnhancementLedgerItem {
+    pub character_name: String,
+    pub target_item: String,
+    pub item_level: Option<i32>,
+    pub starforce_attempts: i64,
+    pub starforce_successes: i64,
+    pub starforce_destroys: i64,
+    // 아이템 레벨을 알 수 없으면 None (비용 미상, 0 메소와 구분)
+    pub starforce_meso: Option<i64>,
+    pub cube_usage: Vec<CubeUsage>,
+    pub total_cube_count: i64,
+}
+
+type LedgerMap = BTreeMap<(String, String), EnhancementLedgerItem>;
+
+fn ledger_entry<'a>(
+    ledger: &'a mut LedgerMap,
+    character_name: &str,
+    target_item: &str,
+    item_levels: &HashMap<String, i32>,
+) -> &'a mut EnhancementLedgerItem {
+    ledger
+        .entry((character_name.to_string(), target_item.to_string()))
+        .or_insert_with(|| {
+            let item_level = item_levels.get(target_item).copied();
+            EnhancementLedgerItem {
+                character_name: character_name.to_string(),
+                target_item: target_item.to_string(),
+                item_level,
+                starforce_attempts: 0,
+                starforce_successes: 0,
+                starforce_destroys: 0,
+                starforce_meso: item_level.map(|_| 0),
+                cube_usage: Vec::new(),
+                total_cube_count: 0,
+            }
+        })
+}
+
+// 캐릭터+아이템 단위로 스타포스 비용과 큐브 사용량 집계
+pub fn build_ledger(
+    starforce: &[StarforceAttemptGroup],
+    cubes: &[CubeUsageGroup],
+    item_levels: &HashMap<String, i32>,
+) -> Vec<EnhancementLedgerItem> {
+    let mut ledger = LedgerMap::new();
+
+    for group in starforce {
+        let item = ledger_entry(&mut ledger, &group.character_name, &group.target_item, item_levels);
+        item.starforce_attempts += group.attempts;
+        item.starforce_successes += group.successes;
+        item.starforce_destroys += group.destroys;
+        if let (Some(level), Some(meso)) = (item.item_level, item.starforce_meso.as_mut()) {
+            let cost = starforce_cost(level, group.before_starforce_count, group.destroy_defence, group.cost_discount_rate);
+            *meso += cost * group.attempts;
+        }
+    }
+
+    for group in cubes {
+        let item = ledger_entry(&mut ledger, &group.character_name, &group.target_item, item_levels);
+        item.cube_usage.push(CubeUsage {
+            cube_type: group.cube_type.clone(),
+            count: group.count,
+        });
+        item.total_cube_count += group.count;
+    }
+
+    ledger.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starforce_cost_matches_cost_table() {
+        assert_eq!(starforce_cost(200, 0, false, 0.0), 223_200);
+        assert_eq!(starforce_cost(150, 9, false, 0.0), 938_500);
+        assert_eq!(starforce_cost(160, 15, false, 0.0), 36_514_500);
+        assert_eq!(starforce_cost(200, 17, false, 0.0), 130_688_600);
+        assert_eq!(starforce_cost(200, 18, false, 0.0), 324_061_900);
+        assert_eq!(starforce_cost(200, 21, false, 0.0), 269_601_800);
+    }
+
+    #[test]
+    fn destroy_defence_only_in_protected_range() {
+        assert_eq!(starforce_cost(160, 15, true, 0.0), 36_514_500 * 3);
+        assert_eq!(starforce_cost(200, 17, true, 0.0), 130_688_600 * 3);
+        assert_eq!(starforce_cost(200, 18, true, 0.0), 324_061_900);
+        assert_eq!(starforce_cost(200, 0, true, 0.0), 223_200);
+    }
+
+    #[test]
+    fn discount_applies_to_base_cost_only() {
+        assert_eq!(starforce_cost(200, 0, false, 0.3), 156_240);
+        assert_eq!(starforce_cost(160, 15, true, 0.3), 25_560_150 + 73_029_000);
+    }
+}
@@ -0,0 +1,70 @@
+// 메이플스토리 레벨별 필요 경험치 (src/data/expTable.ts와 동일)
+// LEVEL_EXP[level - 1] = 해당 레벨에서 다음 레벨로 가기 위한 경험치
+const LEVEL_EXP: [i64; 299] = [
+    15, 34, 57, 92, 135, // 1~5
+    372, 560, 840, 1242, 1242, // 6~10
+    1242, 1242, 1242, 1242, 1490, // 11~15
+    1788, 2145, 2574, 3088, 3705, // 16~20
+    4446, 5335, 6402, 7682, 9218, // 21~25
+    11061, 13273, 15927, 19112, 19112, // 26~30
+    19112, 19112, 19112, 19112, 22934, // 31~35
+    27520, 33024, 39628, 47553, 51357, // 36~40
+    55465, 59902, 64694, 69869, 75458, // 41~45
+    81494, 88013, 95054, 102658, 110870, // 46~50
+    119739, 129318, 139663, 150836, 162902, // 51~55
+    175934, 190008, 205208, 221624, 221624, // 56~60
+    221624, 221624, 221624, 221624, 238245, // 61~65
+    256113, 275321, 295970, 318167, 342029, // 66~70
+    367681, 395257, 424901, 456768, 488741, // 71~75
+    522952, 559558, 598727, 640637, 685481, // 76~80
+    733464, 784806, 839742, 898523, 961419, // 81~85
+    1028718, 1100728, 1177778, 1260222, 1342136, // 86~90
+    1429374, 1522283, 1621231, 1726611, 1838840, // 91~95
+    1958364, 2085657, 2221224, 2365603, 2365603, // 96~100
+    2365603, 2365603, 2365603, 2365603, 2519367, // 101~105
+    2683125, 2857528, 3043267, 3241079, 3451749, // 106~110
+    3676112, 3915059, 4169537, 4440556, 4729192, // 111~115
+    5036589, 5363967, 5712624, 6083944, 6479400, // 116~120
+    6900561, 7349097, 7826788, 8335529, 8877338, // 121~125
+    9454364, 10068897, 10723375, 11420394, 12162719, // 126~130
+    12953295, 13795259, 14691950, 15646926, 16663976, // 131~135
+    17747134, 18900697, 20129242, 21437642, 22777494, // 136~140
+    24201087, 25713654, 27320757, 29028304, 30842573, // 141~145
+    32770233, 34818372, 36994520, 39306677, 41763344, // 146~150
+    44373553, 47146900, 50093581, 53224429, 56550955, // 151~155
+    60085389, 63840725, 67830770, 72070193, 76574580, // 156~160
+    81360491, 86445521, 91848366, 97588888, 103688193, // 161~165
+    110168705, 117054249, 124370139, 132143272, 138750435, // 166~170
+    145687956, 152972353, 160620970, 168652018, 177084618, // 171~175
+    185938848, 195235790, 204997579, 215247457, 226009829, // 176~180
+    237310320, 249175836, 261634627, 274716358, 288452175, // 181~185
+    302874783, 318018522, 333919448, 350615420, 368146191, // 186~190
+    386553500, 405881175, 426175233, 447483994, 469858193, // 191~195
+    493351102, 518018657, 543919589, 571115568, 2207026470, // 196~200
+    2471869646, 2768494003, 3100713283, 3472798876, 3889534741, // 201~205
+    4356278909, 4879032378, 5464516263, 6120258214, 7956335678, // 206~210
+    8831532602, 9803001188, 10881331318, 12078277762, 15701761090, // 211~215
+    17114919588, 18655262350, 20334235961, 22164317197, 28813612356, // 216~220
+    30830565220, 32988704785, 35297914119, 37768768107, 49099398539, // 221~225
+    52536356436, 56213901386, 60148874483, 64359295696, 83667084404, // 226~230
+    86177096936, 88762409844, 91425282139, 94168040603, 122418452783, // 231~235
+    126091006366, 129873736556, 133769948652, 137783047111, 179117961244, // 236~240
+    184491500081, 190026245083, 195727032435, 201598843408, 262078496430, // 241~245
+    269940851322, 278039076861, 286380249166, 294971656640, 442457484960, // 246~250
+    455731209508, 469403145793, 483485240166, 497989797370, 512929491291, // 251~255
+    528317376029, 544166897309, 560491904228, 577306661354, 1731919984062, // 256~260
+    1749239183902, 1766731575741, 1784398891498, 1802242880412, 2342915744535, // 261~265
+    2366344901980, 2390008350999, 2413908434508, 2438047518853, 5412465491853, // 266~270
+    5466590146771, 5521256048238, 5576468608720, 5632233294807, 11377111255510, // 271~275
+    12514822381061, 13766304619167, 15142935081083, 16657228589191, 33647601750165, // 276~280
+    37012361925181, 40713598117699, 44784957929468, 49263453722414, 99512176519276, // 281~285
+    109463394171203, 120409733588323, 132450706947155, 145695777641870, 294305470836577, // 286~290
+    323736017920234, 356109619712257, 391720581683482, 430892639851830, 870403132500696, // 291~295
+    957443445750765, 1053187790325841, 1158506569358425, 1737759854037637, // 296~299
+];
+
+// 해당 레벨에서 다음 레벨까지 필요한 경험치 (300레벨은 None)
+pub fn required_exp(level: i32) -> Option<f64> {
+    let index = usize::try_from(level).ok()?.checked_sub(1)?;
+    LEVEL_EXP.get(index).map(|&exp| exp as f64)
+}
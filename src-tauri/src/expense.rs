@@ -0,0 +1,76 @@
+use crate::db::{Database, DailyMesoFlow};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// 지출 카테고리 (스타포스, 큐브, 환생의 불꽃, 심볼, 경매장 구매, 수수료, 기타)
+pub const EXPENSE_CATEGORIES: [&str; 7] = ["starforce", "cube", "flame", "symbol", "auction", "fee", "etc"];
+
+pub fn is_valid_category(category: &str) -> bool {
+    EXPENSE_CATEGORIES.contains(&category)
+}
+
+// 경매장 판매 수수료 (메소 단위 내림)
+pub fn auction_fee(sale_price: i64, fee_rate: f64) -> i64 {
+    (sale_price as f64 * fee_rate.clamp(0.0, 1.0)).floor() as i64
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FlowPeriod {
+    Daily,
+    Weekly, // 목요일 시작
+    Monthly,
+}
+
+impl FlowPeriod {
+    // 날짜가 속한 구간의 시작일
+    fn period_start(self, date: &str) -> String {
+        match self {
+            Self::Daily => date.to_string(),
+            Self::Weekly => Database::get_week_start_date(date),
+            Self::Monthly => Database::get_month_start_date(date),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MesoFlow {
+    pub period_start: String,
+    pub hunting_meso: i64,
+    pub crystal_income: i64,
+    pub item_drop_income: i64,
+    pub total_income: i64,
+    pub expense: i64,
+    pub net_meso: i64, // 지출 차감 후 순수익
+}
+
+// 일별 수입/지출을 기간 단위로 합산
+pub fn aggregate_flow(daily: &[DailyMesoFlow], period: FlowPeriod) -> Vec<MesoFlow> {
+    let mut buckets: BTreeMap<String, MesoFlow> = BTreeMap::new();
+
+    for day in daily {
+        let start = period.period_start(&day.date);
+        let flow = buckets.entry(start.clone()).or_insert_with(|| MesoFlow {
+            period_start: start,
+            hunting_meso: 0,
+            crystal_income: 0,
+            item_drop_income: 0,
+            total_income: 0,
+            expense: 0,
+            net_meso: 0,
+        });
+        flow.hunting_meso += day.hunting_meso;
+        flow.crystal_income += day.crystal_income;
+        flow.item_drop_income += day.item_drop_income;
+        flow.expense += day.expense;
+    }
+
+    buckets
+        .into_values()
+        .map(|mut flow| {
+            flow.total_income = flow.hunting_meso + flow.crystal_income + flow.item_drop_income;
+            flow.net_meso = flow.total_income - flow.expense;
+            flow
+        })
+        .collect()
+}
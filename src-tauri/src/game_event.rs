@@ -0,0 +1,81 @@
+use crate::db::{DailyTotal, ExpHistory, GameEvent};
+use serde::{Deserialize, Serialize};
+
+// 이벤트 종류 (하이퍼 버닝, 테라 버닝, 경험치 이벤트, 썬데이 메이플, 기타)
+pub const EVENT_TYPES: [&str; 5] = ["hyper_burning", "tera_burning", "exp_event", "sunday_maple", "etc"];
+
+pub fn is_valid_event_type(event_type: &str) -> bool {
+    EVENT_TYPES.contains(&event_type)
+}
+
+// 기본 이벤트 프리셋 (이름, 종류, 경험치 배율)
+// 버닝은 레벨업 시 추가 레벨을 주므로 레벨 환산 경험치 기준 근사 배율, 실제 값은 사용자가 수정
+pub const EVENT_PRESETS: &[(&str, &str, f64)] = &[
+    ("하이퍼 버닝", "hyper_burning", 3.0),
+    ("테라 버닝", "tera_burning", 2.0),
+    ("경험치 이벤트", "exp_event", 1.5),
+    ("썬데이 메이플", "sunday_maple", 1.5),
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EventPreset {
+    pub name: String,
+    pub event_type: String,
+    pub exp_multiplier: f64,
+}
+
+pub fn presets() -> Vec<EventPreset> {
+    EVENT_PRESETS
+        .iter()
+        .map(|(name, event_type, exp_multiplier)| EventPreset {
+            name: name.to_string(),
+            event_type: event_type.to_string(),
+            exp_multiplier: *exp_multiplier,
+        })
+        .collect()
+}
+
+// 날짜별 적용 이벤트 조회 (날짜는 YYYY-MM-DD 문자열 비교)
+pub struct EventCalendar {
+    events: Vec<GameEvent>,
+}
+
+impl EventCalendar {
+    pub fn new(events: Vec<GameEvent>) -> Self {
+        Self { events }
+    }
+
+    pub fn events_on<'a>(&'a self, date: &'a str) -> impl Iterator<Item = &'a GameEvent> {
+        self.events
+            .iter()
+            .filter(move |e| e.start_date.as_str() <= date && date <= e.end_date.as_str())
+    }
+
+    pub fn event_names(&self, date: &str) -> Vec<String> {
+        self.events_on(date).map(|e| e.name.clone()).collect()
+    }
+
+    // 겹치는 이벤트는 배율을 곱해서 적용
+    pub fn exp_multiplier(&self, date: &str) -> f64 {
+        self.events_on(date).map(|e| e.exp_multiplier).product()
+    }
+}
+
+// 일별 합계에 이벤트 표시, normalize면 경험치를 배율로 나눠 평상시 기준으로 환산
+pub fn annotate_daily_totals(totals: &mut [DailyTotal], calendar: &EventCalendar, normalize: bool) {
+    for total in totals.iter_mut() {
+        total.exp_multiplier = calendar.exp_multiplier(&total.date);
+        total.events = calendar.event_names(&total.date);
+        if normalize && total.exp_multiplier > 0.0 {
+            total.total_exp_gained /= total.exp_multiplier;
+        }
+    }
+}
+
+// 경험치 기록은 누적값이라 환산하지 않고 이벤트 기간만 표시
+pub fn annotate_exp_history(history: &mut [ExpHistory], calendar: &EventCalendar) {
+    for entry in history.iter_mut() {
+        entry.exp_multiplier = calendar.exp_multiplier(&entry.date);
+        entry.events = calendar.event_names(&entry.date);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::db::SolErdaRate;
+use serde::{Deserialize, Serialize};
+
+// HEXA 코어 레벨별 강화 비용 (솔 에르다, 솔 에르다 조각)
+// 인덱스 0 = 0→1레벨(해방), 인덱스 29 = 29→30레벨
+const ORIGIN_CORE_COST: [(i32, i32); 30] = [
+    (5, 100), (1, 30), (1, 35), (1, 40), (2, 45), (2, 50), (2, 55), (3, 60), (3, 65), (10, 200),
+    (3, 80), (3, 90), (4, 100), (4, 110), (4, 120), (4, 130), (4, 140), (4, 150), (5, 160), (15, 350),
+    (5, 170), (5, 180), (5, 190), (5, 200), (5, 210), (6, 220), (6, 230), (6, 240), (7, 250), (20, 500),
+];
+
+const MASTERY_CORE_COST: [(i32, i32); 30] = [
+    (3, 50), (1, 15), (1, 18), (1, 20), (1, 23), (1, 25), (1, 28), (2, 30), (2, 33), (5, 100),
+    (2, 40), (2, 45), (2, 50), (2, 55), (2, 60), (2, 65), (2, 70), (2, 75), (3, 80), (8, 175),
+    (3, 85), (3, 90), (3, 95), (3, 100), (3, 105), (3, 110), (3, 115), (4, 120), (4, 125), (10, 250),
+];
+
+const ENHANCEMENT_CORE_COST: [(i32, i32); 30] = [
+    (4, 75), (1, 23), (1, 27), (1, 30), (1, 34), (1, 38), (2, 42), (2, 45), (2, 49), (8, 150),
+    (3, 60), (3, 68), (3, 75), (3, 83), (3, 90), (3, 98), (3, 105), (3, 113), (4, 120), (12, 263),
+    (4, 128), (4, 135), (4, 143), (4, 150), (4, 158), (5, 165), (5, 173), (5, 180), (6, 188), (15, 375),
+];
+
+pub const HEXA_CORE_MAX_LEVEL: i32 = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HexaLevelCost {
+    pub level: i32, // 도달 레벨
+    pub sol_erda: i32,
+    pub sol_erda_piece: i32,
+}
+
+// API의 hexa_core_type 문자열로 비용표 선택
+fn cost_table(core_type: &str) -> Option<&'static [(i32, i32); 30]> {
+    match core_type {
+        "스킬 코어" => Some(&ORIGIN_CORE_COST),
+        "마스터리 코어" => Some(&MASTERY_CORE_COST),
+        "강화 코어" => Some(&ENHANCEMENT_CORE_COST),
+        _ => None,
+    }
+}
+
+// 비용표가 있는 코어 종류인지 (공용 코어 등은 제외)
+pub fn has_cost_table(core_type: &str) -> bool {
+    cost_table(core_type).is_some()
+}
+
+// 코어 종류별 전체 비용표
+pub fn get_cost_table(core_type: &str) -> Vec<HexaLevelCost> {
+    cost_table(core_type)
+        .map(|table| {
+            table
+                .iter()
+                .enumerate()
+                .map(|(i, &(sol_erda, sol_erda_piece))| HexaLevelCost {
+                    level: i as i32 + 1,
+                    sol_erda,
+                    sol_erda_piece,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// 현재 레벨에서 다음 레벨까지의 비용 (만렙이거나 알 수 없는 코어면 None)
+pub fn next_level_cost(core_type: &str, current_level: i32) -> Option<HexaLevelCost> {
+    if !(0..HEXA_CORE_MAX_LEVEL).contains(&current_level) {
+        return None;
+    }
+    let (sol_erda, sol_erda_piece) = cost_table(core_type)?[current_level as usize];
+    Some(HexaLevelCost {
+        level: current_level + 1,
+        sol_erda,
+        sol_erda_piece,
+    })
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HexaCorePlan {
+    pub hexa_core_name: String,
+    pub hexa_core_type: String,
+    pub current_level: i32,
+    pub next_level_cost: Option<HexaLevelCost>,
+    pub missing_sol_erda: f64,
+    pub missing_sol_erda_piece: i64,
+    pub sol_erda_per_hour: f64,
+    pub pieces_per_hour: f64,
+    // 다음 레벨까지 필요한 사냥 시간 (획득 기록이 없어 계산 불가하면 None)
+    pub hours_needed: Option<f64>,
+}
+
+pub fn plan_core(core_name: &str, core_type: &str, current_level: i32, rate: &SolErdaRate) -> HexaCorePlan {
+    let cost = next_level_cost(core_type, current_level);
+    let sol_erda_per_hour = rate.sol_erda_per_hour();
+    let pieces_per_hour = rate.pieces_per_hour();
+
+    let (missing_sol_erda, missing_sol_erda_piece) = match &cost {
+        Some(c) => (
+            (c.sol_erda as f64 - rate.current_sol_erda).max(0.0),
+            (c.sol_erda_piece as i64 - rate.current_sol_erda_piece).max(0),
+        ),
+        None => (0.0, 0),
+    };
+
+    // 솔 에르다와 조각 중 더 오래 걸리는 쪽이 기준
+    let hours_for = |missing: f64, per_hour: f64| -> Option<f64> {
+        if missing <= 0.0 {
+            Some(0.0)
+        } else if per_hour > 0.0 {
+            Some(missing / per_hour)
+        } else {
+            None
+        }
+    };
+    let hours_needed = cost.as_ref().and_then(|_| {
+        let erda_hours = hours_for(missing_sol_erda, sol_erda_per_hour)?;
+        let piece_hours = hours_for(missing_sol_erda_piece as f64, pieces_per_hour)?;
+        Some(erda_hours.max(piece_hours))
+    });
+
+    HexaCorePlan {
+        hexa_core_name: core_name.to_string(),
+        hexa_core_type: core_type.to_string(),
+        current_level,
+        next_level_cost: cost,
+        missing_sol_erda,
+        missing_sol_erda_piece,
+        sol_erda_per_hour,
+        pieces_per_hour,
+        hours_needed,
+    }
+}
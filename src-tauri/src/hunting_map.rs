@@ -0,0 +1,22 @@
+// 기본 사냥터 목록 (지역, 맵)
+// 세부 맵을 나열하지 않은 지역은 지역명으로 등록하고, 나머지 맵은 사용자가 추가
+pub const SEED_MAPS: &[(&str, &[&str])] = &[
+    // 아케인리버
+    ("소멸의 여로", &["풍화된 기쁨의 땅", "풍화된 분노의 땅"]),
+    ("츄츄 아일랜드", &["츄츄 아일랜드"]),
+    ("레헬른", &["레헬른"]),
+    ("아르카나", &["동굴 윗길", "동굴 아랫길"]),
+    ("모라스", &["그림자가 춤추는 곳", "산호숲으로 가는 길"]),
+    ("에스페라", &["거울빛에 물든 바다"]),
+    ("문브릿지", &["사상의 경계"]),
+    ("고통의 미궁", &["고통의 미궁 내부", "고통의 미궁 중심부"]),
+    ("리멘", &["세계의 눈물", "세계가 끝나는 곳"]),
+    // 그란디스
+    ("세르니움", &["세르니움"]),
+    ("호텔 아르크스", &["호텔 아르크스"]),
+    ("오디움", &["오디움"]),
+    ("도원경", &["도원경"]),
+    ("아르테리아", &["아르테리아"]),
+    ("카르시온", &["카르시온"]),
+    ("탈라하트", &["탈라하트"]),
+];
@@ -0,0 +1,156 @@
+// 아이템 카테고리
+pub const ITEM_CATEGORIES: [&str; 6] = ["pitched_boss", "dawn_boss", "cube", "familiar_card", "consumable", "etc"];
+
+pub fn is_valid_category(category: &str) -> bool {
+    ITEM_CATEGORIES.contains(&category)
+}
+
+// 기본 아이템 목록 (정식 명칭, 카테고리, 별칭)
+pub const SEED_ITEMS: &[(&str, &str, &[&str])] = &[
+    // 칠흑의 보스 세트
+    ("창세의 뱃지", "pitched_boss", &["창뱃", "Genesis Badge"]),
+    ("미트라의 분노", "pitched_boss", &["미트라", "Mitra's Rage"]),
+    ("몽환의 벨트", "pitched_boss", &["몽벨", "Dreamy Belt"]),
+    ("고통의 근원", "pitched_boss", &["고근", "Source of Suffering"]),
+    ("거대한 공포", "pitched_boss", &["거공", "Endless Terror"]),
+    ("커맨더 포스 이어링", "pitched_boss", &["커포", "Commanding Force Earring"]),
+    ("루즈 컨트롤 머신 마크", "pitched_boss", &["루컨", "Loose Control Machine Mark"]),
+    ("마력이 깃든 안대", "pitched_boss", &["마깃안", "Magic Eyepatch"]),
+    ("저주받은 적의 마도서", "pitched_boss", &["적마도서", "Cursed Red Spellbook"]),
+    ("저주받은 청의 마도서", "pitched_boss", &["청마도서", "Cursed Blue Spellbook"]),
+    ("저주받은 녹의 마도서", "pitched_boss", &["녹마도서", "Cursed Green Spellbook"]),
+    ("저주받은 황의 마도서", "pitched_boss", &["황마도서", "Cursed Yellow Spellbook"]),
+    ("칠흑의 보스 상자", "pitched_boss", &["칠흑 상자", "Pitched Boss Box"]),
+    // 여명의 보스 세트
+    ("가디언 엔젤 링", "dawn_boss", &["가엔링", "Guardian Angel Ring"]),
+    ("트와일라이트 마크", "dawn_boss", &["트마", "Twilight Mark"]),
+    ("에스텔라 이어링", "dawn_boss", &["Estella Earrings"]),
+    ("데이브레이크 펜던트", "dawn_boss", &["데펜", "Daybreak Pendant"]),
+    // 큐브
+    ("수상한 큐브", "cube", &["수큐", "Occult Cube"]),
+    ("장인의 큐브", "cube", &["장큐", "Craftsman's Cube"]),
+    ("명장의 큐브", "cube", &["명큐", "Meister's Cube"]),
+    ("레드 큐브", "cube", &["레큐", "Red Cube"]),
+    ("블랙 큐브", "cube", &["블큐", "Black Cube"]),
+    ("에디셔널 큐브", "cube", &["에큐", "Bonus Potential Cube"]),
+    ("화이트 에디셔널 큐브", "cube", &["화에큐", "White Bonus Potential Cube"]),
+    // 몬스터 패밀리어
+    ("몬스터 패밀리어 카드", "familiar_card", &["패밀리어 카드", "Familiar Card"]),
+    // 소비
+    ("솔 에르다 조각", "consumable", &["조각", "Sol Erda Fragment"]),
+    ("경험치 쿠폰", "consumable", &["경쿠", "EXP Coupon"]),
+];
+
+// 비교용 이름 정규화 (대소문자, 공백, 기호 무시)
+pub fn normalize_item_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// 단어 단위로 나눈 정규화 이름 ("Pitched Boss Box" -> ["pitched", "boss", "box"])
+fn item_name_tokens(name: &str) -> Vec<String> {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+// 글자 단위 편집 거리
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+// 허용 오타 수 (짧은 이름은 오타 허용 안 함)
+fn typo_tolerance(len: usize) -> usize {
+    if len >= 4 { 1 } else { 0 }
+}
+
+// 가운데 단어만 생략한 이름인지 ("pitched box" -> "Pitched Boss Box")
+// 첫 단어와 마지막 단어(아이템 종류)가 같아야 하므로 "솔 에르다" -> "솔 에르다 조각"은 해당 안 됨
+fn is_abbreviation(tokens: &[String], candidate_tokens: &[String]) -> bool {
+    if tokens.len() < 2 || tokens.len() >= candidate_tokens.len() {
+        return false;
+    }
+    if tokens.first() != candidate_tokens.first() || tokens.last() != candidate_tokens.last() {
+        return false;
+    }
+    let mut rest = candidate_tokens.iter();
+    tokens.iter().all(|token| rest.any(|c| c == token))
+}
+
+// 자동완성 제안용 유사 아이템 (후보: 아이템 id, 이름/별칭)
+// 단어 생략 또는 한 글자 오타만 제안하며, 자동으로 연결하지는 않음
+pub fn find_similar_items(name: &str, candidates: &[(i64, String)]) -> Vec<i64> {
+    let normalized = normalize_item_name(name);
+    if normalized.is_empty() {
+        return Vec::new();
+    }
+    let tokens = item_name_tokens(name);
+    let tolerance = typo_tolerance(normalized.chars().count());
+
+    let mut matches: Vec<(usize, i64)> = candidates.iter()
+        .filter_map(|(id, candidate)| {
+            let candidate_normalized = normalize_item_name(candidate);
+            if candidate_normalized == normalized {
+                return None;
+            }
+            if is_abbreviation(&tokens, &item_name_tokens(candidate)) {
+                return Some((0, *id));
+            }
+            let distance = edit_distance(&normalized, &candidate_normalized);
+            (distance <= tolerance).then_some((distance, *id))
+        })
+        .collect();
+    matches.sort_unstable();
+    let mut ids: Vec<i64> = Vec::new();
+    for (_, id) in matches {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(names: &[&str]) -> Vec<(i64, String)> {
+        names.iter().enumerate().map(|(i, name)| (i as i64 + 1, name.to_string())).collect()
+    }
+
+    #[test]
+    fn suggests_abbreviations_and_typos() {
+        let items = candidates(&["Pitched Boss Box", "창세의 뱃지", "Red Cube"]);
+        assert_eq!(find_similar_items("pitched box", &items), vec![1]);
+        assert_eq!(find_similar_items("창세의 벳지", &items), vec![2]);
+        assert_eq!(find_similar_items("Red Cub", &items), vec![3]);
+    }
+
+    #[test]
+    fn does_not_suggest_different_items() {
+        let items = candidates(&["솔 에르다 조각", "몽환의 벨트", "아케인셰이드 숄더"]);
+        assert!(find_similar_items("솔 에르다", &items).is_empty());
+        assert!(find_similar_items("벨트", &items).is_empty());
+        assert!(find_similar_items("아케인셰이드 슈즈", &items).is_empty());
+    }
+
+    #[test]
+    fn ignores_exact_and_empty_names() {
+        let items = candidates(&["Red Cube"]);
+        assert!(find_similar_items("red cube", &items).is_empty());
+        assert!(find_similar_items(" !! ", &items).is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use rusqlite::types::Value;
+use serde::{Deserialize, Serialize};
+
+// 변경 기록 대상 테이블 (id INTEGER PRIMARY KEY 필요)
+// item_aliases는 id가 없어 제외하므로 별칭 추가와 아이템 병합은 실행 취소할 수 없음
+pub const JOURNAL_TABLES: [&str; 10] = [
+    "hunting_sessions",
+    "session_consumables",
+    "item_drops",
+    "boss_clears",
+    "boss_settings",
+    "meso_expenses",
+    "game_events",
+    "item_catalog",
+    "item_price_history",
+    "sol_erda_piece_prices",
+];
+
+pub const DEFAULT_TRASH_RETENTION_DAYS: i32 = 30;
+
+// 사용자 작업 종류
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Create,
+    Update,
+    Delete, // 삭제 작업은 휴지통에 표시
+}
+
+impl OperationKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Create, Self::Update, Self::Delete].into_iter().find(|k| k.as_str() == value)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JournalOperation {
+    pub id: i64,
+    pub kind: OperationKind,
+    pub table_name: String, // 주 대상 테이블
+    pub label: String,      // "사냥 기록 삭제" 등 화면 표시용
+    pub undone: bool,       // 실행 취소됨 (다시 실행 가능)
+    pub created_at: String,
+}
+
+// 트리거가 기록한 행 하나의 변경 (insert/update/delete, 변경 전/후 행을 JSON으로)
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    pub table_name: String,
+    pub row_id: i64,
+    pub action: String,
+    pub before_json: Option<String>,
+    pub after_json: Option<String>,
+}
+
+// 지금 실행 취소/다시 실행하면 적용될 작업
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JournalState {
+    pub undo: Option<JournalOperation>,
+    pub redo: Option<JournalOperation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrashItem {
+    pub operation_id: i64,
+    pub table_name: String,
+    pub label: String,
+    pub row: serde_json::Value, // 삭제 전 행 (주 대상 테이블)
+    pub related_rows: i64,      // 함께 삭제된 행 수 (사냥 기록의 소모품 등)
+    pub deleted_at: String,
+    pub expires_at: String, // 이 시각 이후 완전히 삭제됨
+}
+
+// 트리거가 json_object로 저장한 값을 다시 SQLite 값으로
+pub fn to_sql_value(value: &serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Integer(*b as i64),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Real(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => Value::Text(s.clone()),
+        other => Value::Text(other.to_string()),
+    }
+}
+
+pub fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
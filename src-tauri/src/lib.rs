@@ -1,17 +1,40 @@
+mod backup;
 mod db;
+mod enhancement;
+mod exp_table;
+mod expense;
+mod game_event;
 mod api;
 mod commands;
+mod consumable;
+mod hexa;
+mod hunting_map;
+mod item_catalog;
+mod journal;
+mod metrics;
+mod profile;
+mod search;
+mod secret;
+mod session_import;
+mod session_revision;
+mod symbol;
+mod table_export;
+mod xlsx_report;
 
 use std::sync::Mutex;
+use std::time::Duration;
 use db::Database;
+use tauri::Manager;
 
 pub struct AppState {
     pub db: Mutex<Database>,
+    pub startup_error: Option<String>, // 활성 프로필 대신 기본 프로필로 시작한 이유
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let database = Database::new().expect("Failed to initialize database");
+    // 시작 백업은 Database::open에서 마이그레이션 전에 생성
+    let (database, startup_error) = profile::open_active_or_default().expect("Failed to initialize database");
 
     tauri::Builder::default()
         .plugin(tauri_plugin_window_state::Builder::new().build())
@@ -22,10 +45,30 @@ pub fn run() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(AppState {
             db: Mutex::new(database),
+            startup_error,
+        })
+        .setup(|app| {
+            // 앱을 켜 둔 채 날짜가 바뀌면 일일 백업
+            let handle = app.handle().clone();
+            std::thread::spawn(move || loop {
+                std::thread::sleep(Duration::from_secs(60 * 60));
+                let state = handle.state::<AppState>();
+                if let Ok(db) = state.db.lock() {
+                    backup::ensure_daily_backup(&db).ok();
+                }
+            });
+            Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             commands::get_settings,
+            commands::get_startup_error,
             commands::save_api_key,
+            commands::get_api_keys,
+            commands::get_api_key_characters,
+            commands::refresh_api_key_characters,
+            commands::delete_api_key,
+            commands::unlock_api_key,
+            commands::set_api_key_passphrase,
             commands::get_character,
             commands::register_character,
             commands::search_character,
@@ -35,13 +78,30 @@ pub fn run() {
             commands::get_hunting_sessions,
             commands::save_hunting_session,
             commands::update_hunting_session,
+            commands::set_hunting_session_map,
             commands::delete_hunting_session,
+            commands::get_hunting_session_revisions,
+            commands::revert_hunting_session_revision,
             commands::get_daily_totals,
             commands::get_exp_history,
             commands::get_weekly_exp_from_api,
             commands::export_data,
+            commands::export_csv,
+            commands::export_xlsx_report,
             commands::import_data,
             commands::reset_data,
+            // Backup Commands
+            commands::list_backups,
+            commands::create_backup,
+            commands::restore_backup,
+            commands::save_backup_keep_count,
+            // Profile Commands
+            commands::get_profiles,
+            commands::create_profile,
+            commands::switch_profile,
+            commands::delete_profile,
+            commands::get_database_path,
+            commands::set_database_path,
             commands::get_boss_settings,
             commands::save_boss_setting,
             commands::delete_boss_setting,
@@ -59,14 +119,92 @@ pub fn run() {
             commands::get_app_settings,
             commands::save_app_settings,
             commands::save_screenshot_folder_path,
+            commands::save_auction_fee_rate,
             commands::get_daily_totals_with_pieces,
+            // Sol Erda Piece Price Commands
+            commands::save_sol_erda_piece_price,
+            commands::delete_sol_erda_piece_price,
+            commands::get_sol_erda_piece_price_history,
+            commands::get_sol_erda_piece_price,
             // Item Drop Commands
             commands::save_item_drop,
             commands::get_item_drops,
             commands::update_item_drop,
+            commands::update_item_drop_sale,
             commands::delete_item_drop,
             commands::get_monthly_item_drops,
+            commands::get_monthly_drop_summary,
             commands::get_months_with_data,
+            // Item Catalog Commands
+            commands::search_items,
+            commands::save_catalog_item,
+            commands::update_catalog_item_category,
+            commands::add_item_alias,
+            commands::merge_catalog_items,
+            // Item Price Commands
+            commands::save_item_price,
+            commands::import_item_prices,
+            commands::delete_item_price,
+            commands::get_item_price_trend,
+            commands::get_held_item_valuation,
+            // HEXA Matrix Commands
+            commands::sync_hexa_matrix,
+            commands::get_hexa_matrix_stat,
+            commands::get_hexa_skill_history,
+            commands::get_hexa_cost_table,
+            commands::plan_hexa_cores,
+            // Symbol Commands
+            commands::sync_symbols,
+            commands::get_symbol_progress_history,
+            commands::set_symbol_daily_quest,
+            commands::get_symbol_daily_quests,
+            commands::project_symbols,
+            // Union Commands
+            commands::sync_union,
+            commands::get_union_history,
+            commands::get_union_member_status,
+            // Guild / Ranking Commands
+            commands::search_guild,
+            commands::sync_rankings,
+            commands::get_ranking_progress,
+            // Enhancement History Commands
+            commands::import_enhancement_history,
+            commands::get_enhancement_ledger,
+            // Meso Expense Commands
+            commands::save_meso_expense,
+            commands::update_meso_expense,
+            commands::delete_meso_expense,
+            commands::get_meso_expenses,
+            commands::get_meso_flow,
+            // Hunting Metrics Commands
+            commands::get_session_metrics,
+            commands::get_daily_metrics,
+            commands::get_buff_combination_metrics,
+            // Hunting Map Commands
+            commands::get_hunting_maps,
+            commands::save_hunting_map,
+            commands::get_map_metrics,
+            // Game Event Commands
+            commands::get_event_presets,
+            commands::get_game_events,
+            commands::save_game_event,
+            commands::update_game_event,
+            commands::delete_game_event,
+            commands::get_event_exp_comparison,
+            // Spreadsheet Import Commands
+            commands::read_import_spreadsheet,
+            commands::preview_hunting_session_import,
+            commands::import_hunting_sessions,
+            // Search Commands
+            commands::search_diary,
+            // Undo / Trash Commands
+            commands::get_journal_state,
+            commands::undo,
+            commands::redo,
+            commands::get_trash,
+            commands::restore_trash,
+            commands::purge_trash,
+            commands::save_trash_retention_days,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
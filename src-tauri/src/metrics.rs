@@ -0,0 +1,376 @@
+use crate::consumable::{self, ConsumableType};
+use crate::db::{HuntingMap, HuntingSession};
+use crate::exp_table::required_exp;
+use crate::game_event::EventCalendar;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// 레벨/경험치% 구간을 절대 경험치로 환산
+pub fn absolute_exp_gained(start_level: i32, start_percent: f64, end_level: i32, end_percent: f64) -> Option<f64> {
+    if end_level < start_level {
+        return None;
+    }
+    let mut total = 0.0;
+    for level in start_level..=end_level {
+        let from = if level == start_level { start_percent } else { 0.0 };
+        let to = if level == end_level { end_percent } else { 100.0 };
+        if to > from {
+            total += required_exp(level)? * (to - from) / 100.0;
+        }
+    }
+    Some(total)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RateMetrics {
+    pub duration_minutes: i32,
+    pub exp_percent_per_hour: f64,
+    pub exp_per_hour: Option<f64>, // 절대 경험치 (환산된 기록의 사냥 시간 기준, 환산된 기록이 없으면 None)
+    pub meso_per_hour: f64,
+    pub pieces_per_hour: f64,
+    pub drop_value: i64,
+    // 메소 환산 수익 = 메소 + 조각 × 조각 가격 + 득템
+    pub meso_equivalent: i64,
+    pub meso_equivalent_per_hour: f64,
+    // 버프/쿠폰 비용 차감 후 순수익
+    pub consumable_cost: i64,
+    pub net_profit: i64,
+    pub net_profit_per_hour: f64,
+}
+
+// 캐릭터 전체 기록 대비 백분위 (0 ~ 100, 높을수록 효율이 좋음)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetricPercentiles {
+    pub exp_per_hour: Option<f64>,
+    pub meso_per_hour: f64,
+    pub pieces_per_hour: f64,
+    pub meso_equivalent_per_hour: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionMetrics {
+    pub session_id: i64,
+    pub date: String,
+    pub session_order: i32,
+    #[serde(flatten)]
+    pub rates: RateMetrics,
+    pub percentiles: MetricPercentiles,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyMetrics {
+    pub date: String,
+    pub session_count: i32,
+    #[serde(flatten)]
+    pub rates: RateMetrics,
+    pub percentiles: MetricPercentiles,
+    // 이벤트 기간 표시와 평상시 기준 환산 경험치 효율
+    pub exp_multiplier: f64,
+    pub events: Vec<String>,
+    pub normalized_exp_percent_per_hour: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MapMetrics {
+    pub map_id: i64,
+    pub region: String,
+    pub map_name: String,
+    pub session_count: i32,
+    #[serde(flatten)]
+    pub rates: RateMetrics,
+}
+
+// 이벤트 기간 안/밖 효율 비교
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EventExpComparison {
+    pub event_session_count: i32,
+    pub event_rates: RateMetrics,
+    pub normal_session_count: i32,
+    pub normal_rates: RateMetrics,
+    // 이벤트 기간 경험치를 배율로 나눠 평상시 기준으로 환산한 효율
+    pub normalized_event_exp_percent_per_hour: f64,
+}
+
+// 버프 조합별 효율
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BuffCombinationMetrics {
+    pub consumables: Vec<ConsumableType>, // 빈 목록 = 버프 없음
+    pub session_count: i32,
+    #[serde(flatten)]
+    pub rates: RateMetrics,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Totals {
+    minutes: i32,
+    exp_percent: f64,
+    exp: Option<f64>,
+    exp_minutes: i32, // 절대 경험치로 환산된 기록의 사냥 시간
+    meso: i64,
+    pieces: i64,
+    piece_value: i64,
+    drop_value: i64,
+    consumable_cost: i64,
+}
+
+impl Totals {
+    fn from_session(session: &HuntingSession) -> Self {
+        let exp = absolute_exp_gained(
+            session.start_level,
+            session.start_exp_percent,
+            session.end_level,
+            session.end_exp_percent,
+        );
+        Self {
+            minutes: session.duration_minutes,
+            exp_percent: session.exp_gained,
+            exp,
+            exp_minutes: if exp.is_some() { session.duration_minutes } else { 0 },
+            meso: session.meso_gained,
+            pieces: session.sol_erda_piece_gained,
+            piece_value: session.sol_erda_piece_gained * session.sol_erda_piece_price,
+            drop_value: 0,
+            consumable_cost: session.consumables.iter().map(|c| c.meso_cost).sum(),
+        }
+    }
+
+    fn add(&mut self, other: &Totals) {
+        self.minutes += other.minutes;
+        self.exp_percent += other.exp_percent;
+        // 환산 불가 기록은 건너뛰고 환산된 기록끼리만 합산
+        self.exp = match (self.exp, other.exp) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        self.exp_minutes += other.exp_minutes;
+        self.meso += other.meso;
+        self.pieces += other.pieces;
+        self.piece_value += other.piece_value;
+        self.drop_value += other.drop_value;
+        self.consumable_cost += other.consumable_cost;
+    }
+
+    fn rates(&self) -> RateMetrics {
+        let hours = self.minutes as f64 / 60.0;
+        let per_hour = |value: f64| if hours > 0.0 { value / hours } else { 0.0 };
+        let exp_hours = self.exp_minutes as f64 / 60.0;
+        let meso_equivalent = self.meso + self.piece_value + self.drop_value;
+        let net_profit = meso_equivalent - self.consumable_cost;
+
+        RateMetrics {
+            duration_minutes: self.minutes,
+            exp_percent_per_hour: per_hour(self.exp_percent),
+            exp_per_hour: self.exp.map(|exp| if exp_hours > 0.0 { exp / exp_hours } else { 0.0 }),
+            meso_per_hour: per_hour(self.meso as f64),
+            pieces_per_hour: per_hour(self.pieces as f64),
+            drop_value: self.drop_value,
+            meso_equivalent,
+            meso_equivalent_per_hour: per_hour(meso_equivalent as f64),
+            consumable_cost: self.consumable_cost,
+            net_profit,
+            net_profit_per_hour: per_hour(net_profit as f64),
+        }
+    }
+}
+
+fn percentile_rank(values: &[f64], value: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().filter(|&&v| v <= value).count() as f64 / values.len() as f64 * 100.0
+}
+
+// 사냥 시간이 있는 기록만 비교 대상
+struct Population {
+    exp_per_hour: Vec<f64>,
+    meso_per_hour: Vec<f64>,
+    pieces_per_hour: Vec<f64>,
+    meso_equivalent_per_hour: Vec<f64>,
+}
+
+impl Population {
+    fn new<'a>(rates: impl Iterator<Item = &'a RateMetrics>) -> Self {
+        let rates: Vec<&RateMetrics> = rates.filter(|r| r.duration_minutes > 0).collect();
+        Self {
+            exp_per_hour: rates.iter().filter_map(|r| r.exp_per_hour).collect(),
+            meso_per_hour: rates.iter().map(|r| r.meso_per_hour).collect(),
+            pieces_per_hour: rates.iter().map(|r| r.pieces_per_hour).collect(),
+            meso_equivalent_per_hour: rates.iter().map(|r| r.meso_equivalent_per_hour).collect(),
+        }
+    }
+
+    fn percentiles(&self, rates: &RateMetrics) -> MetricPercentiles {
+        MetricPercentiles {
+            exp_per_hour: rates.exp_per_hour.map(|v| percentile_rank(&self.exp_per_hour, v)),
+            meso_per_hour: percentile_rank(&self.meso_per_hour, rates.meso_per_hour),
+            pieces_per_hour: percentile_rank(&self.pieces_per_hour, rates.pieces_per_hour),
+            meso_equivalent_per_hour: percentile_rank(&self.meso_equivalent_per_hour, rates.meso_equivalent_per_hour),
+        }
+    }
+}
+
+// 세션별 합계 (득템은 날짜 단위 기록이라 그날 사냥 시간 비율로 나눔)
+fn session_totals(sessions: &[HuntingSession], drop_values: &HashMap<String, i64>) -> Vec<Totals> {
+    let mut day_minutes: HashMap<&str, (i32, i32)> = HashMap::new();
+    for session in sessions {
+        let entry = day_minutes.entry(session.date.as_str()).or_default();
+        entry.0 += session.duration_minutes.max(0);
+        entry.1 += 1;
+    }
+
+    sessions
+        .iter()
+        .map(|session| {
+            let mut totals = Totals::from_session(session);
+            let day_drop_value = drop_values.get(&session.date).copied().unwrap_or(0);
+            let (minutes, count) = day_minutes[session.date.as_str()];
+            let share = if minutes > 0 {
+                session.duration_minutes.max(0) as f64 / minutes as f64
+            } else {
+                1.0 / count as f64
+            };
+            totals.drop_value = (day_drop_value as f64 * share).round() as i64;
+            totals
+        })
+        .collect()
+}
+
+// 전체 사냥 기록의 세션별 효율과 백분위
+pub fn session_metrics(sessions: &[HuntingSession], drop_values: &HashMap<String, i64>) -> Vec<SessionMetrics> {
+    let rates: Vec<RateMetrics> = session_totals(sessions, drop_values)
+        .iter()
+        .map(Totals::rates)
+        .collect();
+    let population = Population::new(rates.iter());
+
+    sessions
+        .iter()
+        .zip(rates)
+        .map(|(session, rates)| SessionMetrics {
+            session_id: session.id,
+            date: session.date.clone(),
+            session_order: session.session_order,
+            percentiles: population.percentiles(&rates),
+            rates,
+        })
+        .collect()
+}
+
+// 전체 사냥 기록의 일별 효율과 백분위
+pub fn daily_metrics(sessions: &[HuntingSession], drop_values: &HashMap<String, i64>, calendar: &EventCalendar) -> Vec<DailyMetrics> {
+    let mut days: BTreeMap<&str, (i32, Totals)> = BTreeMap::new();
+    for session in sessions {
+        let (count, totals) = days
+            .entry(session.date.as_str())
+            .or_insert_with(|| (0, Totals::default()));
+        *count += 1;
+        totals.add(&Totals::from_session(session));
+    }
+    for (date, (_, totals)) in days.iter_mut() {
+        totals.drop_value = drop_values.get(*date).copied().unwrap_or(0);
+    }
+
+    let days: Vec<(&str, i32, RateMetrics)> = days
+        .into_iter()
+        .map(|(date, (count, totals))| (date, count, totals.rates()))
+        .collect();
+    let population = Population::new(days.iter().map(|(_, _, rates)| rates));
+
+    days.into_iter()
+        .map(|(date, session_count, rates)| {
+            let exp_multiplier = calendar.exp_multiplier(date);
+            DailyMetrics {
+                date: date.to_string(),
+                session_count,
+                percentiles: population.percentiles(&rates),
+                exp_multiplier,
+                events: calendar.event_names(date),
+                normalized_exp_percent_per_hour: rates.exp_percent_per_hour / exp_multiplier,
+                rates,
+            }
+        })
+        .collect()
+}
+
+// 사냥터별 효율 (사냥 시간 가중 평균, 메소 환산 효율 높은 순)
+pub fn map_metrics(sessions: &[HuntingSession], drop_values: &HashMap<String, i64>, maps: &[HuntingMap]) -> Vec<MapMetrics> {
+    let mut by_map: HashMap<i64, (i32, Totals)> = HashMap::new();
+    for (session, totals) in sessions.iter().zip(session_totals(sessions, drop_values)) {
+        if let Some(map_id) = session.map_id {
+            let (count, sum) = by_map
+                .entry(map_id)
+                .or_insert_with(|| (0, Totals::default()));
+            *count += 1;
+            sum.add(&totals);
+        }
+    }
+
+    let mut result: Vec<MapMetrics> = maps
+        .iter()
+        .filter_map(|map| {
+            let (session_count, totals) = by_map.get(&map.id)?;
+            Some(MapMetrics {
+                map_id: map.id,
+                region: map.region.clone(),
+                map_name: map.map_name.clone(),
+                session_count: *session_count,
+                rates: totals.rates(),
+            })
+        })
+        .collect();
+    result.sort_by(|a, b| b.rates.meso_equivalent_per_hour.total_cmp(&a.rates.meso_equivalent_per_hour));
+    result
+}
+
+// 버프 조합별 효율 (경험치 효율 높은 순)
+pub fn buff_combination_metrics(sessions: &[HuntingSession], drop_values: &HashMap<String, i64>) -> Vec<BuffCombinationMetrics> {
+    let mut by_combination: BTreeMap<Vec<ConsumableType>, (i32, Totals)> = BTreeMap::new();
+    for (session, totals) in sessions.iter().zip(session_totals(sessions, drop_values)) {
+        let (count, sum) = by_combination
+            .entry(consumable::combination(&session.consumables))
+            .or_insert_with(|| (0, Totals::default()));
+        *count += 1;
+        sum.add(&totals);
+    }
+
+    let mut result: Vec<BuffCombinationMetrics> = by_combination
+        .into_iter()
+        .map(|(consumables, (session_count, totals))| BuffCombinationMetrics {
+            consumables,
+            session_count,
+            rates: totals.rates(),
+        })
+        .collect();
+    result.sort_by(|a, b| {
+        b.rates.exp_per_hour.unwrap_or(0.0).total_cmp(&a.rates.exp_per_hour.unwrap_or(0.0))
+            .then(b.rates.exp_percent_per_hour.total_cmp(&a.rates.exp_percent_per_hour))
+    });
+    result
+}
+
+// 이벤트 기간 안/밖 사냥 효율 비교
+pub fn event_exp_comparison(sessions: &[HuntingSession], drop_values: &HashMap<String, i64>, calendar: &EventCalendar) -> EventExpComparison {
+    let empty = || (0, Totals::default());
+    let (mut event, mut normal) = (empty(), empty());
+    let mut normalized_event_exp_percent = 0.0;
+
+    for (session, totals) in sessions.iter().zip(session_totals(sessions, drop_values)) {
+        let (count, sum) = if calendar.events_on(&session.date).next().is_some() {
+            normalized_event_exp_percent += totals.exp_percent / calendar.exp_multiplier(&session.date);
+            &mut event
+        } else {
+            &mut normal
+        };
+        *count += 1;
+        sum.add(&totals);
+    }
+
+    let event_hours = event.1.minutes as f64 / 60.0;
+    EventExpComparison {
+        event_session_count: event.0,
+        event_rates: event.1.rates(),
+        normal_session_count: normal.0,
+        normal_rates: normal.1.rates(),
+        normalized_event_exp_percent_per_hour: if event_hours > 0.0 { normalized_event_exp_percent / event_hours } else { 0.0 },
+    }
+}
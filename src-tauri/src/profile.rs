@@ -0,0 +1,210 @@
+use crate::db::Database;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_PROFILE_NAME: &str = "기본";
+
+// 다이어리 프로필 (프로필마다 별도 DB 파일)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub db_path: String,
+    pub last_used_at: Option<String>,
+}
+
+// 데이터 폴더의 profiles.json (동기화 폴더로 DB를 옮겨도 이 파일은 로컬에 남음)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProfileConfig {
+    pub active_profile: String,
+    pub profiles: Vec<Profile>, // 최근 사용 순
+}
+
+impl Default for ProfileConfig {
+    fn default() -> Self {
+        Self {
+            active_profile: DEFAULT_PROFILE_NAME.to_string(),
+            profiles: vec![Profile {
+                name: DEFAULT_PROFILE_NAME.to_string(),
+                db_path: Database::get_default_db_path().to_string_lossy().to_string(),
+                last_used_at: None,
+            }],
+        }
+    }
+}
+
+impl ProfileConfig {
+    pub fn active(&self) -> Option<&Profile> {
+        self.find(&self.active_profile)
+    }
+
+    fn find(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    // 사용한 프로필을 맨 앞으로
+    fn touch(&mut self, name: &str) {
+        if let Some(index) = self.profiles.iter().position(|p| p.name == name) {
+            let mut profile = self.profiles.remove(index);
+            profile.last_used_at = Some(chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
+            self.profiles.insert(0, profile);
+        }
+        self.active_profile = name.to_string();
+    }
+}
+
+fn config_path() -> PathBuf {
+    Database::get_data_dir().join("profiles.json")
+}
+
+// 설정 파일이 없거나 읽을 수 없으면 기본 프로필
+pub fn load_config() -> ProfileConfig {
+    std::fs::read_to_string(config_path())
+        .ok()
+        .and_then(|json| serde_json::from_str::<ProfileConfig>(&json).ok())
+        .filter(|config| config.active().is_some())
+        .unwrap_or_default()
+}
+
+fn save_config(config: &ProfileConfig) -> Result<(), String> {
+    let path = config_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
+
+pub fn active_db_path() -> PathBuf {
+    let config = load_config();
+    config
+        .active()
+        .map(|p| PathBuf::from(&p.db_path))
+        .unwrap_or_else(Database::get_default_db_path)
+}
+
+// 시작 시 활성 프로필 DB를 열 수 없으면 (동기화 폴더/드라이브 미연결 등) 기본 프로필로 전환하고 오류를 함께 반환
+pub fn open_active_or_default() -> Result<(Database, Option<String>), String> {
+    let error = match Database::new() {
+        Ok(db) => return Ok((db, None)),
+        Err(e) => e,
+    };
+
+    let mut config = load_config();
+    let failed_profile = config.active_profile.clone();
+    let default_profile = match config.find(DEFAULT_PROFILE_NAME) {
+        Some(profile) => profile.clone(),
+        None => {
+            let profile = Profile {
+                name: DEFAULT_PROFILE_NAME.to_string(),
+                db_path: Database::get_default_db_path().to_string_lossy().to_string(),
+                last_used_at: None,
+            };
+            config.profiles.push(profile.clone());
+            profile
+        }
+    };
+    if Path::new(&default_profile.db_path) == active_db_path() {
+        return Err(format!("DB를 열 수 없습니다: {}", error));
+    }
+
+    let db = Database::open(&PathBuf::from(&default_profile.db_path))
+        .map_err(|e| format!("기본 프로필 DB를 열 수 없습니다: {}", e))?;
+    config.touch(DEFAULT_PROFILE_NAME);
+    save_config(&config)?;
+    Ok((db, Some(format!(
+        "'{}' 프로필의 DB를 열 수 없어 기본 프로필로 시작했습니다: {}",
+        failed_profile, error
+    ))))
+}
+
+// 프로필 이름으로 만든 기본 DB 경로 (파일 이름에 쓸 수 없는 문자는 _)
+fn default_profile_db_path(name: &str) -> PathBuf {
+    let file_stem: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    Database::get_data_dir().join("profiles").join(format!("{}.db", file_stem))
+}
+
+pub fn create_profile(name: &str, db_path: Option<&str>) -> Result<Profile, String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > 50 {
+        return Err("프로필 이름은 1 ~ 50자여야 합니다".to_string());
+    }
+    let mut config = load_config();
+    if config.find(name).is_some() {
+        return Err(format!("이미 있는 프로필입니다: {}", name));
+    }
+
+    let db_path = match db_path {
+        Some(path) => PathBuf::from(path),
+        None => default_profile_db_path(name),
+    };
+    if config.profiles.iter().any(|p| Path::new(&p.db_path) == db_path) {
+        return Err("다른 프로필이 사용 중인 DB 파일입니다".to_string());
+    }
+
+    let profile = Profile {
+        name: name.to_string(),
+        db_path: db_path.to_string_lossy().to_string(),
+        last_used_at: None,
+    };
+    config.profiles.push(profile.clone());
+    save_config(&config)?;
+    Ok(profile)
+}
+
+// 프로필 DB를 열고 활성 프로필로 기록 (열기에 실패하면 설정은 그대로)
+pub fn open_profile(name: &str) -> Result<Database, String> {
+    let mut config = load_config();
+    let profile = config.find(name).ok_or(format!("프로필을 찾을 수 없습니다: {}", name))?;
+    let db = Database::open(&PathBuf::from(&profile.db_path)).map_err(|e| e.to_string())?;
+    config.touch(name);
+    save_config(&config)?;
+    Ok(db)
+}
+
+// 활성 프로필이 아닌 프로필만 목록에서 삭제 (DB 파일은 남겨 둠)
+pub fn delete_profile(name: &str) -> Result<(), String> {
+    let mut config = load_config();
+    if config.active_profile == name {
+        return Err("사용 중인 프로필은 삭제할 수 없습니다".to_string());
+    }
+    let before = config.profiles.len();
+    config.profiles.retain(|p| p.name != name);
+    if config.profiles.len() == before {
+        return Err(format!("프로필을 찾을 수 없습니다: {}", name));
+    }
+    save_config(&config)
+}
+
+// 활성 프로필의 DB 위치 변경 (copy_current면 현재 데이터를 새 파일로 복사)
+pub fn move_active_database(db: &Database, new_path: &str, copy_current: bool) -> Result<Database, String> {
+    let new_path = PathBuf::from(new_path);
+    let mut config = load_config();
+    if config
+        .profiles
+        .iter()
+        .any(|p| p.name != config.active_profile && Path::new(&p.db_path) == new_path)
+    {
+        return Err("다른 프로필이 사용 중인 DB 파일입니다".to_string());
+    }
+
+    if copy_current {
+        if new_path.exists() {
+            return Err("이미 파일이 있는 위치로는 복사할 수 없습니다".to_string());
+        }
+        if let Some(parent) = new_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        db.backup_to(&new_path).map_err(|e| e.to_string())?;
+    }
+    let new_db = Database::open(&new_path).map_err(|e| e.to_string())?;
+
+    let active = config.active_profile.clone();
+    if let Some(profile) = config.profiles.iter_mut().find(|p| p.name == active) {
+        profile.db_path = new_path.to_string_lossy().to_string();
+    }
+    save_config(&config)?;
+    Ok(new_db)
+}
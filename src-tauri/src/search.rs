@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+// 검색 대상
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSource {
+    HuntingSession, // 사냥 메모
+    ItemDrop,       // 득템 아이템 이름
+    BossClear,      // 보스 이름/난이도/메모
+    Character,      // 캐릭터 이름/월드/직업
+}
+
+impl SearchSource {
+    pub const ALL: [SearchSource; 4] = [Self::HuntingSession, Self::ItemDrop, Self::BossClear, Self::Character];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::HuntingSession => "hunting_session",
+            Self::ItemDrop => "item_drop",
+            Self::BossClear => "boss_clear",
+            Self::Character => "character",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+
+    // 원본 테이블
+    pub fn table(self) -> &'static str {
+        match self {
+            Self::HuntingSession => "hunting_sessions",
+            Self::ItemDrop => "item_drops",
+            Self::BossClear => "boss_clears",
+            Self::Character => "characters",
+        }
+    }
+
+    // 인덱스에 넣을 (body, character_id, date) 식과 조건, {row}는 new/old 또는 테이블 이름
+    pub fn index_columns(self) -> (&'static str, &'static str) {
+        match self {
+            Self::HuntingSession => (
+                "{row}.memo, {row}.character_id, {row}.date",
+                "{row}.memo IS NOT NULL AND {row}.memo <> ''",
+            ),
+            // 아이템 카탈로그 별칭도 함께 ("Dreamy Belt"로 "몽환의 벨트" 검색)
+            Self::ItemDrop => (
+                "{row}.item_name || COALESCE(' ' || (SELECT group_concat(alias, ' ') FROM item_aliases WHERE item_id = {row}.item_id), ''), {row}.character_id, {row}.date",
+                "1",
+            ),
+            Self::BossClear => (
+                "{row}.boss_id || ' ' || {row}.difficulty || ' ' || COALESCE({row}.memo, ''), {row}.character_id, {row}.cleared_date",
+                "1",
+            ),
+            Self::Character => (
+                "{row}.character_name || ' ' || {row}.world_name || ' ' || {row}.character_class, {row}.id, NULL",
+                "1",
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SearchHit {
+    pub source: SearchSource,
+    pub source_id: i64,
+    pub character_id: Option<i64>,
+    pub character_name: Option<String>,
+    pub date: Option<String>,
+    pub snippet: String, // 일치한 부분은 [ ]로 표시
+    pub score: f64,      // bm25 (낮을수록 관련도 높음)
+}
+
+// 입력한 단어마다 접두사 검색, 모든 단어가 포함된 결과만 ("드림 벨" -> "드림"* "벨"*)
+pub fn build_match_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .map(|term| term.replace('"', ""))
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{}\"*", term))
+        .collect();
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
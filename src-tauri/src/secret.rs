@@ -0,0 +1,136 @@
+use crate::db::Database;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+
+// 저장된 API Key 보호 방식
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyProtection {
+    Plain,      // 이전 버전에서 저장된 평문
+    Local,      // 이 PC의 로컬 키로 암호화
+    Passphrase, // 사용자 암호로 암호화
+}
+
+// 내보내기 파일에 API Key를 넣는 방식
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyExport {
+    #[default]
+    Omit,
+    Encrypted, // 암호를 주면 암호 보호, 아니면 이 PC에서만 복원 가능
+    Plain,
+}
+
+// enc:v1:local:<nonce>:<암호문>
+// enc:v1:passphrase:<salt>:<nonce>:<암호문>
+const PREFIX: &str = "enc:v1:";
+const SALT_LEN: usize = 16;
+
+pub fn protection(stored: &str) -> KeyProtection {
+    match stored.strip_prefix(PREFIX) {
+        Some(rest) if rest.starts_with("passphrase:") => KeyProtection::Passphrase,
+        Some(_) => KeyProtection::Local,
+        None => KeyProtection::Plain,
+    }
+}
+
+// DB와 따로 두어서 DB 파일만 동기화 폴더로 옮겨도 키는 PC 밖으로 나가지 않음
+fn local_secret_path() -> PathBuf {
+    Database::get_data_dir().join("secret.key")
+}
+
+// 로컬 키 (파일이 없을 때만 새로 만듦, 읽기 오류로 기존 키를 덮어쓰지 않음)
+fn local_key() -> Result<[u8; 32], String> {
+    let path = local_secret_path();
+    match std::fs::read(&path) {
+        Ok(bytes) => return bytes.try_into().map_err(|_| "로컬 키 파일이 손상되었습니다".to_string()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(format!("로컬 키 파일을 읽을 수 없습니다: {}", e)),
+    }
+
+    let mut key = [0u8; 32];
+    OsRng.fill_bytes(&mut key);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    // 처음부터 소유자만 읽을 수 있게 생성 (다른 곳에서 먼저 만들었으면 덮어쓰지 않음)
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&path).map_err(|e| e.to_string())?;
+    file.write_all(&key).map_err(|e| e.to_string())?;
+    file.sync_all().map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+fn passphrase_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+fn decode(part: Option<&str>) -> Result<Vec<u8>, String> {
+    STANDARD
+        .decode(part.ok_or("암호화된 API Key 형식이 올바르지 않습니다")?)
+        .map_err(|e| e.to_string())
+}
+
+// passphrase가 있으면 암호 보호, 없으면 로컬 키로 암호화
+pub fn encrypt(plain: &str, passphrase: Option<&str>) -> Result<String, String> {
+    let (header, key) = match passphrase {
+        Some(passphrase) => {
+            let mut salt = [0u8; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            (format!("passphrase:{}:", STANDARD.encode(salt)), passphrase_key(passphrase, &salt)?)
+        }
+        None => ("local:".to_string(), local_key()?),
+    };
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let encrypted = cipher
+        .encrypt(&nonce, plain.as_bytes())
+        .map_err(|_| "API Key 암호화에 실패했습니다".to_string())?;
+    Ok(format!("{}{}{}:{}", PREFIX, header, STANDARD.encode(nonce), STANDARD.encode(encrypted)))
+}
+
+// 평문은 그대로 반환, 암호 보호된 값은 passphrase 필요
+pub fn decrypt(stored: &str, passphrase: Option<&str>) -> Result<String, String> {
+    let Some(rest) = stored.strip_prefix(PREFIX) else {
+        return Ok(stored.to_string());
+    };
+
+    let mut parts = rest.split(':');
+    let key = match parts.next() {
+        Some("local") => local_key()?,
+        Some("passphrase") => {
+            let passphrase = passphrase.ok_or("API Key가 암호로 잠겨 있습니다")?;
+            passphrase_key(passphrase, &decode(parts.next())?)?
+        }
+        _ => return Err("암호화된 API Key 형식이 올바르지 않습니다".to_string()),
+    };
+    let nonce = decode(parts.next())?;
+    let encrypted = decode(parts.next())?;
+    if nonce.len() != 12 {
+        return Err("암호화된 API Key 형식이 올바르지 않습니다".to_string());
+    }
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let plain = cipher
+        .decrypt(Nonce::from_slice(&nonce), encrypted.as_slice())
+        .map_err(|_| "API Key를 복호화할 수 없습니다 (암호가 다르거나 다른 PC에서 암호화됨)".to_string())?;
+    String::from_utf8(plain).map_err(|e| e.to_string())
+}
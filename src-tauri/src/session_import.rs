@@ -0,0 +1,399 @@
+use crate::db::{Database, HuntingSession, SaveHuntingSessionInput};
+use crate::item_catalog::normalize_item_name;
+use calamine::{open_workbook_auto, Data, Reader};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+// 가져올 수 있는 필드 (필드 키, 필수 여부, 자동 매핑용 열 이름)
+pub const IMPORT_FIELDS: &[(&str, bool, &[&str])] = &[
+    ("date", true, &["날짜", "일자", "date"]),
+    ("start_level", true, &["시작 레벨", "시작레벨", "start level"]),
+    ("end_level", true, &["종료 레벨", "종료레벨", "끝 레벨", "end level"]),
+    ("start_exp_percent", true, &["시작 경험치", "시작경험치", "start exp"]),
+    ("end_exp_percent", true, &["종료 경험치", "종료경험치", "끝 경험치", "end exp"]),
+    ("start_meso", true, &["시작 메소", "시작메소", "start meso"]),
+    ("end_meso", true, &["종료 메소", "종료메소", "끝 메소", "end meso"]),
+    ("duration_minutes", true, &["사냥 시간", "사냥시간", "시간", "duration", "minutes"]),
+    ("start_sol_erda", false, &["시작 솔 에르다", "시작 솔에르다"]),
+    ("end_sol_erda", false, &["종료 솔 에르다", "종료 솔에르다"]),
+    ("start_sol_erda_gauge", false, &["시작 게이지", "시작 솔 에르다 게이지"]),
+    ("end_sol_erda_gauge", false, &["종료 게이지", "종료 솔 에르다 게이지"]),
+    ("start_sol_erda_piece", false, &["시작 조각", "시작 솔 에르다 조각"]),
+    ("end_sol_erda_piece", false, &["종료 조각", "종료 솔 에르다 조각"]),
+    ("sol_erda_piece_price", false, &["조각 가격", "조각 시세", "piece price"]),
+    ("memo", false, &["메모", "비고", "memo"]),
+];
+
+// 첫 행은 열 이름, 나머지는 데이터 (모든 셀을 문자열로 통일)
+#[derive(Debug, Clone)]
+pub struct SpreadsheetTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+// 열 매핑 단계에 보여줄 미리보기
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SpreadsheetPreview {
+    pub headers: Vec<String>,
+    pub sample_rows: Vec<Vec<String>>,
+    pub row_count: usize,
+    pub suggested_mapping: HashMap<String, usize>, // 필드 키 -> 열 번호
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionImportRow {
+    pub row_number: usize, // 스프레드시트 행 번호 (열 이름 행 = 1)
+    pub session: Option<HuntingSession>,
+    pub errors: Vec<String>,
+    pub duplicate: bool, // 같은 날짜/레벨/경험치/메소 기록이 이미 있거나 앞 행과 같음
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionImportPreview {
+    pub rows: Vec<SessionImportRow>,
+    pub valid_count: usize,
+    pub error_count: usize,
+    pub duplicate_count: usize,
+}
+
+// 중복 판단 기준 (캐릭터, 날짜, 시작/종료 레벨, 경험치, 메소)
+fn same_session(a: &HuntingSession, b: &HuntingSession) -> bool {
+    a.character_id == b.character_id
+        && a.date == b.date
+        && a.start_level == b.start_level
+        && a.end_level == b.end_level
+        && a.start_exp_percent == b.start_exp_percent
+        && a.end_exp_percent == b.end_exp_percent
+        && a.start_meso == b.start_meso
+        && a.end_meso == b.end_meso
+}
+
+pub fn read_spreadsheet(path: &str) -> Result<SpreadsheetTable, String> {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+
+    let mut rows = match extension.as_str() {
+        "csv" => read_csv(path)?,
+        "xlsx" | "xlsm" | "xls" | "ods" => read_workbook(path)?,
+        _ => return Err(format!("지원하지 않는 파일 형식입니다: {}", extension)),
+    };
+    if rows.is_empty() {
+        return Err("빈 파일입니다".to_string());
+    }
+
+    let headers = rows.remove(0).into_iter().map(|h| h.trim().to_string()).collect();
+    Ok(SpreadsheetTable { headers, rows })
+}
+
+// UTF-8 CSV (BOM 허용)
+fn read_csv(path: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_path(path)
+        .map_err(|e| e.to_string())?;
+
+    let mut rows = Vec::new();
+    for record in reader.byte_records() {
+        let record = record.map_err(|e| e.to_string())?;
+        rows.push(
+            record
+                .iter()
+                .map(|field| String::from_utf8_lossy(field).trim_start_matches('\u{feff}').to_string())
+                .collect(),
+        );
+    }
+    Ok(rows)
+}
+
+// 첫 번째 시트만 읽음
+fn read_workbook(path: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut workbook = open_workbook_auto(path).map_err(|e| e.to_string())?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or("시트가 없습니다")?
+        .map_err(|e| e.to_string())?;
+
+    Ok(range.rows().map(|row| row.iter().map(cell_to_string).collect()).collect())
+}
+
+fn cell_to_string(cell: &Data) -> String {
+    match cell {
+        Data::Float(value) if value.fract() == 0.0 => format!("{}", *value as i64),
+        // 날짜 셀은 YYYY-MM-DD, 시간 형식 셀은 분 단위로 변환
+        Data::DateTime(value) if value.is_duration() || value.as_f64() < 1.0 => value
+            .as_duration()
+            .map(|d| d.num_minutes().to_string())
+            .unwrap_or_default(),
+        Data::DateTime(value) => value
+            .as_datetime()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        other => other.to_string(),
+    }
+}
+
+// 열 이름으로 필드 자동 매핑
+pub fn suggest_mapping(headers: &[String]) -> HashMap<String, usize> {
+    let normalized: Vec<String> = headers.iter().map(|h| normalize_item_name(h)).collect();
+    let mut mapping = HashMap::new();
+    for (field, _, aliases) in IMPORT_FIELDS {
+        let column = normalized.iter().position(|header| {
+            *header == normalize_item_name(field) || aliases.iter().any(|alias| *header == normalize_item_name(alias))
+        });
+        if let Some(column) = column {
+            mapping.insert(field.to_string(), column);
+        }
+    }
+    mapping
+}
+
+pub fn preview_table(table: &SpreadsheetTable, sample_size: usize) -> SpreadsheetPreview {
+    SpreadsheetPreview {
+        headers: table.headers.clone(),
+        sample_rows: table.rows.iter().take(sample_size).cloned().collect(),
+        row_count: table.rows.len(),
+        suggested_mapping: suggest_mapping(&table.headers),
+    }
+}
+
+pub fn validate_mapping(mapping: &HashMap<String, usize>, column_count: usize) -> Result<(), String> {
+    for (field, column) in mapping {
+        if !IMPORT_FIELDS.iter().any(|(key, _, _)| key == field) {
+            return Err(format!("알 수 없는 필드입니다: {}", field));
+        }
+        if *column >= column_count {
+            return Err(format!("{} 필드의 열 번호가 범위를 벗어났습니다", field));
+        }
+    }
+    for (field, required, _) in IMPORT_FIELDS {
+        if *required && !mapping.contains_key(*field) {
+            return Err(format!("필수 필드가 매핑되지 않았습니다: {}", field));
+        }
+    }
+    Ok(())
+}
+
+// "2024-01-05", "2024/1/5", "2024. 1. 5." 형식
+fn parse_date(value: &str) -> Option<String> {
+    let parts: Vec<u32> = value
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|p| !p.is_empty())
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    match parts[..] {
+        [year, month, day] => NaiveDate::from_ymd_opt(year as i32, month, day).map(|d| d.format("%Y-%m-%d").to_string()),
+        _ => None,
+    }
+}
+
+// 쉼표, 공백, % 제거
+fn parse_number(value: &str) -> Option<f64> {
+    let cleaned: String = value.chars().filter(|c| !matches!(c, ',' | ' ' | '%')).collect();
+    cleaned.parse().ok()
+}
+
+// "12억 3456만", "1.5억" 같은 한글 단위 허용
+fn parse_meso(value: &str) -> Option<i64> {
+    if !value.contains('억') && !value.contains('만') {
+        return parse_number(value).map(|v| v.round() as i64);
+    }
+    let mut total = 0.0;
+    let mut rest = value;
+    for (unit, multiplier) in [('억', 100_000_000.0), ('만', 10_000.0)] {
+        if let Some((number, tail)) = rest.split_once(unit) {
+            total += parse_number(number)? * multiplier;
+            rest = tail;
+        }
+    }
+    if !rest.trim().is_empty() {
+        total += parse_number(rest)?;
+    }
+    Some(total.round() as i64)
+}
+
+// 분 단위 숫자, "1:30"("1:30:00"), "1시간 30분", "90분"
+fn parse_duration(value: &str) -> Option<i32> {
+    if value.contains(':') {
+        let mut parts = value.split(':').map(|p| p.trim().parse::<i32>().ok());
+        return Some(parts.next()?? * 60 + parts.next()??);
+    }
+    if value.contains("시간") || value.contains('분') {
+        let (hours, rest) = value.split_once("시간").unwrap_or(("0", value));
+        let minutes = rest.trim().trim_end_matches('분');
+        let hours = parse_number(hours)?;
+        let minutes = if minutes.is_empty() { 0.0 } else { parse_number(minutes)? };
+        return Some((hours * 60.0 + minutes).round() as i32);
+    }
+    parse_number(value).map(|v| v.round() as i32)
+}
+
+struct RowReader<'a> {
+    row: &'a [String],
+    mapping: &'a HashMap<String, usize>,
+    errors: Vec<String>,
+}
+
+impl RowReader<'_> {
+    fn raw(&self, field: &str) -> Option<&str> {
+        let column = *self.mapping.get(field)?;
+        self.row.get(column).map(|v| v.trim()).filter(|v| !v.is_empty())
+    }
+
+    // 값이 없으면 필수 필드는 오류, 선택 필드는 기본값
+    fn parse<T: Default>(&mut self, field: &str, label: &str, parser: impl Fn(&str) -> Option<T>) -> T {
+        let required = IMPORT_FIELDS.iter().any(|(key, required, _)| *key == field && *required);
+        match self.raw(field) {
+            Some(value) => match parser(value) {
+                Some(parsed) => parsed,
+                None => {
+                    self.errors.push(format!("{}: '{}' 값을 읽을 수 없습니다", label, value));
+                    T::default()
+                }
+            },
+            None => {
+                if required {
+                    self.errors.push(format!("{}: 값이 없습니다", label));
+                }
+                T::default()
+            }
+        }
+    }
+}
+
+fn parse_int(value: &str) -> Option<i32> {
+    parse_number(value).map(|v| v.round() as i32)
+}
+
+// 행 하나를 세션 입력값으로 변환, 실패하면 행 단위 오류 목록
+fn parse_row(character_id: i64, row: &[String], mapping: &HashMap<String, usize>) -> Result<SaveHuntingSessionInput, Vec<String>> {
+    let mut reader = RowReader { row, mapping, errors: Vec::new() };
+
+    let date = reader.parse("date", "날짜", parse_date);
+    let start_level = reader.parse("start_level", "시작 레벨", parse_int);
+    let end_level = reader.parse("end_level", "종료 레벨", parse_int);
+    let start_exp_percent = reader.parse("start_exp_percent", "시작 경험치", parse_number);
+    let end_exp_percent = reader.parse("end_exp_percent", "종료 경험치", parse_number);
+    let start_meso = reader.parse("start_meso", "시작 메소", parse_meso);
+    let end_meso = reader.parse("end_meso", "종료 메소", parse_meso);
+    let duration_minutes = reader.parse("duration_minutes", "사냥 시간", parse_duration);
+    let start_sol_erda = reader.parse("start_sol_erda", "시작 솔 에르다", parse_int);
+    let end_sol_erda = reader.parse("end_sol_erda", "종료 솔 에르다", parse_int);
+    let start_sol_erda_gauge = reader.parse("start_sol_erda_gauge", "시작 게이지", parse_int);
+    let end_sol_erda_gauge = reader.parse("end_sol_erda_gauge", "종료 게이지", parse_int);
+    let start_sol_erda_piece = reader.parse("start_sol_erda_piece", "시작 조각", parse_meso);
+    let end_sol_erda_piece = reader.parse("end_sol_erda_piece", "종료 조각", parse_meso);
+    let sol_erda_piece_price = if reader.raw("sol_erda_piece_price").is_some() {
+        Some(reader.parse("sol_erda_piece_price", "조각 가격", parse_meso))
+    } else {
+        None
+    };
+    let memo = reader.raw("memo").map(str::to_string);
+
+    let mut errors = reader.errors;
+    if errors.is_empty() {
+        if !(1..=300).contains(&start_level) || !(1..=300).contains(&end_level) {
+            errors.push("레벨은 1 ~ 300 사이여야 합니다".to_string());
+        }
+        if end_level < start_level {
+            errors.push("종료 레벨이 시작 레벨보다 낮습니다".to_string());
+        }
+        if !(0.0..100.0).contains(&start_exp_percent) || !(0.0..100.0).contains(&end_exp_percent) {
+            errors.push("경험치는 0 ~ 100% 사이여야 합니다".to_string());
+        }
+        if start_level == end_level && end_exp_percent < start_exp_percent {
+            errors.push("종료 경험치가 시작 경험치보다 낮습니다".to_string());
+        }
+        if duration_minutes <= 0 {
+            errors.push("사냥 시간은 0분보다 길어야 합니다".to_string());
+        }
+        if !(0..=20).contains(&start_sol_erda) || !(0..=20).contains(&end_sol_erda) {
+            errors.push("솔 에르다는 0 ~ 20개 사이여야 합니다".to_string());
+        }
+        if !(0..=1000).contains(&start_sol_erda_gauge) || !(0..=1000).contains(&end_sol_erda_gauge) {
+            errors.push("솔 에르다 게이지는 0 ~ 1000 사이여야 합니다".to_string());
+        }
+        if sol_erda_piece_price.is_some_and(|price| price < 0) {
+            errors.push("조각 가격은 0 이상이어야 합니다".to_string());
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(SaveHuntingSessionInput {
+        character_id,
+        date,
+        start_level,
+        end_level,
+        start_exp_percent,
+        end_exp_percent,
+        start_meso,
+        end_meso,
+        duration_minutes,
+        start_sol_erda,
+        end_sol_erda,
+        start_sol_erda_gauge,
+        end_sol_erda_gauge,
+        start_sol_erda_piece,
+        end_sol_erda_piece,
+        sol_erda_piece_price,
+        start_screenshot: None,
+        end_screenshot: None,
+        consumables: Vec::new(),
+        memo,
+        map_id: None,
+    })
+}
+
+// 모든 행을 검증하고 세션으로 변환 (빈 행은 건너뜀)
+pub fn preview_import(
+    db: &Database,
+    character_id: i64,
+    table: &SpreadsheetTable,
+    mapping: &HashMap<String, usize>,
+) -> rusqlite::Result<SessionImportPreview> {
+    let mut rows: Vec<SessionImportRow> = Vec::new();
+    // 날짜별 기존 기록 (같은 시트를 다시 가져와도 기록이 두 배가 되지 않도록)
+    let mut existing: HashMap<String, Vec<HuntingSession>> = HashMap::new();
+    for (index, row) in table.rows.iter().enumerate() {
+        if row.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let row_number = index + 2;
+        match parse_row(character_id, row, mapping) {
+            Ok(input) => {
+                let sol_erda_piece_price = match input.sol_erda_piece_price {
+                    Some(price) => price,
+                    None => db.get_sol_erda_piece_price_on(&input.date)?,
+                };
+                let session = input.into_session(sol_erda_piece_price);
+                if !existing.contains_key(&session.date) {
+                    existing.insert(session.date.clone(), db.get_hunting_sessions(character_id, &session.date)?);
+                }
+                let duplicate = existing[&session.date].iter().any(|s| same_session(s, &session))
+                    || rows.iter().filter_map(|r| r.session.as_ref()).any(|s| same_session(s, &session));
+                rows.push(SessionImportRow {
+                    row_number,
+                    session: Some(session),
+                    errors: Vec::new(),
+                    duplicate,
+                });
+            }
+            Err(errors) => rows.push(SessionImportRow { row_number, session: None, errors, duplicate: false }),
+        }
+    }
+
+    let valid_count = rows.iter().filter(|r| r.session.is_some()).count();
+    Ok(SessionImportPreview {
+        error_count: rows.len() - valid_count,
+        duplicate_count: rows.iter().filter(|r| r.duplicate).count(),
+        valid_count,
+        rows,
+    })
+}
@@ -0,0 +1,93 @@
+use crate::db::HuntingSession;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+// 수정 이력에서 제외하는 필드 (직접 수정할 수 없거나 자동으로 바뀌는 값)
+const IGNORED_FIELDS: [&str; 4] = ["id", "character_id", "created_at", "updated_at"];
+
+// 입력값에서 계산되는 필드 (HuntingSession::derive_gains)
+const DERIVED_FIELDS: [&str; 5] = ["exp_gained", "meso_gained", "sojaebi", "sol_erda_gained", "sol_erda_piece_gained"];
+
+// 수정 경로
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionSource {
+    Edit,   // 사냥 기록 수정, 사냥터 변경
+    Revert, // 이전 수정 되돌리기
+    Undo,   // 실행 취소
+    Redo,   // 다시 실행
+}
+
+impl RevisionSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Edit => "edit",
+            Self::Revert => "revert",
+            Self::Undo => "undo",
+            Self::Redo => "redo",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Edit, Self::Revert, Self::Undo, Self::Redo].into_iter().find(|s| s.as_str() == value)
+    }
+}
+
+// 필드 하나의 변경 (소모품은 목록 전체를 한 필드로)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionFieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HuntingSessionRevision {
+    pub id: i64,
+    pub session_id: i64,
+    pub source: RevisionSource,
+    pub changes: Vec<SessionFieldChange>,
+    pub reverted_revision_id: Option<i64>, // source가 revert일 때 되돌린 수정
+    pub created_at: String,
+}
+
+fn session_fields(session: &HuntingSession) -> Map<String, Value> {
+    let mut value = serde_json::to_value(session).unwrap_or_default();
+    // 소모품 id는 저장할 때마다 새로 매겨지므로 비교하지 않음
+    if let Some(consumables) = value.get_mut("consumables").and_then(|v| v.as_array_mut()) {
+        for consumable in consumables.iter_mut().filter_map(|c| c.as_object_mut()) {
+            consumable.remove("id");
+        }
+    }
+    match value {
+        Value::Object(fields) => fields,
+        _ => Map::new(),
+    }
+}
+
+// 바뀐 필드만 (필드 이름 순)
+pub fn diff_sessions(before: &HuntingSession, after: &HuntingSession) -> Vec<SessionFieldChange> {
+    let before = session_fields(before);
+    session_fields(after)
+        .into_iter()
+        .filter(|(field, _)| !IGNORED_FIELDS.contains(&field.as_str()))
+        .filter_map(|(field, after)| {
+            let before = before.get(&field).cloned().unwrap_or(Value::Null);
+            (before != after).then_some(SessionFieldChange { field, before, after })
+        })
+        .collect()
+}
+
+// 입력 필드만 변경 전 값으로 되돌리고 파생값은 다시 계산 (다른 필드는 현재 값 유지)
+pub fn revert_changes(session: &HuntingSession, changes: &[SessionFieldChange]) -> Result<HuntingSession, String> {
+    let mut fields = session_fields(session);
+    for change in changes {
+        let field = change.field.as_str();
+        if !IGNORED_FIELDS.contains(&field) && !DERIVED_FIELDS.contains(&field) {
+            fields.insert(change.field.clone(), change.before.clone());
+        }
+    }
+    let mut reverted: HuntingSession = serde_json::from_value(Value::Object(fields)).map_err(|e| e.to_string())?;
+    reverted.derive_gains();
+    Ok(reverted)
+}
@@ -0,0 +1,129 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Arcane,
+    Authentic,
+    GrandAuthentic,
+}
+
+impl SymbolKind {
+    // API symbol_name 접두어로 종류 판별 ("아케인심볼 : 소멸의 여로" 등)
+    pub fn from_symbol_name(symbol_name: &str) -> Option<Self> {
+        if symbol_name.starts_with("그랜드 어센틱심볼") {
+            Some(Self::GrandAuthentic)
+        } else if symbol_name.starts_with("어센틱심볼") {
+            Some(Self::Authentic)
+        } else if symbol_name.starts_with("아케인심볼") {
+            Some(Self::Arcane)
+        } else {
+            None
+        }
+    }
+
+    pub fn max_level(self) -> i32 {
+        match self {
+            Self::Arcane => 20,
+            Self::Authentic | Self::GrandAuthentic => 11,
+        }
+    }
+
+    // 현재 레벨에서 다음 레벨까지 필요한 성장치
+    pub fn required_growth(self, level: i32) -> i64 {
+        let level = level as i64;
+        match self {
+            Self::Arcane => level * level + 11,
+            Self::Authentic | Self::GrandAuthentic => 9 * level * level + 20 * level,
+        }
+    }
+
+    // 일일 퀘스트 1회 성장치
+    pub fn daily_growth(self) -> i64 {
+        match self {
+            Self::Arcane => 20,
+            Self::Authentic | Self::GrandAuthentic => 10,
+        }
+    }
+}
+
+// 지역별 강화 비용 (레벨 L→L+1 = base + per_level × L)
+fn upgrade_cost_formula(symbol_name: &str) -> (i64, i64) {
+    let region = symbol_name.split(':').nth(1).map(str::trim).unwrap_or_default();
+    match region {
+        "소멸의 여로" => (3_110_000, 3_960_000),
+        "츄츄 아일랜드" => (6_220_000, 4_620_000),
+        "레헬른" | "아르카나" | "모라스" | "에스페라" => (9_330_000, 5_280_000),
+        "세르니움" => (96_900_000, 88_500_000),
+        "아르크스" => (106_600_000, 97_300_000),
+        "오디움" => (117_200_000, 107_000_000),
+        "도원경" => (128_900_000, 117_700_000),
+        "아르테리아" => (141_800_000, 129_500_000),
+        "카르시온" => (156_000_000, 142_500_000),
+        "탈라하트" => (171_600_000, 156_800_000),
+        _ => (0, 0),
+    }
+}
+
+pub fn upgrade_cost(symbol_name: &str, level: i32) -> i64 {
+    let (base, per_level) = upgrade_cost_formula(symbol_name);
+    base + per_level * level as i64
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SymbolProjection {
+    pub symbol_name: String,
+    pub symbol_kind: SymbolKind,
+    pub symbol_level: i32,
+    pub max_level: i32,
+    pub remaining_growth: i64,
+    pub daily_growth: f64, // 최근 일일 퀘스트 완료율 반영
+    pub days_to_max: Option<i64>,
+    pub projected_max_date: Option<String>,
+    pub remaining_meso_cost: i64,
+}
+
+// 현재 레벨/성장치와 일일 퀘스트 완료율로 만렙 예상일과 남은 강화 비용 계산
+pub fn project_symbol(
+    symbol_name: &str,
+    symbol_level: i32,
+    growth_count: i64,
+    completion_rate: f64,
+    today: NaiveDate,
+) -> Option<SymbolProjection> {
+    let kind = SymbolKind::from_symbol_name(symbol_name)?;
+    let max_level = kind.max_level();
+
+    let remaining_growth = ((symbol_level..max_level)
+        .map(|level| kind.required_growth(level))
+        .sum::<i64>()
+        - growth_count)
+        .max(0);
+    let remaining_meso_cost = (symbol_level..max_level)
+        .map(|level| upgrade_cost(symbol_name, level))
+        .sum();
+
+    let daily_growth = kind.daily_growth() as f64 * completion_rate;
+    let days_to_max = if remaining_growth == 0 {
+        Some(0)
+    } else if daily_growth > 0.0 {
+        Some((remaining_growth as f64 / daily_growth).ceil() as i64)
+    } else {
+        None
+    };
+    let projected_max_date = days_to_max
+        .map(|days| (today + chrono::Duration::days(days)).format("%Y-%m-%d").to_string());
+
+    Some(SymbolProjection {
+        symbol_name: symbol_name.to_string(),
+        symbol_kind: kind,
+        symbol_level,
+        max_level,
+        remaining_growth,
+        daily_growth,
+        days_to_max,
+        projected_max_date,
+        remaining_meso_cost,
+    })
+}
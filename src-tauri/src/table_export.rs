@@ -0,0 +1,249 @@
+use crate::db::Database;
+use rusqlite::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 표 형식으로 내보낼 수 있는 테이블
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportTable {
+    HuntingSessions,
+    BossClears,
+    ItemDrops,
+    ExpHistory,
+}
+
+impl ExportTable {
+    // 컬럼 순서는 고정 (기존 스프레드시트 수식이 깨지지 않도록 새 컬럼은 뒤에 추가)
+    pub fn headers(self) -> &'static [&'static str] {
+        match self {
+            Self::HuntingSessions => &[
+                "id", "character_id", "character_name", "date", "session_order", "map",
+                "duration_minutes", "start_level", "start_exp_percent", "end_level", "end_exp_percent",
+                "exp_gained", "start_meso", "end_meso", "meso_gained", "sojaebi", "sol_erda_gained",
+                "sol_erda_piece_gained", "sol_erda_piece_price", "sol_erda_piece_value",
+                "consumables", "consumable_cost", "memo",
+            ],
+            Self::BossClears => &[
+                "id", "character_id", "character_name", "cleared_date", "week_start_date",
+                "boss_id", "difficulty", "crystal_price", "party_size", "crystal_income_per_person",
+                "memo",
+            ],
+            Self::ItemDrops => &[
+                "id", "character_id", "character_name", "date", "item_name", "price",
+                "sale_status", "sale_price", "sale_fee", "net_sale_income", "sold_date",
+            ],
+            Self::ExpHistory => &[
+                "id", "character_id", "character_name", "date", "level", "exp_rate",
+                "total_exp", "exp_gained",
+            ],
+        }
+    }
+}
+
+// 내보내기 셀 값
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Empty,
+}
+
+impl Cell {
+    // 스프레드시트가 수식으로 실행하지 않도록 =, +, -, @ 등으로 시작하는 문자열은 ' 접두사
+    fn to_field(&self) -> String {
+        match self {
+            Self::Text(value) if value.starts_with(['=', '+', '-', '@', '\t', '\r']) => format!("'{}", value),
+            Self::Text(value) => value.clone(),
+            Self::Int(value) => value.to_string(),
+            Self::Float(value) => value.to_string(),
+            Self::Empty => String::new(),
+        }
+    }
+}
+
+impl From<String> for Cell {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<i64> for Cell {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<i32> for Cell {
+    fn from(value: i32) -> Self {
+        Self::Int(value as i64)
+    }
+}
+
+impl From<f64> for Cell {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl<T: Into<Cell>> From<Option<T>> for Cell {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Empty)
+    }
+}
+
+pub struct ExportSheet {
+    pub table: ExportTable,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+// 테이블 행을 캐릭터/기간으로 걸러서 고정 컬럼 순서로 변환
+pub fn build_sheet(
+    db: &Database,
+    table: ExportTable,
+    character_id: Option<i64>,
+    start_date: Option<&str>,
+    end_date: Option<&str>,
+) -> Result<ExportSheet> {
+    let character_names: HashMap<i64, String> = db
+        .get_characters()?
+        .into_iter()
+        .map(|c| (c.id, c.character_name))
+        .collect();
+    let character_name = |id: i64| Cell::from(character_names.get(&id).cloned());
+
+    let rows = match table {
+        ExportTable::HuntingSessions => {
+            let map_names: HashMap<i64, String> = db
+                .get_hunting_maps()?
+                .into_iter()
+                .map(|m| (m.id, format!("{} - {}", m.region, m.map_name)))
+                .collect();
+
+            db.get_hunting_sessions_in_range(character_id, start_date, end_date)?
+                .into_iter()
+                .map(|s| {
+                    let consumables = s
+                        .consumables
+                        .iter()
+                        .map(|c| format!("{} x{}", c.consumable_type.as_str(), c.quantity))
+                        .collect::<Vec<_>>()
+                        .join("; ");
+                    let consumable_cost: i64 = s.consumables.iter().map(|c| c.meso_cost).sum();
+                    vec![
+                        s.id.into(),
+                        s.character_id.into(),
+                        character_name(s.character_id),
+                        s.date.into(),
+                        s.session_order.into(),
+                        s.map_id.and_then(|id| map_names.get(&id).cloned()).into(),
+                        s.duration_minutes.into(),
+                        s.start_level.into(),
+                        s.start_exp_percent.into(),
+                        s.end_level.into(),
+                        s.end_exp_percent.into(),
+                        s.exp_gained.into(),
+                        s.start_meso.into(),
+                        s.end_meso.into(),
+                        s.meso_gained.into(),
+                        s.sojaebi.into(),
+                        s.sol_erda_gained.into(),
+                        s.sol_erda_piece_gained.into(),
+                        s.sol_erda_piece_price.into(),
+                        (s.sol_erda_piece_gained * s.sol_erda_piece_price).into(),
+                        consumables.into(),
+                        consumable_cost.into(),
+                        s.memo.into(),
+                    ]
+                })
+                .collect()
+        }
+        ExportTable::BossClears => db
+            .get_boss_clears_in_range(character_id, start_date, end_date)?
+            .into_iter()
+            .map(|c| {
+                let per_person = c.crystal_price / c.party_size.max(1) as i64;
+                vec![
+                    c.id.into(),
+                    c.character_id.into(),
+                    character_name(c.character_id),
+                    c.cleared_date.into(),
+                    c.week_start_date.into(),
+                    c.boss_id.into(),
+                    c.difficulty.into(),
+                    c.crystal_price.into(),
+                    c.party_size.into(),
+                    per_person.into(),
+                    c.memo.into(),
+                ]
+            })
+            .collect(),
+        ExportTable::ItemDrops => db
+            .get_item_drops_in_range(character_id, start_date, end_date)?
+            .into_iter()
+            .map(|d| {
+                // 판매 완료만 실수령액 계산
+                let net_sale_income = match (d.sale_status.as_str(), d.sale_price) {
+                    ("sold", Some(price)) => Some(price - d.sale_fee.unwrap_or(0)),
+                    _ => None,
+                };
+                vec![
+                    d.id.into(),
+                    d.character_id.into(),
+                    character_name(d.character_id),
+                    d.date.into(),
+                    d.item_name.into(),
+                    d.price.into(),
+                    d.sale_status.into(),
+                    d.sale_price.into(),
+                    d.sale_fee.into(),
+                    net_sale_income.into(),
+                    d.sold_date.into(),
+                ]
+            })
+            .collect(),
+        ExportTable::ExpHistory => {
+            // 캐릭터별 직전 기록 대비 증가량 (기간 첫 기록은 시작일 이전 마지막 기록과 비교)
+            let mut previous: HashMap<i64, f64> = match start_date {
+                Some(start_date) => db.get_last_total_exp_before(character_id, start_date)?.into_iter().collect(),
+                None => HashMap::new(),
+            };
+            db.get_exp_history_in_range(character_id, start_date, end_date)?
+                .into_iter()
+                .map(|h| {
+                    let exp_gained = previous.insert(h.character_id, h.total_exp).map(|prev| h.total_exp - prev);
+                    vec![
+                        h.id.into(),
+                        h.character_id.into(),
+                        character_name(h.character_id),
+                        h.date.into(),
+                        h.level.into(),
+                        h.exp_rate.into(),
+                        h.total_exp.into(),
+                        exp_gained.into(),
+                    ]
+                })
+                .collect()
+        }
+    };
+
+    Ok(ExportSheet { table, rows })
+}
+
+// 엑셀에서 한글이 깨지지 않도록 UTF-8 BOM을 붙임
+pub fn to_csv(sheet: &ExportSheet) -> std::result::Result<String, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(sheet.table.headers())?;
+    for row in &sheet.rows {
+        writer.write_record(row.iter().map(Cell::to_field))?;
+    }
+    let bytes = writer.into_inner().map_err(|e| csv::Error::from(e.into_error()))?;
+    Ok(format!("\u{feff}{}", String::from_utf8_lossy(&bytes)))
+}
@@ -0,0 +1,291 @@
+use crate::db::Database;
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+use std::collections::{BTreeMap, HashMap};
+
+// 일별 리포트 행
+#[derive(Debug, Clone, Default)]
+pub struct DailyReportRow {
+    pub date: String,
+    pub session_count: f64, // 일 평균에서 소수로 나오므로 f64
+    pub sojaebi: f64,
+    pub exp_gained: f64,
+    pub meso: i64,
+    pub pieces: i64,
+    pub piece_value: i64, // 획득일 시세 기준
+    pub crystal_income: i64, // 파티 분배 후
+    pub drop_value: i64, // 판매 완료는 실수령액
+}
+
+impl DailyReportRow {
+    fn total_income(&self) -> i64 {
+        self.meso + self.piece_value + self.crystal_income + self.drop_value
+    }
+
+    fn add(&mut self, other: &DailyReportRow) {
+        self.session_count += other.session_count;
+        self.sojaebi += other.sojaebi;
+        self.exp_gained += other.exp_gained;
+        self.meso += other.meso;
+        self.pieces += other.pieces;
+        self.piece_value += other.piece_value;
+        self.crystal_income += other.crystal_income;
+        self.drop_value += other.drop_value;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MonthReport {
+    pub month: i32,
+    pub days: Vec<DailyReportRow>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WeeklyBossRow {
+    pub week_start_date: String,
+    pub clear_count: i32,
+    pub crystal_income: i64,
+    pub bosses: Vec<String>, // boss_id(difficulty)
+}
+
+#[derive(Debug, Clone)]
+pub struct YearReport {
+    pub character_name: String,
+    pub year: i32,
+    pub months: Vec<MonthReport>,
+    pub weekly_bosses: Vec<WeeklyBossRow>,
+}
+
+// 기록이 있는 달만 모아서 연간 리포트 데이터 구성
+pub fn build_report(db: &Database, character_id: i64, year: i32) -> rusqlite::Result<YearReport> {
+    let character_name = db
+        .get_characters()?
+        .into_iter()
+        .find(|c| c.id == character_id)
+        .map(|c| c.character_name)
+        .unwrap_or_default();
+    let year_start = format!("{:04}-01-01", year);
+    let year_end = format!("{:04}-12-31", year);
+
+    let drop_values: HashMap<String, i64> = db.get_daily_drop_values(character_id)?.into_iter().collect();
+    let boss_clears = db.get_boss_clears_in_range(Some(character_id), Some(&year_start), Some(&year_end))?;
+
+    let mut months = Vec::new();
+    for month in db.get_months_with_data(character_id, year)? {
+        let mut days: BTreeMap<String, DailyReportRow> = BTreeMap::new();
+        let day = |days: &mut BTreeMap<String, DailyReportRow>, date: &str| -> DailyReportRow {
+            days.remove(date).unwrap_or_else(|| DailyReportRow { date: date.to_string(), ..Default::default() })
+        };
+
+        for (total, pieces, piece_value) in db.get_daily_totals_with_pieces(character_id, year, month)? {
+            let mut row = day(&mut days, &total.date);
+            row.session_count = total.session_count as f64;
+            row.sojaebi = total.total_sojaebi;
+            row.exp_gained = total.total_exp_gained;
+            row.meso = total.total_meso_gained;
+            row.pieces = pieces;
+            row.piece_value = piece_value;
+            days.insert(row.date.clone(), row);
+        }
+
+        let month_prefix = format!("{:04}-{:02}-", year, month);
+        for clear in boss_clears.iter().filter(|c| c.cleared_date.starts_with(&month_prefix)) {
+            let mut row = day(&mut days, &clear.cleared_date);
+            row.crystal_income += clear.crystal_price / clear.party_size.max(1) as i64;
+            days.insert(row.date.clone(), row);
+        }
+        for (date, value) in drop_values.iter().filter(|(date, _)| date.starts_with(&month_prefix)) {
+            let mut row = day(&mut days, date);
+            row.drop_value += value;
+            days.insert(row.date.clone(), row);
+        }
+
+        months.push(MonthReport { month, days: days.into_values().collect() });
+    }
+
+    let mut weeks: BTreeMap<String, WeeklyBossRow> = BTreeMap::new();
+    for clear in &boss_clears {
+        let week = weeks.entry(clear.week_start_date.clone()).or_insert_with(|| WeeklyBossRow {
+            week_start_date: clear.week_start_date.clone(),
+            clear_count: 0,
+            crystal_income: 0,
+            bosses: Vec::new(),
+        });
+        week.clear_count += 1;
+        week.crystal_income += clear.crystal_price / clear.party_size.max(1) as i64;
+        week.bosses.push(format!("{}({})", clear.boss_id, clear.difficulty));
+    }
+
+    Ok(YearReport {
+        character_name,
+        year,
+        months,
+        weekly_bosses: weeks.into_values().collect(),
+    })
+}
+
+const DAILY_HEADERS: [&str; 10] = [
+    "날짜", "사냥 횟수", "소재비", "경험치(%)", "메소", "조각", "조각 가치", "결정석", "득템", "총 수입",
+];
+
+const SUMMARY_HEADERS: [&str; 11] = [
+    "월", "기록일", "사냥 횟수", "소재비", "경험치(%)", "메소", "조각", "조각 가치", "결정석", "득템", "총 수입",
+];
+
+struct Formats {
+    header: Format,
+    number: Format,
+    decimal: Format,
+    bold_number: Format,
+    bold_decimal: Format,
+}
+
+impl Formats {
+    fn new() -> Self {
+        Self {
+            header: Format::new().set_bold().set_background_color("#DDEBF7"),
+            number: Format::new().set_num_format("#,##0"),
+            decimal: Format::new().set_num_format("#,##0.00"),
+            bold_number: Format::new().set_bold().set_num_format("#,##0"),
+            bold_decimal: Format::new().set_bold().set_num_format("#,##0.00"),
+        }
+    }
+}
+
+fn write_headers(sheet: &mut Worksheet, headers: &[&str], formats: &Formats) -> Result<(), XlsxError> {
+    for (col, header) in headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *header, &formats.header)?;
+    }
+    sheet.set_freeze_panes(1, 1)?;
+    Ok(())
+}
+
+// 날짜/월 이후 컬럼 (사냥 횟수 ~ 총 수입)
+fn write_values(sheet: &mut Worksheet, row: u32, first_col: u16, values: &DailyReportRow, bold: bool, formats: &Formats) -> Result<(), XlsxError> {
+    let (number, decimal) = if bold {
+        (&formats.bold_number, &formats.bold_decimal)
+    } else {
+        (&formats.number, &formats.decimal)
+    };
+    let cells: [(f64, &Format); 9] = [
+        (values.session_count, if values.session_count.fract() == 0.0 { number } else { decimal }),
+        (values.sojaebi, decimal),
+        (values.exp_gained, decimal),
+        (values.meso as f64, number),
+        (values.pieces as f64, number),
+        (values.piece_value as f64, number),
+        (values.crystal_income as f64, number),
+        (values.drop_value as f64, number),
+        (values.total_income() as f64, number),
+    ];
+    for (offset, (value, format)) in cells.into_iter().enumerate() {
+        sheet.write_number_with_format(row, first_col + offset as u16, value, format)?;
+    }
+    Ok(())
+}
+
+// 기록일 평균 (합계 / 일수)
+fn average(total: &DailyReportRow, day_count: usize) -> DailyReportRow {
+    let n = day_count.max(1);
+    DailyReportRow {
+        date: String::new(),
+        session_count: total.session_count / n as f64,
+        sojaebi: total.sojaebi / n as f64,
+        exp_gained: total.exp_gained / n as f64,
+        meso: total.meso / n as i64,
+        pieces: total.pieces / n as i64,
+        piece_value: total.piece_value / n as i64,
+        crystal_income: total.crystal_income / n as i64,
+        drop_value: total.drop_value / n as i64,
+    }
+}
+
+fn sum_days(days: &[DailyReportRow]) -> DailyReportRow {
+    let mut total = DailyReportRow::default();
+    for day in days {
+        total.add(day);
+    }
+    total
+}
+
+// 요약 시트 + 월별 시트 + 주간 보스 시트
+pub fn save_report(report: &YearReport, path: &str) -> Result<(), XlsxError> {
+    let formats = Formats::new();
+    let mut workbook = Workbook::new();
+
+    // 요약 (월별 합계, 연간 합계/평균)
+    let summary = workbook.add_worksheet();
+    summary.set_name("요약")?;
+    summary.write_string_with_format(0, 0, format!("{} {}년 리포트", report.character_name, report.year), &formats.header)?;
+    let mut row = 2;
+    for (col, header) in SUMMARY_HEADERS.iter().enumerate() {
+        summary.write_string_with_format(row, col as u16, *header, &formats.header)?;
+    }
+    row += 1;
+    let mut year_total = DailyReportRow::default();
+    let mut year_days = 0;
+    for month in &report.months {
+        let total = sum_days(&month.days);
+        summary.write_string(row, 0, format!("{}월", month.month))?;
+        summary.write_number(row, 1, month.days.len() as f64)?;
+        write_values(summary, row, 2, &total, false, &formats)?;
+        year_total.add(&total);
+        year_days += month.days.len();
+        row += 1;
+    }
+    summary.write_string_with_format(row, 0, "합계", &formats.header)?;
+    summary.write_number_with_format(row, 1, year_days as f64, &formats.bold_number)?;
+    write_values(summary, row, 2, &year_total, true, &formats)?;
+    row += 1;
+    summary.write_string_with_format(row, 0, "일 평균", &formats.header)?;
+    write_values(summary, row, 2, &average(&year_total, year_days), true, &formats)?;
+    summary.set_column_width(0, 12)?;
+    for col in 1..SUMMARY_HEADERS.len() as u16 {
+        summary.set_column_width(col, 14)?;
+    }
+
+    // 월별 일간 기록
+    for month in &report.months {
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(format!("{:04}-{:02}", report.year, month.month))?;
+        write_headers(sheet, &DAILY_HEADERS, &formats)?;
+        let mut row = 1;
+        for day in &month.days {
+            sheet.write_string(row, 0, &day.date)?;
+            write_values(sheet, row, 1, day, false, &formats)?;
+            row += 1;
+        }
+        let total = sum_days(&month.days);
+        sheet.write_string_with_format(row, 0, "합계", &formats.header)?;
+        write_values(sheet, row, 1, &total, true, &formats)?;
+        sheet.write_string_with_format(row + 1, 0, "일 평균", &formats.header)?;
+        write_values(sheet, row + 1, 1, &average(&total, month.days.len()), true, &formats)?;
+        sheet.set_column_width(0, 12)?;
+        for col in 1..DAILY_HEADERS.len() as u16 {
+            sheet.set_column_width(col, 14)?;
+        }
+    }
+
+    // 주간 보스 (목요일 시작 주)
+    let bosses = workbook.add_worksheet();
+    bosses.set_name("주간 보스")?;
+    write_headers(bosses, &["주 시작일", "클리어 수", "결정석 수입", "보스"], &formats)?;
+    let mut row = 1;
+    for week in &report.weekly_bosses {
+        bosses.write_string(row, 0, &week.week_start_date)?;
+        bosses.write_number_with_format(row, 1, week.clear_count as f64, &formats.number)?;
+        bosses.write_number_with_format(row, 2, week.crystal_income as f64, &formats.number)?;
+        bosses.write_string(row, 3, week.bosses.join(", "))?;
+        row += 1;
+    }
+    let week_total: i64 = report.weekly_bosses.iter().map(|w| w.crystal_income).sum();
+    let clear_total: i32 = report.weekly_bosses.iter().map(|w| w.clear_count).sum();
+    bosses.write_string_with_format(row, 0, "합계", &formats.header)?;
+    bosses.write_number_with_format(row, 1, clear_total as f64, &formats.bold_number)?;
+    bosses.write_number_with_format(row, 2, week_total as f64, &formats.bold_number)?;
+    bosses.set_column_width(0, 12)?;
+    bosses.set_column_width(1, 10)?;
+    bosses.set_column_width(2, 16)?;
+    bosses.set_column_width(3, 60)?;
+
+    workbook.save(path)
+}
@@ -17,6 +17,12 @@ function App() {
 
   async function loadInitialData() {
     try {
+      // 활성 프로필 DB를 열 수 없어 기본 프로필로 시작했으면 알림
+      const startupError = await invoke<string | null>("get_startup_error");
+      if (startupError) {
+        alert(startupError);
+      }
+
       const [settingsData, characterData] = await Promise.all([
         invoke<Settings | null>("get_settings"),
         invoke<Character | null>("get_character"),
@@ -305,7 +305,7 @@ export function DailyDashboardDialog({
                     <span className="text-[11px] font-semibold">일일 총 수익</span>
                   </div>
                   <p className="text-sm font-bold text-primary">
-                    {formatMesoDetailed((dailyTotal?.total_meso_gained ?? 0) + todayBossIncome + (dailyTotal ? dailyTotal.total_pieces * dailyTotal.avg_piece_price : 0) + itemDropTotal)}
+                    {formatMesoDetailed((dailyTotal?.total_meso_gained ?? 0) + todayBossIncome + (dailyTotal ? dailyTotal.total_piece_value : 0) + itemDropTotal)}
                   </p>
                 </div>
               </div>
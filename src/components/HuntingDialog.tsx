@@ -11,7 +11,7 @@ import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
 import { Loader2, Save, Plus, Trash2, ChevronDown, ChevronUp, Sword, Pencil } from "lucide-react";
-import type { HuntingSession, AppSettings, HuntingOcrResult } from "@/types";
+import type { HuntingSession, HuntingOcrResult } from "@/types";
 import { formatMeso } from "@/data/bossData";
 import { formatExpWithPercent } from "@/data/expTable";
 
@@ -88,6 +88,8 @@ export function HuntingDialog({
   const [_expandedSession, setExpandedSession] = useState<number | null>(null);
   const [editingSession, setEditingSession] = useState<HuntingSession | null>(null);
   const [isOcrApplied, setIsOcrApplied] = useState(false);
+  // 조각 가격을 직접 수정했을 때만 저장 시 전달 (아니면 날짜 기준 시세 사용)
+  const [isPiecePriceEdited, setIsPiecePriceEdited] = useState(false);
 
   useEffect(() => {
     if (open && date) {
@@ -113,9 +115,10 @@ export function HuntingDialog({
       console.log("[HuntingDialog] 솔 에르다:", ocrResult.start_sol_erda, "개", ocrResult.start_sol_erda_gauge, "게이지 ->", ocrResult.end_sol_erda, "개", ocrResult.end_sol_erda_gauge, "게이지");
       console.log("[HuntingDialog] 솔 에르다 조각:", ocrResult.start_sol_erda_piece, "->", ocrResult.end_sol_erda_piece);
 
-      // OCR 결과 적용 시 조각 가격만 기본 설정에서 가져옴
-      invoke<AppSettings>("get_app_settings").then((appSettings) => {
-        console.log("[HuntingDialog] AppSettings 로드 완료, 폼 데이터 설정 중...");
+      // OCR 결과 적용 시 조각 가격만 해당 날짜 시세에서 가져옴
+      setIsPiecePriceEdited(false);
+      invoke<number>("get_sol_erda_piece_price", { date }).then((piecePrice) => {
+        console.log("[HuntingDialog] 조각 시세 로드 완료, 폼 데이터 설정 중...");
         const newData = {
           // 0도 유효한 값이므로 ?? 사용 (null/undefined만 대체)
           startLevel: ocrResult.start_level ?? characterLevel,
@@ -131,13 +134,13 @@ export function HuntingDialog({
           endSolErdaGauge: ocrResult.end_sol_erda_gauge ?? 0,
           startSolErdaPiece: ocrResult.start_sol_erda_piece ?? 0,
           endSolErdaPiece: ocrResult.end_sol_erda_piece ?? 0,
-          solErdaPiecePrice: appSettings.sol_erda_piece_price,
+          solErdaPiecePrice: piecePrice,
           memo: "",
         };
         console.log("[HuntingDialog] 적용할 폼 데이터:", newData);
         setNewSession(newData);
       }).catch((error) => {
-        console.error("[HuntingDialog] AppSettings 로드 실패:", error);
+        console.error("[HuntingDialog] 조각 시세 로드 실패:", error);
         // 설정 로드 실패 시에도 OCR 결과 적용
         const newData = {
           startLevel: ocrResult.start_level ?? characterLevel,
@@ -170,20 +173,22 @@ export function HuntingDialog({
       setIsOcrApplied(false);
       setShowNewForm(false);
       setEditingSession(null);
+      setIsPiecePriceEdited(false);
     }
   }, [open]);
 
   async function loadDefaultSettings() {
+    setIsPiecePriceEdited(false);
     try {
-      const appSettings = await invoke<AppSettings>("get_app_settings");
+      const piecePrice = await invoke<number>("get_sol_erda_piece_price", { date });
       setNewSession(prev => ({
         ...prev,
         startLevel: characterLevel,
         endLevel: characterLevel,
-        solErdaPiecePrice: appSettings.sol_erda_piece_price,
+        solErdaPiecePrice: piecePrice,
       }));
     } catch (error) {
-      console.error("Failed to load app settings:", error);
+      console.error("Failed to load sol erda piece price:", error);
     }
   }
 
@@ -224,14 +229,15 @@ export function HuntingDialog({
           end_sol_erda_gauge: newSession.endSolErdaGauge,
           start_sol_erda_piece: newSession.startSolErdaPiece,
           end_sol_erda_piece: newSession.endSolErdaPiece,
-          sol_erda_piece_price: newSession.solErdaPiecePrice,
+          sol_erda_piece_price: isPiecePriceEdited ? newSession.solErdaPiecePrice : null,
           start_screenshot: screenshotPaths?.start || null,
           end_screenshot: screenshotPaths?.end || null,
-          items: "[]",
+          consumables: [],
           memo: newSession.memo || null,
         },
       });
       setNewSession(defaultFormData);
+      setIsPiecePriceEdited(false);
       setShowNewForm(false);
       await loadSessions();
       onSaved();
@@ -273,6 +279,7 @@ export function HuntingDialog({
       solErdaPiecePrice: session.sol_erda_piece_price,
       memo: session.memo || "",
     });
+    setIsPiecePriceEdited(false);
     setShowNewForm(true);
     setExpandedSession(null);
   }
@@ -314,16 +321,18 @@ export function HuntingDialog({
           start_sol_erda_piece: newSession.startSolErdaPiece,
           end_sol_erda_piece: newSession.endSolErdaPiece,
           sol_erda_piece_gained: solErdaPieceGained,
-          sol_erda_piece_price: newSession.solErdaPiecePrice,
+          // 수정하지 않았으면 기록 당시 시세 유지
+          sol_erda_piece_price: isPiecePriceEdited ? newSession.solErdaPiecePrice : editingSession.sol_erda_piece_price,
           start_screenshot: editingSession.start_screenshot,
           end_screenshot: editingSession.end_screenshot,
-          items: editingSession.items,
+          consumables: editingSession.consumables,
           memo: newSession.memo || null,
           created_at: editingSession.created_at,
           updated_at: "",
         },
       });
       setNewSession(defaultFormData);
+      setIsPiecePriceEdited(false);
       setShowNewForm(false);
       setEditingSession(null);
       await loadSessions();
@@ -698,18 +707,19 @@ export function HuntingDialog({
                   <div className="space-y-2">
                     <Label className="text-xs flex items-center gap-2">
                       조각 단가
-                      <span className="text-muted-foreground">(현재 시세)</span>
+                      <span className="text-muted-foreground">(해당 날짜 시세)</span>
                     </Label>
                     <div className="flex items-center gap-2">
                       <Input
                         type="number"
                         value={Math.floor(newSession.solErdaPiecePrice / 10000) || ""}
-                        onChange={(e) =>
+                        onChange={(e) => {
+                          setIsPiecePriceEdited(true);
                           setNewSession({
                             ...newSession,
                             solErdaPiecePrice: (parseInt(e.target.value) || 0) * 10000,
-                          })
-                        }
+                          });
+                        }}
                         className="w-20"
                       />
                       <span className="text-sm text-muted-foreground whitespace-nowrap">
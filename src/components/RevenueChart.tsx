@@ -123,7 +123,7 @@ export function RevenueChart({
       const drops = itemDropsMap.get(dateStr) || [];
 
       const hunting = dt?.total_meso_gained ?? 0;
-      const pieces = dt ? dt.total_pieces * dt.avg_piece_price : 0;
+      const pieces = dt ? dt.total_piece_value : 0;
       const boss = bc.reduce((sum, c) => sum + Math.floor(c.crystal_price / c.party_size), 0);
       const itemDrop = drops.reduce((sum, d) => sum + d.price, 0);
 
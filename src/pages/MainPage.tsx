@@ -98,7 +98,7 @@ export function MainPage({ character, onCharacterChange }: MainPageProps) {
 
         const data = results.map(([totals, clears, drops], i) => {
           const hunting = totals.reduce((s, t) => s + t.total_meso_gained, 0);
-          const pieces = totals.reduce((s, t) => s + t.total_pieces * t.avg_piece_price, 0);
+          const pieces = totals.reduce((s, t) => s + t.total_piece_value, 0);
           const boss = clears.reduce((s, c) => s + Math.floor(c.crystal_price / c.party_size), 0);
           const itemDrop = drops.reduce((s, d) => s + d.price, 0);
           return {
@@ -282,7 +282,7 @@ export function MainPage({ character, onCharacterChange }: MainPageProps) {
     // 사냥 메소 + 조각 가치
     dailyTotals.forEach((dt) => {
       total += dt.total_meso_gained;
-      total += dt.total_pieces * dt.avg_piece_price;
+      total += dt.total_piece_value;
     });
 
     // 보스 메소
@@ -644,7 +644,7 @@ export function MainPage({ character, onCharacterChange }: MainPageProps) {
 
               // 상세보기용 계산: 사냥메소 + 보스메소 + 조각값 + 득템
               const huntingMeso = dailyTotal?.total_meso_gained ?? 0;
-              const pieceValue = dailyTotal ? dailyTotal.total_pieces * dailyTotal.avg_piece_price : 0;
+              const pieceValue = dailyTotal ? dailyTotal.total_piece_value : 0;
               const totalIncome = huntingMeso + bossIncome + pieceValue + itemDropIncome;
 
               return (
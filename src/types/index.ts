@@ -1,7 +1,10 @@
 // 설정 타입 (백엔드 응답과 일치)
 export interface Settings {
   id: number;
-  api_key: string;
+  name: string; // API Key 이름 (가장 먼저 등록한 키가 기본 키)
+  api_key: string; // 복호화된 값 (잠겨 있으면 빈 문자열)
+  key_protection: "plain" | "local" | "passphrase";
+  locked: boolean; // 암호 입력 전이거나 다른 PC에서 암호화됨
   created_at: string;
   updated_at: string;
   // 프론트엔드 편의용
@@ -52,10 +55,46 @@ export interface HuntingSession {
   sol_erda_piece_price: number; // 해당 사냥 시점의 조각 가격
   start_screenshot?: string;
   end_screenshot?: string;
-  items: string;
+  consumables: SessionConsumable[]; // 사용한 버프/쿠폰
   memo?: string;
   created_at: string;
   updated_at: string;
+  map_id?: number; // 사냥터
+}
+
+// 사냥 기록 수정 이력
+export type RevisionSource = "edit" | "revert" | "undo" | "redo";
+
+export interface SessionFieldChange {
+  field: string; // HuntingSession 필드 이름 (consumables는 목록 전체)
+  before: unknown;
+  after: unknown;
+}
+
+export interface HuntingSessionRevision {
+  id: number;
+  session_id: number;
+  source: RevisionSource;
+  changes: SessionFieldChange[];
+  reverted_revision_id?: number; // source가 revert일 때 되돌린 수정
+  created_at: string;
+}
+
+// 사냥 세션 버프/쿠폰 종류
+export type ConsumableType =
+  | "exp_coupon"
+  | "vip_buff"
+  | "mvp_buff"
+  | "wealth_potion"
+  | "legion_wealth"
+  | "familiar_buff";
+
+// 사냥 세션 소모품
+export interface SessionConsumable {
+  id?: number;
+  consumable_type: ConsumableType;
+  quantity: number;
+  meso_cost: number;
 }
 
 // 아이템 기록
@@ -73,6 +112,8 @@ export interface DailyTotal {
   total_meso_gained: number;
   total_sojaebi: number;
   session_count: number;
+  exp_multiplier: number; // 이벤트 경험치 배율 (이벤트 없으면 1)
+  events: string[];
 }
 
 // 캐릭터 목록 아이템 (character/list API)
@@ -84,6 +125,17 @@ export interface CharacterListItem {
   character_level: number;
 }
 
+// API Key로 조회되는 캐릭터
+export interface ApiKeyCharacter extends CharacterListItem {
+  api_key_id: number;
+}
+
+// API Key 저장 결과
+export interface ApiKeyValidation {
+  api_key_id: number;
+  characters: CharacterListItem[];
+}
+
 // 캐릭터 검색 결과
 export interface SearchCharacterResult {
   ocid: string;
@@ -104,6 +156,8 @@ export interface ExpHistory {
   exp_rate: number;
   total_exp: number;
   created_at: string;
+  exp_multiplier: number;
+  events: string[];
 }
 
 // API에서 가져온 일별 경험치 데이터
@@ -135,6 +189,7 @@ export interface BossClear {
   week_start_date: string;
   crystal_price: number;
   party_size: number;
+  memo?: string;
   created_at: string;
 }
 
@@ -150,10 +205,28 @@ export interface AppSettings {
   id: number;
   sol_erda_piece_price: number;
   screenshot_folder_path?: string;
+  auction_fee_rate: number;
+  backup_keep_count: number; // 종류별 자동 백업 보관 개수
+  trash_retention_days: number; // 휴지통 보관 기간 (일)
   created_at: string;
   updated_at: string;
 }
 
+// 내보내기 파일의 API Key 포함 방식
+export type ApiKeyExport = "omit" | "encrypted" | "plain";
+
+// 다이어리 프로필 (프로필마다 별도 DB 파일)
+export interface Profile {
+  name: string;
+  db_path: string;
+  last_used_at?: string;
+}
+
+export interface ProfileConfig {
+  active_profile: string;
+  profiles: Profile[]; // 최근 사용 순
+}
+
 // 아이템 드랍 기록 (백엔드 응답과 일치)
 export interface ItemDrop {
   id: number;
@@ -162,6 +235,11 @@ export interface ItemDrop {
   item_name: string;
   price: number;
   screenshot?: string;
+  sale_status: 'listed' | 'sold' | 'kept';
+  sale_price?: number;
+  sale_fee?: number;
+  sold_date?: string;
+  item_id?: number;
   created_at: string;
 }
 
@@ -181,7 +259,8 @@ export interface DailyTotalWithPieces {
   total_sojaebi: number;
   session_count: number;
   total_pieces: number;
-  avg_piece_price: number;
+  avg_piece_price: number; // 조각 수 가중 평균
+  total_piece_value: number;
 }
 
 // 장비 관련 타입
@@ -234,6 +313,9 @@ export interface EquipmentResponse {
   character_class: string;
   preset_no: number;
   item_equipment: EquipmentItem[];
+  item_equipment_preset_1: EquipmentItem[] | null;
+  item_equipment_preset_2: EquipmentItem[] | null;
+  item_equipment_preset_3: EquipmentItem[] | null;
   title: {
     title_name: string;
     title_icon: string;
@@ -270,3 +352,44 @@ export interface HuntingOcrResult {
   end_sol_erda_piece: number;
   sol_erda_piece_gained: number;
 }
+
+// 전문 검색 결과
+export type SearchSource = "hunting_session" | "item_drop" | "boss_clear" | "character";
+
+export interface SearchHit {
+  source: SearchSource;
+  source_id: number;
+  character_id?: number;
+  character_name?: string;
+  date?: string;
+  snippet: string; // 일치한 부분은 [ ]로 표시
+  score: number; // bm25 (낮을수록 관련도 높음)
+}
+
+// 실행 취소/다시 실행 기록
+export type OperationKind = "create" | "update" | "delete";
+
+export interface JournalOperation {
+  id: number;
+  kind: OperationKind;
+  table_name: string;
+  label: string; // "사냥 기록 삭제" 등
+  undone: boolean;
+  created_at: string;
+}
+
+export interface JournalState {
+  undo?: JournalOperation; // 지금 실행 취소하면 되돌릴 작업
+  redo?: JournalOperation;
+}
+
+// 휴지통 항목 (삭제 작업)
+export interface TrashItem {
+  operation_id: number;
+  table_name: string;
+  label: string;
+  row: Record<string, unknown>; // 삭제 전 행
+  related_rows: number; // 함께 삭제된 행 수 (사냥 기록의 소모품 등)
+  deleted_at: string;
+  expires_at: string; // 이후 완전히 삭제됨
+}